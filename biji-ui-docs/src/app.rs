@@ -29,11 +29,11 @@ use crate::pages::docs::{
     command::CommandDocPage, combobox::ComboboxDocPage, context_menu::ContextMenuDocPage,
     dialog::DialogDocPage, drawer::DrawerDocPage, dropdown_menu::DropdownMenuDocPage,
     getting_started::GettingStartedPage, hover_card::HoverCardDocPage, menubar::MenubarDocPage,
-    navigation_menu::NavigationMenuDocPage,
+    navigation_menu::NavigationMenuDocPage, number_field::NumberFieldDocPage,
     pin_input::PinInputDocPage, popover::PopoverDocPage, progress::ProgressDocPage,
     radio_group::RadioGroupDocPage, select::SelectDocPage, separator::SeparatorDocPage,
-    slider::SliderDocPage, switch::SwitchDocPage, tabs::TabsDocPage, toast::ToastDocPage,
-    toggle_group::ToggleGroupDocPage, tooltip::TooltipDocPage,
+    slider::SliderDocPage, switch::SwitchDocPage, tabs::TabsDocPage, tags_input::TagsInputDocPage,
+    toast::ToastDocPage, toggle_group::ToggleGroupDocPage, tooltip::TooltipDocPage,
 };
 
 #[component]
@@ -76,6 +76,7 @@ pub fn App() -> impl IntoView {
                         <Route path={path!("hover-card")} view={HoverCardDocPage} />
                         <Route path={path!("menubar")} view={MenubarDocPage} />
                         <Route path={path!("navigation-menu")} view={NavigationMenuDocPage} />
+                        <Route path={path!("number-field")} view={NumberFieldDocPage} />
                         <Route path={path!("pin-input")} view={PinInputDocPage} />
                         <Route path={path!("popover")} view={PopoverDocPage} />
                         <Route path={path!("progress")} view={ProgressDocPage} />
@@ -85,6 +86,7 @@ pub fn App() -> impl IntoView {
                         <Route path={path!("slider")} view={SliderDocPage} />
                         <Route path={path!("switch")} view={SwitchDocPage} />
                         <Route path={path!("tabs")} view={TabsDocPage} />
+                        <Route path={path!("tags-input")} view={TagsInputDocPage} />
                         <Route path={path!("toast")} view={ToastDocPage} />
                         <Route path={path!("toggle-group")} view={ToggleGroupDocPage} />
                         <Route path={path!("tooltip")} view={TooltipDocPage} />
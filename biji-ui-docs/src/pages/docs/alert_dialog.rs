@@ -163,13 +163,44 @@ const ACTION_PROPS: &[PropRow] = &[PropRow {
     name: "class",
     prop_type: "String",
     default: "\"\"",
-    description: "CSS class applied to the action button. Does not auto-close; wire your own handler.",
+    description: "CSS class applied to the action button. Does not auto-close under Root; wire your own handler. Under AlertDialogProvider it resolves the confirmation with true and closes.",
 }];
 
-const DATA_ATTRS: &[DataAttrRow] = &[DataAttrRow {
-    name: "data-state",
-    description: "\"open\" when the dialog is visible; \"closed\" when hidden. Present on Trigger.",
-}];
+const PROVIDER_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "template",
+        prop_type: "Fn(ConfirmOptions) -> impl IntoView",
+        default: "-",
+        description: "Builds the dialog from the alert_dialog parts for each use_confirm() call. Action resolves true; Cancel and Escape resolve false.",
+    },
+    PropRow {
+        name: "prevent_scroll",
+        prop_type: "bool",
+        default: "true",
+        description: "When true, prevents the page from scrolling while a confirmation is open.",
+    },
+    PropRow {
+        name: "hide_delay",
+        prop_type: "Duration",
+        default: "200ms",
+        description: "Animation unmount delay. Queued confirmations open after it elapses.",
+    },
+];
+
+const DATA_ATTRS: &[DataAttrRow] = &[
+    DataAttrRow {
+        name: "data-state",
+        description: "\"open\" when the dialog is visible; \"closed\" when hidden. Present on Trigger.",
+    },
+    DataAttrRow {
+        name: "data-nested",
+        description: "Present on Content while this modal is open on top of another dialog, alert dialog or drawer.",
+    },
+    DataAttrRow {
+        name: "data-nested-count",
+        description: "Number of modals currently open on top of this one. Present on Content.",
+    },
+];
 
 const KEYBOARD: &[KeyboardRow] = &[
     KeyboardRow {
@@ -219,6 +250,7 @@ pub fn AlertDialogDocPage() -> impl IntoView {
             <PropsTable title="Description" rows={DESCRIPTION_PROPS} />
             <PropsTable title="Cancel" rows={CANCEL_PROPS} />
             <PropsTable title="Action" rows={ACTION_PROPS} />
+            <PropsTable title="AlertDialogProvider" rows={PROVIDER_PROPS} />
             <DataAttrsTable rows={DATA_ATTRS} />
             <KeyboardTable rows={KEYBOARD} />
         </DocPage>
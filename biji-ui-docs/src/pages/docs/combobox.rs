@@ -105,6 +105,12 @@ const ROOT_PROPS: &[PropRow] = &[
         default: "false",
         description: "Set to true when using InputTrigger (the input sits above the dropdown).",
     },
+    PropRow {
+        name: "items",
+        prop_type: "Option<Collection>",
+        default: "None",
+        description: "Data-driven items rendered with Items and filtered by label and keywords. Accepts a Vec, Signal, RwSignal or Memo of CollectionItem<T>.",
+    },
 ];
 
 const TRIGGER_PROPS: &[PropRow] = &[PropRow {
@@ -172,6 +178,33 @@ const INPUT_PROPS: &[PropRow] = &[
     },
 ];
 
+const ITEMS_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "render",
+        prop_type: "Fn(CollectionItem<T>) -> impl IntoView",
+        default: "-",
+        description: "Renders the content of each item. Annotate the argument (e.g. |item: CollectionItem|) so T is known.",
+    },
+    PropRow {
+        name: "item_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to each rendered item.",
+    },
+    PropRow {
+        name: "group_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the wrapper of each group of items.",
+    },
+    PropRow {
+        name: "group_label_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to each group heading.",
+    },
+];
+
 const ITEM_PROPS: &[PropRow] = &[
     PropRow {
         name: "value",
@@ -199,6 +232,33 @@ const ITEM_PROPS: &[PropRow] = &[
     },
 ];
 
+const VIRTUAL_ITEMS_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "virtualizer",
+        prop_type: "Virtualizer",
+        default: "-",
+        description: "Windowing state created with Virtualizer::new(count, ItemSize). Keyboard navigation moves over every row while this part is mounted.",
+    },
+    PropRow {
+        name: "render",
+        prop_type: "Fn(usize) -> impl IntoView",
+        default: "-",
+        description: "Renders the Item for a row index. Only rows in view (plus overscan) are mounted.",
+    },
+    PropRow {
+        name: "key",
+        prop_type: "Option<Callback<usize, String>>",
+        default: "None",
+        description: "Stable identity for the row at an index. Pass it when filtering can change which item sits at an index.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the scroll container. It needs a bounded height, e.g. max-h-64.",
+    },
+];
+
 const DATA_ATTRS: &[DataAttrRow] = &[
     DataAttrRow {
         name: "data-state",
@@ -339,6 +399,8 @@ pub fn ComboboxDocPage() -> impl IntoView {
             <PropsTable title="Content" rows={CONTENT_PROPS} />
             <PropsTable title="Input" rows={INPUT_PROPS} />
             <PropsTable title="Item" rows={ITEM_PROPS} />
+            <PropsTable title="Items" rows={ITEMS_PROPS} />
+            <PropsTable title="VirtualItems" rows={VIRTUAL_ITEMS_PROPS} />
             <DataAttrsTable rows={DATA_ATTRS} />
             <KeyboardTable rows={KEYBOARD} />
         </DocPage>
@@ -51,12 +51,26 @@ pub fn MyCommand() -> impl IntoView {
     }
 }"#;
 
-const ROOT_PROPS: &[PropRow] = &[PropRow {
-    name: "class",
-    prop_type: "String",
-    default: "\"\"",
-    description: "CSS class applied to the root wrapper element.",
-}];
+const ROOT_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the root wrapper element.",
+    },
+    PropRow {
+        name: "history",
+        prop_type: "Option<CommandHistory>",
+        default: "None",
+        description: "Records selected values. Enables command::Recent, and command::Items ranks recent items first within their group while typing. Use CommandHistory::local_storage(key) or implement HistoryStore for custom persistence.",
+    },
+    PropRow {
+        name: "items",
+        prop_type: "Option<Collection>",
+        default: "None",
+        description: "Data-driven items rendered with Items and filtered by label and keywords. Accepts a Vec, Signal, RwSignal or Memo of CollectionItem<T>.",
+    },
+];
 
 const INPUT_PROPS: &[PropRow] = &[
     PropRow {
@@ -101,6 +115,39 @@ const GROUP_PROPS: &[PropRow] = &[
     },
 ];
 
+const ITEMS_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "render",
+        prop_type: "Fn(CollectionItem<T>) -> impl IntoView",
+        default: "-",
+        description: "Renders the content of each item. Annotate the argument (e.g. |item: CollectionItem|) so T is known.",
+    },
+    PropRow {
+        name: "on_select",
+        prop_type: "Option<Callback<String>>",
+        default: "None",
+        description: "Called with the value of the selected item.",
+    },
+    PropRow {
+        name: "item_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to each rendered item.",
+    },
+    PropRow {
+        name: "group_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the wrapper of each group of items.",
+    },
+    PropRow {
+        name: "group_label_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to each group heading.",
+    },
+];
+
 const ITEM_PROPS: &[PropRow] = &[
     PropRow {
         name: "value",
@@ -149,6 +196,113 @@ const HIGHLIGHTED_TEXT_PROPS: &[PropRow] = &[
     },
 ];
 
+const RECENT_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "label",
+        prop_type: "String",
+        default: "\"Recent\"",
+        description: "Heading text rendered above the recent items.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the group container element.",
+    },
+    PropRow {
+        name: "label_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the group label element.",
+    },
+    PropRow {
+        name: "item_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to each rendered item. Pinned items carry data-pinned.",
+    },
+];
+
+const VIRTUAL_ITEMS_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "virtualizer",
+        prop_type: "Virtualizer",
+        default: "-",
+        description: "Windowing state created with Virtualizer::new(count, ItemSize). Keyboard navigation moves over every row while this part is mounted.",
+    },
+    PropRow {
+        name: "render",
+        prop_type: "Fn(usize) -> impl IntoView",
+        default: "-",
+        description: "Renders the Item for a row index. Only rows in view (plus overscan) are mounted.",
+    },
+    PropRow {
+        name: "key",
+        prop_type: "Option<Callback<usize, String>>",
+        default: "None",
+        description: "Stable identity for the row at an index. Pass it when filtering can change which item sits at an index.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the scroll container. It needs a bounded height, e.g. max-h-64.",
+    },
+];
+
+const DIALOG_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the dialog wrapper element.",
+    },
+    PropRow {
+        name: "shortcut",
+        prop_type: "String",
+        default: "\"Mod+K\"",
+        description: "Global shortcut that toggles the dialog. Mod is Cmd on Apple platforms and Ctrl elsewhere. An empty string disables it.",
+    },
+    PropRow {
+        name: "prevent_scroll",
+        prop_type: "bool",
+        default: "true",
+        description: "Prevents page scrolling while the dialog is open.",
+    },
+    PropRow {
+        name: "hide_delay",
+        prop_type: "Duration",
+        default: "200ms",
+        description: "Delay before unmounting after close, to allow hide animations to finish.",
+    },
+];
+
+const DIALOG_USAGE_CODE: &str = r#"use leptos::prelude::*;
+use biji_ui::components::{command, dialog};
+
+#[component]
+pub fn MyCommandDialog() -> impl IntoView {
+    view! {
+        <command::Dialog shortcut="Mod+K">
+            <dialog::Trigger>"Open palette"</dialog::Trigger>
+            <dialog::Overlay class="fixed inset-0 bg-black/40" />
+            <dialog::Content class="fixed top-24 left-1/2 w-full max-w-md -translate-x-1/2">
+                <command::Root class="overflow-hidden rounded-lg border shadow-md border-border bg-background">
+                    <command::Input placeholder="Type a command..." class="py-2 px-3 w-full text-sm border-b outline-none" />
+                    <command::List class="overflow-y-auto p-1 max-h-64">
+                        <command::Item
+                            value="settings"
+                            on_select={Callback::new(|_| leptos::logging::log!("open settings"))}
+                        >
+                            "Settings"
+                        </command::Item>
+                    </command::List>
+                </command::Root>
+            </dialog::Content>
+        </command::Dialog>
+    }
+}"#;
+
 const HIGHLIGHTED_USAGE_CODE: &str = r#"use leptos::prelude::*;
 use biji_ui::components::command;
 
@@ -198,6 +352,10 @@ const DATA_ATTRS: &[DataAttrRow] = &[
         name: "data-disabled",
         description: "Present on Item when the item is disabled.",
     },
+    DataAttrRow {
+        name: "data-pinned",
+        description: "Present on Item when its value is pinned in the history.",
+    },
 ];
 
 const KEYBOARD: &[KeyboardRow] = &[
@@ -221,6 +379,10 @@ const KEYBOARD: &[KeyboardRow] = &[
         key: "Enter",
         description: "Selects the focused item and fires on_select.",
     },
+    KeyboardRow {
+        key: "Mod+K",
+        description: "Toggles a command::Dialog (configurable via the shortcut prop).",
+    },
 ];
 
 #[component]
@@ -260,13 +422,27 @@ pub fn CommandDocPage() -> impl IntoView {
                 code={HIGHLIGHTED_USAGE_CODE}
                 language="rust"
             />
+            <SectionHeading title="Command Dialog" />
+            <p class="mb-4 text-sm text-muted-foreground">
+                "Wrap the palette in " <code class="font-mono text-foreground">"command::Dialog"</code>
+                " to open it with a global shortcut. The input is focused on open, the query is cleared on close, selecting an item closes the dialog, and focus returns to the previously focused element."
+            </p>
+            <Code
+                class="[&>.shiki]:overflow-x-auto [&>.shiki]:p-4 [&>.shiki]:rounded-lg [&>.shiki]:text-sm"
+                code={DIALOG_USAGE_CODE}
+                language="rust"
+            />
             <SectionHeading title="API Reference" />
             <PropsTable title="Root" rows={ROOT_PROPS} />
             <PropsTable title="Input" rows={INPUT_PROPS} />
             <PropsTable title="List" rows={LIST_PROPS} />
             <PropsTable title="Group" rows={GROUP_PROPS} />
             <PropsTable title="Item" rows={ITEM_PROPS} />
+            <PropsTable title="Items" rows={ITEMS_PROPS} />
+            <PropsTable title="VirtualItems" rows={VIRTUAL_ITEMS_PROPS} />
             <PropsTable title="HighlightedText" rows={HIGHLIGHTED_TEXT_PROPS} />
+            <PropsTable title="Recent" rows={RECENT_PROPS} />
+            <PropsTable title="Dialog" rows={DIALOG_PROPS} />
             <DataAttrsTable rows={DATA_ATTRS} />
             <KeyboardTable rows={KEYBOARD} />
         </DocPage>
@@ -73,6 +73,30 @@ const ROOT_PROPS: &[PropRow] = &[
         default: "None",
         description: "Fired with true when the menu opens and false when it closes.",
     },
+    PropRow {
+        name: "items",
+        prop_type: "Option<Collection>",
+        default: "None",
+        description: "Data-driven items rendered with Items. Accepts a Vec, Signal, RwSignal or Memo of CollectionItem<T>.",
+    },
+    PropRow {
+        name: "long_press_threshold",
+        prop_type: "Duration",
+        default: "500ms",
+        description: "How long a touch must rest on the Trigger to open the menu at the touch point. Needed on iOS Safari, which does not fire contextmenu.",
+    },
+    PropRow {
+        name: "long_press_tolerance",
+        prop_type: "f64",
+        default: "10.0",
+        description: "How far in CSS pixels the touch may move before the long press is cancelled.",
+    },
+    PropRow {
+        name: "shortcuts",
+        prop_type: "Vec<MenuShortcut>",
+        default: "[]",
+        description: "Shortcuts that run their action when pressed, also while the menu is closed. Build each with MenuShortcut::new(keys, action) and optionally .disabled(signal). Requires a hotkeys::Provider.",
+    },
 ];
 
 const TRIGGER_PROPS: &[PropRow] = &[PropRow {
@@ -103,6 +127,151 @@ const CONTENT_PROPS: &[PropRow] = &[
     },
 ];
 
+const CHECKBOX_ITEM_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "checked",
+        prop_type: "RwSignal<bool>",
+        default: "-",
+        description: "Whether the item is checked. Toggled on select.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the item element.",
+    },
+    PropRow {
+        name: "disabled",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, prevents the item from being selected.",
+    },
+    PropRow {
+        name: "close_on_select",
+        prop_type: "bool",
+        default: "true",
+        description: "Close the menu after toggling. Set to false to keep it open.",
+    },
+    PropRow {
+        name: "on_checked_change",
+        prop_type: "Option<Callback<bool>>",
+        default: "None",
+        description: "Called with the new checked state.",
+    },
+    PropRow {
+        name: "shortcut",
+        prop_type: "Option<String>",
+        default: "None",
+        description: "Keyboard shortcut such as \"Mod+Shift+P\" or \"g then i\", shown after the children. Declare it in the shortcuts of Root as well to make pressing it toggle the item.",
+    },
+    PropRow {
+        name: "shortcut_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the shortcut hint span.",
+    },
+];
+
+const RADIO_GROUP_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "value",
+        prop_type: "RwSignal<String>",
+        default: "-",
+        description: "Value of the checked RadioItem.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the group element.",
+    },
+    PropRow {
+        name: "on_value_change",
+        prop_type: "Option<Callback<String>>",
+        default: "None",
+        description: "Called with the value of the newly checked item.",
+    },
+];
+
+const RADIO_ITEM_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "value",
+        prop_type: "String",
+        default: "-",
+        description: "Value written to the RadioGroup when the item is selected.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the item element.",
+    },
+    PropRow {
+        name: "disabled",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, prevents the item from being selected.",
+    },
+    PropRow {
+        name: "close_on_select",
+        prop_type: "bool",
+        default: "true",
+        description: "Close the menu after selecting. Set to false to keep it open.",
+    },
+    PropRow {
+        name: "shortcut",
+        prop_type: "Option<String>",
+        default: "None",
+        description: "Keyboard shortcut such as \"Mod+Shift+P\" or \"g then i\", shown after the children. Declare it in the shortcuts of Root as well to make pressing it select the item.",
+    },
+    PropRow {
+        name: "shortcut_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the shortcut hint span.",
+    },
+];
+
+const ITEM_INDICATOR_PROPS: &[PropRow] = &[PropRow {
+    name: "class",
+    prop_type: "String",
+    default: "\"\"",
+    description: "CSS class applied to the indicator. Children render only while the item is checked.",
+}];
+
+const ITEMS_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "render",
+        prop_type: "Fn(CollectionItem<T>) -> impl IntoView",
+        default: "-",
+        description: "Renders the content of each item. Annotate the argument (e.g. |item: CollectionItem|) so T is known.",
+    },
+    PropRow {
+        name: "on_select",
+        prop_type: "Option<Callback<String>>",
+        default: "None",
+        description: "Called with the value of the selected item.",
+    },
+    PropRow {
+        name: "item_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to each rendered item.",
+    },
+    PropRow {
+        name: "group_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the wrapper of each group of items.",
+    },
+    PropRow {
+        name: "group_label_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to each group heading.",
+    },
+];
+
 const ITEM_PROPS: &[PropRow] = &[
     PropRow {
         name: "class",
@@ -122,21 +291,99 @@ const ITEM_PROPS: &[PropRow] = &[
         default: "None",
         description: "Fired when the item is selected by click or Enter/Space.",
     },
+    PropRow {
+        name: "shortcut",
+        prop_type: "Option<String>",
+        default: "None",
+        description: "Keyboard shortcut such as \"Mod+Shift+P\" or \"g then i\", shown after the children. Declare it in the shortcuts of Root as well to make pressing it select the item.",
+    },
+    PropRow {
+        name: "shortcut_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the shortcut hint span.",
+    },
+];
+
+const SUB_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "disabled",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, the SubTrigger is skipped during keyboard navigation and does not open the submenu.",
+    },
+    PropRow {
+        name: "positioning",
+        prop_type: "Positioning",
+        default: "RightStart",
+        description: "Where SubContent is placed relative to SubTrigger.",
+    },
+    PropRow {
+        name: "avoid_collisions",
+        prop_type: "AvoidCollisions",
+        default: "Flip",
+        description: "How SubContent reacts when it would overflow the viewport.",
+    },
+    PropRow {
+        name: "hide_delay",
+        prop_type: "Duration",
+        default: "200ms",
+        description: "How long to wait before unmounting SubContent after it closes. Should match your CSS transition duration.",
+    },
+];
+
+const SUB_TRIGGER_PROPS: &[PropRow] = &[PropRow {
+    name: "class",
+    prop_type: "String",
+    default: "\"\"",
+    description: "CSS class applied to the item that opens the submenu.",
+}];
+
+const SUB_CONTENT_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the submenu panel.",
+    },
+    PropRow {
+        name: "show_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied when the submenu is visible.",
+    },
+    PropRow {
+        name: "hide_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied when the submenu is hidden.",
+    },
 ];
 
+const SHORTCUT_PROPS: &[PropRow] = &[PropRow {
+    name: "class",
+    prop_type: "String",
+    default: "\"\"",
+    description: "CSS class applied to the shortcut hint, e.g. ml-auto to right-align it inside a flex Item.",
+}];
+
 const DATA_ATTRS: &[DataAttrRow] = &[
     DataAttrRow {
         name: "data-state",
-        description: "\"open\" or \"closed\". Present on Trigger.",
+        description: "\"open\" or \"closed\" on Trigger and SubTrigger; \"checked\" or \"unchecked\" on CheckboxItem and RadioItem.",
     },
     DataAttrRow {
         name: "data-highlighted",
-        description: "Present on Item when it has keyboard focus or the mouse is over it.",
+        description: "Present on Item and SubTrigger when it has keyboard focus or the mouse is over it.",
     },
     DataAttrRow {
         name: "data-disabled",
         description: "Present on Item when it is disabled.",
     },
+    DataAttrRow {
+        name: "data-shortcut",
+        description: "Present on the hint span rendered by the shortcut prop.",
+    },
 ];
 
 const KEYBOARD: &[KeyboardRow] = &[
@@ -158,7 +405,15 @@ const KEYBOARD: &[KeyboardRow] = &[
     },
     KeyboardRow {
         key: "Enter / Space",
-        description: "Activates the focused item and closes the menu.",
+        description: "Activates the focused item and closes the menu. On a SubTrigger, opens the submenu.",
+    },
+    KeyboardRow {
+        key: "ArrowRight",
+        description: "On a SubTrigger, opens the submenu and focuses its first item.",
+    },
+    KeyboardRow {
+        key: "ArrowLeft",
+        description: "Inside a submenu, closes it and returns focus to its SubTrigger.",
     },
     KeyboardRow {
         key: "Escape",
@@ -199,6 +454,15 @@ pub fn ContextMenuDocPage() -> impl IntoView {
             <PropsTable title="Trigger" rows={TRIGGER_PROPS} />
             <PropsTable title="Content" rows={CONTENT_PROPS} />
             <PropsTable title="Item" rows={ITEM_PROPS} />
+            <PropsTable title="Items" rows={ITEMS_PROPS} />
+            <PropsTable title="CheckboxItem" rows={CHECKBOX_ITEM_PROPS} />
+            <PropsTable title="RadioGroup" rows={RADIO_GROUP_PROPS} />
+            <PropsTable title="RadioItem" rows={RADIO_ITEM_PROPS} />
+            <PropsTable title="ItemIndicator" rows={ITEM_INDICATOR_PROPS} />
+            <PropsTable title="Sub" rows={SUB_PROPS} />
+            <PropsTable title="SubTrigger" rows={SUB_TRIGGER_PROPS} />
+            <PropsTable title="SubContent" rows={SUB_CONTENT_PROPS} />
+            <PropsTable title="Shortcut" rows={SHORTCUT_PROPS} />
             <DataAttrsTable rows={DATA_ATTRS} />
             <KeyboardTable rows={KEYBOARD} />
         </DocPage>
@@ -3,7 +3,10 @@ use std::time::Duration;
 use leptos::{portal::Portal, prelude::*};
 
 use crate::components::{
-    api_table::{KeyboardRow, KeyboardTable, PropRow, PropsTable, SectionHeading},
+    api_table::{
+        DataAttrRow, DataAttrsTable, KeyboardRow, KeyboardTable, PropRow, PropsTable,
+        SectionHeading,
+    },
     button::{Variant, btn},
     code::Code,
 };
@@ -66,6 +69,24 @@ const ROOT_PROPS: &[PropRow] = &[
         default: "true",
         description: "When true, prevents the page from scrolling while the dialog is open.",
     },
+    PropRow {
+        name: "open",
+        prop_type: "Option<RwSignal<bool>>",
+        default: "None",
+        description: "Controlled open state. Set it to open or close the dialog from route changes, server responses or shortcuts.",
+    },
+    PropRow {
+        name: "on_open_change",
+        prop_type: "Option<Callback<bool>>",
+        default: "None",
+        description: "Called with `true` when the dialog opens and `false` when it closes.",
+    },
+    PropRow {
+        name: "before_close",
+        prop_type: "Option<Callback<CloseReason, bool>>",
+        default: "None",
+        description: "Called before closing via Escape, the overlay, Close or Trigger. Return false to keep the dialog open.",
+    },
 ];
 
 const TRIGGER_PROPS: &[PropRow] = &[PropRow {
@@ -124,6 +145,17 @@ const CLOSE_PROPS: &[PropRow] = &[PropRow {
     description: "CSS class applied to the close button.",
 }];
 
+const DATA_ATTRS: &[DataAttrRow] = &[
+    DataAttrRow {
+        name: "data-nested",
+        description: "Present on Content while this modal is open on top of another dialog, alert dialog or drawer.",
+    },
+    DataAttrRow {
+        name: "data-nested-count",
+        description: "Number of modals currently open on top of this one. Present on Content.",
+    },
+];
+
 const KEYBOARD: &[KeyboardRow] = &[
     KeyboardRow {
         key: "Tab",
@@ -169,6 +201,7 @@ pub fn DialogDocPage() -> impl IntoView {
             <PropsTable title="Content" rows={CONTENT_PROPS} />
             <PropsTable title="Overlay" rows={OVERLAY_PROPS} />
             <PropsTable title="Close" rows={CLOSE_PROPS} />
+            <DataAttrsTable rows={DATA_ATTRS} />
             <KeyboardTable rows={KEYBOARD} />
         </DocPage>
     }
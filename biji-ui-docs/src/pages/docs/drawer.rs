@@ -94,6 +94,30 @@ const ROOT_PROPS: &[PropRow] = &[
         default: "None",
         description: "Called with `true` when opening and `false` when closing.",
     },
+    PropRow {
+        name: "draggable",
+        prop_type: "bool",
+        default: "false",
+        description: "Whether Content can be dragged along its side's axis with a pointer or touch. Inside a scrollable element, a drag starts once it is scrolled to its edge.",
+    },
+    PropRow {
+        name: "swipe_to_dismiss",
+        prop_type: "bool",
+        default: "true",
+        description: "With `draggable`, whether dragging Content towards its edge, or flinging it, closes the drawer.",
+    },
+    PropRow {
+        name: "snap_points",
+        prop_type: "Vec<SnapPoint>",
+        default: "[]",
+        description: "Positions the open panel rests at, smallest first, as `SnapPoint::Fraction` of the viewport or `SnapPoint::Pixels` visible. Empty means fully open only.",
+    },
+    PropRow {
+        name: "active_snap_point",
+        prop_type: "Option<RwSignal<usize>>",
+        default: "None",
+        description: "Index into `snap_points` the panel rests at. Pass a signal to control it or observe drags.",
+    },
 ];
 
 const TRIGGER_PROPS: &[PropRow] = &[PropRow {
@@ -161,6 +185,30 @@ const DATA_ATTRS: &[DataAttrRow] = &[
         name: "data-side",
         description: "\"top\" | \"right\" | \"bottom\" | \"left\". Present on Content.",
     },
+    DataAttrRow {
+        name: "data-nested",
+        description: "Present on Content while this modal is open on top of another dialog, alert dialog or drawer.",
+    },
+    DataAttrRow {
+        name: "data-nested-count",
+        description: "Number of modals currently open on top of this one. Present on Content.",
+    },
+    DataAttrRow {
+        name: "data-nested-open",
+        description: "Present on Content while a drawer nested inside this one is open. Content also gets `--biji-drawer-nested-scale` and `--biji-drawer-nested-offset` to recede with.",
+    },
+    DataAttrRow {
+        name: "data-dragging",
+        description: "Present on Content while it is being dragged.",
+    },
+    DataAttrRow {
+        name: "data-snap-point",
+        description: "Index of the snap point Content rests at. Present on Content when `snap_points` is set.",
+    },
+    DataAttrRow {
+        name: "data-drawer-no-drag",
+        description: "Add to an element inside Content to stop presses on it from starting a drag.",
+    },
 ];
 
 const KEYBOARD: &[KeyboardRow] = &[
@@ -326,7 +374,7 @@ pub fn DrawerSidesExample() -> impl IntoView {
     view! {
         <div class="flex flex-wrap items-center justify-center gap-3 p-8">
             // Top
-            <drawer::Root side={DrawerSide::Top}>
+            <drawer::Root side={DrawerSide::Top} draggable=true>
                 <drawer::Trigger class="inline-flex items-center gap-2 px-4 py-2 text-sm font-medium \
                                         rounded-md border border-border bg-background \
                                         hover:bg-accent transition-colors">
@@ -365,7 +413,7 @@ pub fn DrawerSidesExample() -> impl IntoView {
             </drawer::Root>
 
             // Right
-            <drawer::Root side={DrawerSide::Right}>
+            <drawer::Root side={DrawerSide::Right} draggable=true>
                 <drawer::Trigger class="inline-flex items-center gap-2 px-4 py-2 text-sm font-medium \
                                         rounded-md border border-border bg-background \
                                         hover:bg-accent transition-colors">
@@ -404,7 +452,7 @@ pub fn DrawerSidesExample() -> impl IntoView {
             </drawer::Root>
 
             // Bottom
-            <drawer::Root side={DrawerSide::Bottom}>
+            <drawer::Root side={DrawerSide::Bottom} draggable=true>
                 <drawer::Trigger class="inline-flex items-center gap-2 px-4 py-2 text-sm font-medium \
                                         rounded-md border border-border bg-background \
                                         hover:bg-accent transition-colors">
@@ -443,7 +491,7 @@ pub fn DrawerSidesExample() -> impl IntoView {
             </drawer::Root>
 
             // Left
-            <drawer::Root side={DrawerSide::Left}>
+            <drawer::Root side={DrawerSide::Left} draggable=true>
                 <drawer::Trigger class="inline-flex items-center gap-2 px-4 py-2 text-sm font-medium \
                                         rounded-md border border-border bg-background \
                                         hover:bg-accent transition-colors">
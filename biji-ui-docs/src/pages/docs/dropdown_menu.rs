@@ -95,6 +95,12 @@ const MENU_PROPS: &[PropRow] = &[
         default: "false",
         description: "When true, prevents the page from scrolling while the menu is open.",
     },
+    PropRow {
+        name: "shortcuts",
+        prop_type: "Vec<MenuShortcut>",
+        default: "[]",
+        description: "Shortcuts that run their action when pressed, also while the menu is closed. Build each with MenuShortcut::new(keys, action) and optionally .disabled(signal). Requires a hotkeys::Provider.",
+    },
 ];
 
 const TRIGGER_PROPS: &[PropRow] = &[PropRow {
@@ -159,6 +165,24 @@ const ITEM_PROPS: &[PropRow] = &[
         default: "false",
         description: "Prevents interaction with the item and applies data-disabled.",
     },
+    PropRow {
+        name: "on_select",
+        prop_type: "Option<Callback<()>>",
+        default: "None",
+        description: "Fired when the item is clicked or activated with Enter.",
+    },
+    PropRow {
+        name: "shortcut",
+        prop_type: "Option<String>",
+        default: "None",
+        description: "Keyboard shortcut such as \"Mod+Shift+P\" or \"g then i\", shown after the children. Declare it in the shortcuts of Menu as well to make pressing it select the item.",
+    },
+    PropRow {
+        name: "shortcut_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the shortcut hint span.",
+    },
 ];
 
 const SUBMENU_PROPS: &[PropRow] = &[
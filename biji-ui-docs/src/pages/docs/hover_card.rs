@@ -95,6 +95,12 @@ const ROOT_PROPS: &[PropRow] = &[
         default: "None",
         description: "Called with `true` when the card opens and `false` when it closes.",
     },
+    PropRow {
+        name: "long_press_threshold",
+        prop_type: "Duration",
+        default: "500ms",
+        description: "How long a touch must rest on the trigger to open. Taps outside close it again.",
+    },
 ];
 
 const TRIGGER_PROPS: &[PropRow] = &[PropRow {
@@ -125,6 +131,13 @@ const CONTENT_PROPS: &[PropRow] = &[
     },
 ];
 
+const ANCHOR_PROPS: &[PropRow] = &[PropRow {
+    name: "class",
+    prop_type: "String",
+    default: "\"\"",
+    description: "CSS class applied to the anchor element. When rendered, Content is positioned against the anchor instead of the Trigger; the Trigger still opens the card on hover.",
+}];
+
 const ARROW_PROPS: &[PropRow] = &[PropRow {
     name: "class",
     prop_type: "String",
@@ -132,10 +145,16 @@ const ARROW_PROPS: &[PropRow] = &[PropRow {
     description: "CSS class applied to the arrow `<div>`.",
 }];
 
-const DATA_ATTRS: &[DataAttrRow] = &[DataAttrRow {
-    name: "data-state",
-    description: "\"open\" when the card is visible; \"closed\" otherwise. Present on Trigger.",
-}];
+const DATA_ATTRS: &[DataAttrRow] = &[
+    DataAttrRow {
+        name: "data-state",
+        description: "\"open\" when the card is visible; \"closed\" otherwise. Present on Trigger.",
+    },
+    DataAttrRow {
+        name: "data-pointer-type",
+        description: "\"mouse\", \"touch\" or \"pen\" — the pointer that last interacted with the trigger. Present on Content.",
+    },
+];
 
 #[component]
 pub fn HoverCardDocPage() -> impl IntoView {
@@ -164,6 +183,7 @@ pub fn HoverCardDocPage() -> impl IntoView {
             <SectionHeading title="API Reference" />
             <PropsTable title="Root" rows={ROOT_PROPS} />
             <PropsTable title="Trigger" rows={TRIGGER_PROPS} />
+            <PropsTable title="Anchor" rows={ANCHOR_PROPS} />
             <PropsTable title="Content" rows={CONTENT_PROPS} />
             <PropsTable title="Arrow" rows={ARROW_PROPS} />
             <DataAttrsTable rows={DATA_ATTRS} />
@@ -106,6 +106,12 @@ const ROOT_PROPS: &[PropRow] = &[
         default: "false",
         description: "When true, prevents the page from scrolling while any menu is open.",
     },
+    PropRow {
+        name: "shortcuts",
+        prop_type: "Vec<MenuShortcut>",
+        default: "[]",
+        description: "Shortcuts that run their action when pressed, also while every menu is closed. Build each with MenuShortcut::new(keys, action) and optionally .disabled(signal). Requires a hotkeys::Provider.",
+    },
 ];
 
 const MENU_PROPS: &[PropRow] = &[
@@ -139,6 +145,12 @@ const MENU_PROPS: &[PropRow] = &[
         default: "200ms",
         description: "How long to wait before unmounting the content after closing begins. Should match your CSS transition duration.",
     },
+    PropRow {
+        name: "items",
+        prop_type: "Option<Collection>",
+        default: "None",
+        description: "Data-driven items rendered with Items inside this menu's Content. Accepts a Vec, Signal, RwSignal or Memo of CollectionItem<T>.",
+    },
 ];
 
 const TRIGGER_PROPS: &[PropRow] = &[PropRow {
@@ -169,6 +181,151 @@ const CONTENT_PROPS: &[PropRow] = &[
     },
 ];
 
+const CHECKBOX_ITEM_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "checked",
+        prop_type: "RwSignal<bool>",
+        default: "-",
+        description: "Whether the item is checked. Toggled on select.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the item element.",
+    },
+    PropRow {
+        name: "disabled",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, prevents the item from being selected.",
+    },
+    PropRow {
+        name: "close_on_select",
+        prop_type: "bool",
+        default: "true",
+        description: "Close the menu after toggling. Set to false to keep it open.",
+    },
+    PropRow {
+        name: "on_checked_change",
+        prop_type: "Option<Callback<bool>>",
+        default: "None",
+        description: "Called with the new checked state.",
+    },
+    PropRow {
+        name: "shortcut",
+        prop_type: "Option<String>",
+        default: "None",
+        description: "Keyboard shortcut such as \"Mod+Shift+P\" or \"g then i\", shown after the children. Declare it in the shortcuts of Root as well to make pressing it toggle the item.",
+    },
+    PropRow {
+        name: "shortcut_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the shortcut hint span.",
+    },
+];
+
+const RADIO_GROUP_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "value",
+        prop_type: "RwSignal<String>",
+        default: "-",
+        description: "Value of the checked RadioItem.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the group element.",
+    },
+    PropRow {
+        name: "on_value_change",
+        prop_type: "Option<Callback<String>>",
+        default: "None",
+        description: "Called with the value of the newly checked item.",
+    },
+];
+
+const RADIO_ITEM_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "value",
+        prop_type: "String",
+        default: "-",
+        description: "Value written to the RadioGroup when the item is selected.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the item element.",
+    },
+    PropRow {
+        name: "disabled",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, prevents the item from being selected.",
+    },
+    PropRow {
+        name: "close_on_select",
+        prop_type: "bool",
+        default: "true",
+        description: "Close the menu after selecting. Set to false to keep it open.",
+    },
+    PropRow {
+        name: "shortcut",
+        prop_type: "Option<String>",
+        default: "None",
+        description: "Keyboard shortcut such as \"Mod+Shift+P\" or \"g then i\", shown after the children. Declare it in the shortcuts of Root as well to make pressing it select the item.",
+    },
+    PropRow {
+        name: "shortcut_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the shortcut hint span.",
+    },
+];
+
+const ITEM_INDICATOR_PROPS: &[PropRow] = &[PropRow {
+    name: "class",
+    prop_type: "String",
+    default: "\"\"",
+    description: "CSS class applied to the indicator. Children render only while the item is checked.",
+}];
+
+const ITEMS_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "render",
+        prop_type: "Fn(CollectionItem<T>) -> impl IntoView",
+        default: "-",
+        description: "Renders the content of each item. Annotate the argument (e.g. |item: CollectionItem|) so T is known.",
+    },
+    PropRow {
+        name: "on_select",
+        prop_type: "Option<Callback<String>>",
+        default: "None",
+        description: "Called with the value of the selected item.",
+    },
+    PropRow {
+        name: "item_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to each rendered item.",
+    },
+    PropRow {
+        name: "group_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the wrapper of each group of items.",
+    },
+    PropRow {
+        name: "group_label_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to each group heading.",
+    },
+];
+
 const ITEM_PROPS: &[PropRow] = &[
     PropRow {
         name: "class",
@@ -182,6 +339,24 @@ const ITEM_PROPS: &[PropRow] = &[
         default: "false",
         description: "Prevents interaction with the item and applies data-disabled.",
     },
+    PropRow {
+        name: "on_select",
+        prop_type: "Option<Callback<()>>",
+        default: "None",
+        description: "Fired when the item is clicked or activated with Enter.",
+    },
+    PropRow {
+        name: "shortcut",
+        prop_type: "Option<String>",
+        default: "None",
+        description: "Keyboard shortcut such as \"Mod+Shift+P\" or \"g then i\", shown after the children. Declare it in the shortcuts of Root as well to make pressing it select the item.",
+    },
+    PropRow {
+        name: "shortcut_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the shortcut hint span.",
+    },
 ];
 
 const SUBMENU_PROPS: &[PropRow] = &[
@@ -252,6 +427,10 @@ const DATA_ATTRS: &[DataAttrRow] = &[
         name: "data-open",
         description: "Present on Trigger with value true when its menu is open.",
     },
+    DataAttrRow {
+        name: "data-shortcut",
+        description: "Present on the hint span rendered by the shortcut prop.",
+    },
 ];
 
 const KEYBOARD: &[KeyboardRow] = &[
@@ -311,6 +490,11 @@ pub fn MenubarDocPage() -> impl IntoView {
             <PropsTable title="Trigger" rows={TRIGGER_PROPS} />
             <PropsTable title="Content" rows={CONTENT_PROPS} />
             <PropsTable title="Item" rows={ITEM_PROPS} />
+            <PropsTable title="Items" rows={ITEMS_PROPS} />
+            <PropsTable title="CheckboxItem" rows={CHECKBOX_ITEM_PROPS} />
+            <PropsTable title="RadioGroup" rows={RADIO_GROUP_PROPS} />
+            <PropsTable title="RadioItem" rows={RADIO_ITEM_PROPS} />
+            <PropsTable title="ItemIndicator" rows={ITEM_INDICATOR_PROPS} />
             <PropsTable title="SubMenu" rows={SUBMENU_PROPS} />
             <PropsTable title="SubMenuTrigger" rows={SUBMENU_TRIGGER_PROPS} />
             <PropsTable title="SubMenuContent" rows={CONTENT_PROPS} />
@@ -19,6 +19,7 @@ pub mod getting_started;
 pub mod hover_card;
 pub mod menubar;
 pub mod navigation_menu;
+pub mod number_field;
 pub mod pin_input;
 pub mod popover;
 pub mod progress;
@@ -28,6 +29,7 @@ pub mod separator;
 pub mod slider;
 pub mod switch;
 pub mod tabs;
+pub mod tags_input;
 pub mod toast;
 pub mod toggle_group;
 pub mod tooltip;
@@ -63,6 +65,7 @@ pub const COMPONENT_PAGES: &[(&str, &str)] = &[
     ("/docs/hover-card", "Hover Card"),
     ("/docs/menubar", "Menubar"),
     ("/docs/navigation-menu", "Navigation Menu"),
+    ("/docs/number-field", "Number Field"),
     ("/docs/pin-input", "PIN Input"),
     ("/docs/popover", "Popover"),
     ("/docs/progress", "Progress"),
@@ -72,6 +75,7 @@ pub const COMPONENT_PAGES: &[(&str, &str)] = &[
     ("/docs/slider", "Slider"),
     ("/docs/switch", "Switch"),
     ("/docs/tabs", "Tabs"),
+    ("/docs/tags-input", "Tags Input"),
     ("/docs/toast", "Toast"),
     ("/docs/toggle-group", "Toggle Group"),
     ("/docs/tooltip", "Tooltip"),
@@ -75,11 +75,17 @@ const ROOT_PROPS: &[PropRow] = &[
         default: "None",
         description: "Value of the `aria-label` attribute on the `<nav>` element.",
     },
+    PropRow {
+        name: "orientation",
+        prop_type: "Orientation",
+        default: "Horizontal",
+        description: "Layout of the list. Vertical menus move between triggers with ArrowUp/ArrowDown and open a panel to the side with ArrowRight.",
+    },
     PropRow {
         name: "positioning",
         prop_type: "Positioning",
-        default: "BottomStart",
-        description: "Where to render each Content panel relative to its Trigger.",
+        default: "BottomStart / RightStart",
+        description: "Where to render each Content panel relative to its Trigger. Defaults to BottomStart when horizontal and RightStart when vertical.",
     },
     PropRow {
         name: "avoid_collisions",
@@ -150,6 +156,69 @@ const CONTENT_PROPS: &[PropRow] = &[
     },
 ];
 
+const VIEWPORT_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied in both open and closed states. Size it with var(--biji-navigation-menu-viewport-width) and var(--biji-navigation-menu-viewport-height).",
+    },
+    PropRow {
+        name: "show_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied while any panel is open.",
+    },
+    PropRow {
+        name: "hide_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied while the viewport is closing.",
+    },
+];
+
+const INDICATOR_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the indicator `<li>`. Place it inside a `relative` List.",
+    },
+    PropRow {
+        name: "show_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied while an item is open.",
+    },
+    PropRow {
+        name: "hide_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied while no item is open.",
+    },
+];
+
+const SUB_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the wrapper `<div>`.",
+    },
+    PropRow {
+        name: "orientation",
+        prop_type: "Orientation",
+        default: "Horizontal",
+        description: "Layout of the sub menu's List.",
+    },
+    PropRow {
+        name: "positioning",
+        prop_type: "Positioning",
+        default: "BottomStart / RightStart",
+        description: "Where the sub menu's panels open, defaulting by orientation.",
+    },
+];
+
 const LINK_PROPS: &[PropRow] = &[
     PropRow {
         name: "href",
@@ -175,12 +244,34 @@ const LINK_PROPS: &[PropRow] = &[
         default: "true",
         description: "When true, clicking the link closes any open content panel.",
     },
+    PropRow {
+        name: "active_match",
+        prop_type: "ActiveMatch",
+        default: "Exact",
+        description: "With the router feature: Exact marks the link active only on its own path, Prefix also on paths below it.",
+    },
 ];
 
 const DATA_ATTRS: &[DataAttrRow] = &[
     DataAttrRow {
         name: "data-state",
-        description: "\"open\" when the item's panel is visible; \"closed\" otherwise. Present on Trigger.",
+        description: "\"open\" when the item's panel is visible; \"closed\" otherwise. Present on Trigger, Content and Viewport.",
+    },
+    DataAttrRow {
+        name: "data-active",
+        description: "Present on Link while the current route matches its href. Requires the router feature, which also sets aria-current=\"page\".",
+    },
+    DataAttrRow {
+        name: "data-orientation",
+        description: "\"horizontal\" or \"vertical\" on Root, Sub, List and Indicator.",
+    },
+    DataAttrRow {
+        name: "data-motion",
+        description: "\"from-start\", \"from-end\", \"to-start\" or \"to-end\" on Content while the open item switches, following the order of the items.",
+    },
+    DataAttrRow {
+        name: "data-state (Indicator)",
+        description: "\"visible\" while an item is open; \"hidden\" otherwise.",
     },
     DataAttrRow {
         name: "data-disabled",
@@ -207,7 +298,11 @@ const KEYBOARD: &[KeyboardRow] = &[
     },
     KeyboardRow {
         key: "ArrowDown",
-        description: "Opens the current item's Content panel and focuses the first focusable element inside it.",
+        description: "Opens the current item's Content panel and focuses the first focusable element inside it. In a vertical menu, moves focus to the next Trigger or Link instead.",
+    },
+    KeyboardRow {
+        key: "ArrowRight (vertical)",
+        description: "Opens the current item's Content panel to the side and focuses its first focusable element.",
     },
     KeyboardRow {
         key: "Home",
@@ -223,7 +318,7 @@ const KEYBOARD: &[KeyboardRow] = &[
     },
     KeyboardRow {
         key: "Escape",
-        description: "Closes the open panel and returns focus to its Trigger.",
+        description: "Closes the innermost open panel, including Sub menus, and returns focus to its Trigger.",
     },
 ];
 
@@ -257,6 +352,9 @@ pub fn NavigationMenuDocPage() -> impl IntoView {
             <PropsTable title="Item" rows={ITEM_PROPS} />
             <PropsTable title="Trigger" rows={TRIGGER_PROPS} />
             <PropsTable title="Content" rows={CONTENT_PROPS} />
+            <PropsTable title="Viewport" rows={VIEWPORT_PROPS} />
+            <PropsTable title="Indicator" rows={INDICATOR_PROPS} />
+            <PropsTable title="Sub" rows={SUB_PROPS} />
             <PropsTable title="Link" rows={LINK_PROPS} />
             <DataAttrsTable rows={DATA_ATTRS} />
             <KeyboardTable rows={KEYBOARD} />
@@ -0,0 +1,229 @@
+use leptos::prelude::*;
+
+use crate::components::{
+    api_table::{
+        DataAttrRow, DataAttrsTable, KeyboardRow, KeyboardTable, PropRow, PropsTable,
+        SectionHeading,
+    },
+    code::Code,
+};
+
+const INSTALL_CODE: &str = concat!(
+    "biji-ui = { version = \"",
+    env!("CARGO_PKG_VERSION"),
+    "\", features = [\"number_field\"] }",
+);
+
+const USAGE_CODE: &str = r#"use leptos::prelude::*;
+use biji_ui::components::number_field::{self, NumberFormat};
+
+#[component]
+pub fn MyNumberField() -> impl IntoView {
+    view! {
+        <number_field::Root
+            value=1234.5
+            min=0.0
+            step=0.5
+            format={NumberFormat::default().currency("$").fraction_digits(2, 2)}
+            class="flex items-center rounded-md border border-input"
+        >
+            <number_field::Decrement class="px-3 py-2">"−"</number_field::Decrement>
+            <number_field::Input class="w-28 bg-transparent text-center outline-none" />
+            <number_field::Increment class="px-3 py-2">"+"</number_field::Increment>
+        </number_field::Root>
+    }
+}"#;
+
+const ROOT_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the root element.",
+    },
+    PropRow {
+        name: "value",
+        prop_type: "Option<f64>",
+        default: "None",
+        description: "The initial value. The field starts empty without one.",
+    },
+    PropRow {
+        name: "min",
+        prop_type: "f64",
+        default: "f64::NEG_INFINITY",
+        description: "The minimum value.",
+    },
+    PropRow {
+        name: "max",
+        prop_type: "f64",
+        default: "f64::INFINITY",
+        description: "The maximum value.",
+    },
+    PropRow {
+        name: "step",
+        prop_type: "f64",
+        default: "1.0, or 0.01 with a percent format",
+        description: "The step increment. Committed values are snapped to it.",
+    },
+    PropRow {
+        name: "disabled",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, prevents the field from being interacted with.",
+    },
+    PropRow {
+        name: "allow_wheel_scrub",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, the mouse wheel changes the value while the input is focused.",
+    },
+    PropRow {
+        name: "format",
+        prop_type: "NumberFormat",
+        default: "en-US decimal",
+        description: "Separators, style and precision used to show and read the value, e.g. `NumberFormat::locale(\"de-DE\").currency(\"€\")` or `NumberFormat::default().percent()`.",
+    },
+    PropRow {
+        name: "on_value_change",
+        prop_type: "Option<Callback<Option<f64>>>",
+        default: "None",
+        description: "Callback fired with the new value, or `None` when the field is cleared.",
+    },
+];
+
+const INPUT_PROPS: &[PropRow] = &[PropRow {
+    name: "class",
+    prop_type: "String",
+    default: "\"\"",
+    description: "CSS class applied to the input element.",
+}];
+
+const BUTTON_PROPS: &[PropRow] = &[PropRow {
+    name: "class",
+    prop_type: "String",
+    default: "\"\"",
+    description: "CSS class applied to the button element.",
+}];
+
+const DATA_ATTRS: &[DataAttrRow] = &[DataAttrRow {
+    name: "data-disabled",
+    description: "Present on Root and Input when the field is disabled, and on Increment or Decrement when the value cannot move further.",
+}];
+
+const KEYBOARD: &[KeyboardRow] = &[
+    KeyboardRow {
+        key: "ArrowUp",
+        description: "Increases the value by one step.",
+    },
+    KeyboardRow {
+        key: "ArrowDown",
+        description: "Decreases the value by one step.",
+    },
+    KeyboardRow {
+        key: "Page Up",
+        description: "Increases the value by ten steps.",
+    },
+    KeyboardRow {
+        key: "Page Down",
+        description: "Decreases the value by ten steps.",
+    },
+    KeyboardRow {
+        key: "Home",
+        description: "Sets the value to the minimum, when bounded.",
+    },
+    KeyboardRow {
+        key: "End",
+        description: "Sets the value to the maximum, when bounded.",
+    },
+    KeyboardRow {
+        key: "Enter",
+        description: "Parses the typed text and formats it.",
+    },
+];
+
+#[component]
+pub fn NumberFieldDocPage() -> impl IntoView {
+    use crate::pages::docs::{DocPage, DocPreview};
+
+    view! {
+        <DocPage title="Number Field">
+            <p class="mt-3 mb-11 text-base text-balance">
+                "A text input for numbers with increment and decrement buttons, stepping and locale-aware formatting."
+            </p>
+            <DocPreview>
+                <NumberFieldExample />
+            </DocPreview>
+            <SectionHeading title="Installation" />
+            <Code
+                class="[&>.shiki]:overflow-x-auto [&>.shiki]:p-4 [&>.shiki]:rounded-lg [&>.shiki]:text-sm"
+                code={INSTALL_CODE}
+                language="toml"
+            />
+            <SectionHeading title="Usage" />
+            <Code
+                class="[&>.shiki]:overflow-x-auto [&>.shiki]:p-4 [&>.shiki]:rounded-lg [&>.shiki]:text-sm"
+                code={USAGE_CODE}
+                language="rust"
+            />
+            <SectionHeading title="API Reference" />
+            <PropsTable title="Root" rows={ROOT_PROPS} />
+            <PropsTable title="Input" rows={INPUT_PROPS} />
+            <PropsTable title="Increment" rows={BUTTON_PROPS} />
+            <PropsTable title="Decrement" rows={BUTTON_PROPS} />
+            <DataAttrsTable rows={DATA_ATTRS} />
+            <KeyboardTable rows={KEYBOARD} />
+        </DocPage>
+    }
+}
+
+#[component]
+pub fn NumberFieldExample() -> impl IntoView {
+    use biji_ui::components::number_field::{self, NumberFormat};
+
+    const ROOT_CLS: &str = "flex items-center w-48 h-10 rounded-md border border-input bg-background \
+        focus-within:ring-2 focus-within:ring-ring data-[disabled]:opacity-50";
+    const INPUT_CLS: &str = "w-full min-w-0 bg-transparent text-center text-sm tabular-nums outline-none";
+    const BUTTON_CLS: &str = "flex items-center justify-center w-10 h-full text-muted-foreground \
+        hover:text-foreground disabled:opacity-40 disabled:cursor-not-allowed";
+
+    view! {
+        <div class="flex flex-col gap-6">
+            <div class="flex flex-col gap-2">
+                <span class="text-sm font-medium">"Quantity"</span>
+                <number_field::Root value=1.0 min=0.0 max=99.0 allow_wheel_scrub=true class={ROOT_CLS}>
+                    <number_field::Decrement class={BUTTON_CLS}>"−"</number_field::Decrement>
+                    <number_field::Input class={INPUT_CLS} attr:aria-label="Quantity" />
+                    <number_field::Increment class={BUTTON_CLS}>"+"</number_field::Increment>
+                </number_field::Root>
+            </div>
+            <div class="flex flex-col gap-2">
+                <span class="text-sm font-medium">"Price (de-DE)"</span>
+                <number_field::Root
+                    value=1234.5
+                    min=0.0
+                    step=0.5
+                    format={NumberFormat::locale("de-DE").currency("€").fraction_digits(2, 2)}
+                    class={ROOT_CLS}
+                >
+                    <number_field::Decrement class={BUTTON_CLS}>"−"</number_field::Decrement>
+                    <number_field::Input class={INPUT_CLS} attr:aria-label="Price" />
+                    <number_field::Increment class={BUTTON_CLS}>"+"</number_field::Increment>
+                </number_field::Root>
+            </div>
+            <div class="flex flex-col gap-2">
+                <span class="text-sm font-medium">"Discount"</span>
+                <number_field::Root
+                    value=0.15
+                    min=0.0
+                    max=1.0
+                    format={NumberFormat::default().percent()}
+                    class={ROOT_CLS}
+                >
+                    <number_field::Decrement class={BUTTON_CLS}>"−"</number_field::Decrement>
+                    <number_field::Input class={INPUT_CLS} attr:aria-label="Discount" />
+                    <number_field::Increment class={BUTTON_CLS}>"+"</number_field::Increment>
+                </number_field::Root>
+            </div>
+        </div>
+    }
+}
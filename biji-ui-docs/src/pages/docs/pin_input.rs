@@ -74,6 +74,24 @@ const ROOT_PROPS: &[PropRow] = &[
         default: "None",
         description: "Callback fired with the current (partial or full) PIN string on every change.",
     },
+    PropRow {
+        name: "pin_type",
+        prop_type: "PinType",
+        default: "PinType::Numeric",
+        description: "Accepted characters: `Numeric` digits, `Alphanumeric` letters and digits (uppercased), or `Custom(Callback<char, bool>)`.",
+    },
+    PropRow {
+        name: "mask",
+        prop_type: "bool",
+        default: "false",
+        description: "Display entered characters as `•`, like a password field.",
+    },
+    PropRow {
+        name: "on_invalid",
+        prop_type: "Option<Callback<String>>",
+        default: "None",
+        description: "Callback fired with typed or pasted input that `pin_type` rejects. Pasted whitespace and `-` are skipped rather than rejected.",
+    },
 ];
 
 const CELL_PROPS: &[PropRow] = &[
@@ -91,6 +109,21 @@ const CELL_PROPS: &[PropRow] = &[
     },
 ];
 
+const SEPARATOR_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "children",
+        prop_type: "Option<Children>",
+        default: "None",
+        description: "Content of the divider, e.g. a dash.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the divider element.",
+    },
+];
+
 const DATA_ATTRS: &[DataAttrRow] = &[
     DataAttrRow {
         name: "data-filled",
@@ -104,6 +137,10 @@ const DATA_ATTRS: &[DataAttrRow] = &[
         name: "data-index",
         description: "The zero-based index of the cell. Present on Cell.",
     },
+    DataAttrRow {
+        name: "data-separator",
+        description: "Present on Separator.",
+    },
 ];
 
 const KEYBOARD: &[KeyboardRow] = &[
@@ -156,6 +193,7 @@ pub fn PinInputDocPage() -> impl IntoView {
             <SectionHeading title="API Reference" />
             <PropsTable title="Root" rows={ROOT_PROPS} />
             <PropsTable title="Cell" rows={CELL_PROPS} />
+            <PropsTable title="Separator" rows={SEPARATOR_PROPS} />
             <DataAttrsTable rows={DATA_ATTRS} />
             <KeyboardTable rows={KEYBOARD} />
         </DocPage>
@@ -76,6 +76,18 @@ const ROOT_PROPS: &[PropRow] = &[
         default: "true",
         description: "When true, focuses the first focusable element inside Content when the popover opens.",
     },
+    PropRow {
+        name: "modal",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, traps focus inside Content, makes the rest of the page inert and hidden from assistive technology, and returns focus to the trigger on close.",
+    },
+    PropRow {
+        name: "prevent_scroll",
+        prop_type: "bool",
+        default: "true",
+        description: "When true and modal, prevents scrolling the page while the popover is open.",
+    },
     PropRow {
         name: "avoid_collisions",
         prop_type: "AvoidCollisions",
@@ -214,6 +226,13 @@ const AVOID_COLLISIONS_PROPS: &[PropRow] = &[
     },
 ];
 
+const ANCHOR_PROPS: &[PropRow] = &[PropRow {
+    name: "class",
+    prop_type: "String",
+    default: "\"\"",
+    description: "CSS class applied to the anchor element. When rendered, Content is positioned against the anchor instead of the Trigger; the Trigger still toggles the popover.",
+}];
+
 const ARROW_PROPS: &[PropRow] = &[PropRow {
     name: "class",
     prop_type: "String",
@@ -258,6 +277,7 @@ pub fn PopoverDocPage() -> impl IntoView {
             <SectionHeading title="API Reference" />
             <PropsTable title="Root" rows={ROOT_PROPS} />
             <PropsTable title="Trigger" rows={TRIGGER_PROPS} />
+            <PropsTable title="Anchor" rows={ANCHOR_PROPS} />
             <PropsTable title="Content" rows={CONTENT_PROPS} />
             <PropsTable title="Arrow" rows={ARROW_PROPS} />
             <PropsTable title="Positioning" rows={POSITIONING_PROPS} />
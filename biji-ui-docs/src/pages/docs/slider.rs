@@ -66,11 +66,59 @@ const ROOT_PROPS: &[PropRow] = &[
         default: "false",
         description: "When true, prevents the slider from being interacted with.",
     },
+    PropRow {
+        name: "orientation",
+        prop_type: "Orientation",
+        default: "Orientation::Horizontal",
+        description: "Vertical sliders have `min` at the bottom and `max` at the top.",
+    },
+    PropRow {
+        name: "inverted",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, `max` is at the start of the track (left, or bottom when vertical) instead.",
+    },
+    PropRow {
+        name: "scale",
+        prop_type: "Scale",
+        default: "Scale::Linear",
+        description: "Maps values to positions: `Linear`, `Logarithmic` (for a positive `min`), or `Custom { to_fraction, from_fraction }`.",
+    },
     PropRow {
         name: "on_value_change",
         prop_type: "Option<Callback<f64>>",
         default: "None",
-        description: "Callback fired when the value changes. Receives the new value.",
+        description: "Callback fired when the value changes. Receives the new value of the thumb that moved.",
+    },
+    PropRow {
+        name: "values",
+        prop_type: "Vec<f64>",
+        default: "[]",
+        description: "Initial value of each thumb for a range slider; render one Thumb per value. Takes precedence over `value`.",
+    },
+    PropRow {
+        name: "min_steps_between_thumbs",
+        prop_type: "usize",
+        default: "0",
+        description: "The smallest distance between neighbouring thumbs, in steps.",
+    },
+    PropRow {
+        name: "swap_thumbs",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, a thumb dragged past its neighbour swaps places with it instead of stopping.",
+    },
+    PropRow {
+        name: "on_values_change",
+        prop_type: "Option<Callback<Vec<f64>>>",
+        default: "None",
+        description: "Callback fired with every thumb's value when one of them moves.",
+    },
+    PropRow {
+        name: "on_value_commit",
+        prop_type: "Option<Callback<Vec<f64>>>",
+        default: "None",
+        description: "Callback fired with every thumb's value when a drag ends or a key moves a thumb.",
     },
 ];
 
@@ -88,12 +136,74 @@ const RANGE_PROPS: &[PropRow] = &[PropRow {
     description: "CSS class applied to the range fill element.",
 }];
 
-const THUMB_PROPS: &[PropRow] = &[PropRow {
-    name: "class",
-    prop_type: "String",
-    default: "\"\"",
-    description: "CSS class applied to the thumb element.",
-}];
+const THUMB_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the thumb element.",
+    },
+    PropRow {
+        name: "index",
+        prop_type: "usize",
+        default: "0",
+        description: "Which value this thumb controls in a range slider.",
+    },
+    PropRow {
+        name: "value_text",
+        prop_type: "Option<Callback<f64, String>>",
+        default: "None",
+        description: "Formats the thumb's value for `aria-valuetext`, e.g. `\"$40\"`.",
+    },
+];
+
+const MARKS_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the marks container.",
+    },
+    PropRow {
+        name: "values",
+        prop_type: "Vec<f64>",
+        default: "[]",
+        description: "Values to render a Tick at.",
+    },
+    PropRow {
+        name: "every",
+        prop_type: "Option<usize>",
+        default: "None",
+        description: "When `values` is empty, renders a Tick every this many steps from `min` to `max`.",
+    },
+    PropRow {
+        name: "tick_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to each generated Tick.",
+    },
+    PropRow {
+        name: "label",
+        prop_type: "Option<Callback<f64, String>>",
+        default: "None",
+        description: "Label rendered inside each generated Tick.",
+    },
+];
+
+const TICK_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "value",
+        prop_type: "f64",
+        default: "",
+        description: "The value the tick marks; it is positioned along the track like a thumb.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the tick element.",
+    },
+];
 
 const DATA_ATTRS: &[DataAttrRow] = &[
     DataAttrRow {
@@ -106,18 +216,30 @@ const DATA_ATTRS: &[DataAttrRow] = &[
     },
     DataAttrRow {
         name: "data-orientation",
-        description: "Always \"horizontal\". Present on Root, Track, and Range.",
+        description: "\"horizontal\" | \"vertical\". Present on Root, Track, Range, Thumb, Marks, and Tick.",
+    },
+    DataAttrRow {
+        name: "data-index",
+        description: "The zero-based index of the thumb. Present on Thumb.",
+    },
+    DataAttrRow {
+        name: "data-in-range",
+        description: "Present on Tick when its value lies within the filled range.",
+    },
+    DataAttrRow {
+        name: "data-value",
+        description: "The value a tick marks. Present on Tick.",
     },
 ];
 
 const KEYBOARD: &[KeyboardRow] = &[
     KeyboardRow {
         key: "ArrowRight / ArrowUp",
-        description: "Increases the value by one step.",
+        description: "Increases the value by one step. When inverted, the arrow pointing towards `max` increases it.",
     },
     KeyboardRow {
         key: "ArrowLeft / ArrowDown",
-        description: "Decreases the value by one step.",
+        description: "Decreases the value by one step. When inverted, the arrow pointing towards `min` decreases it.",
     },
     KeyboardRow {
         key: "Page Up",
@@ -166,6 +288,8 @@ pub fn SliderDocPage() -> impl IntoView {
             <PropsTable title="Track" rows={TRACK_PROPS} />
             <PropsTable title="Range" rows={RANGE_PROPS} />
             <PropsTable title="Thumb" rows={THUMB_PROPS} />
+            <PropsTable title="Marks" rows={MARKS_PROPS} />
+            <PropsTable title="Tick" rows={TICK_PROPS} />
             <DataAttrsTable rows={DATA_ATTRS} />
             <KeyboardTable rows={KEYBOARD} />
         </DocPage>
@@ -207,6 +331,39 @@ pub fn SliderExample() -> impl IntoView {
                     <slider::Thumb class={thumb_class} />
                 </slider::Root>
             </div>
+            <div class="flex flex-col gap-2">
+                <span class="text-sm font-medium">"Price range"</span>
+                <slider::Root
+                    values={vec![20.0, 80.0]}
+                    min_steps_between_thumbs=10
+                    class="relative flex items-center w-full h-5 touch-none select-none"
+                >
+                    <slider::Track class="relative h-2 w-full grow overflow-hidden rounded-full bg-secondary">
+                        <slider::Range class="absolute h-full bg-primary" />
+                    </slider::Track>
+                    <slider::Thumb index=0 class={thumb_class} attr:aria-label="Minimum price" />
+                    <slider::Thumb index=1 class={thumb_class} attr:aria-label="Maximum price" />
+                </slider::Root>
+            </div>
+            <div class="flex flex-col gap-2 pb-4">
+                <span class="text-sm font-medium">"Marks (every 25)"</span>
+                <slider::Root
+                    value=50.0
+                    step=5.0
+                    class="relative flex items-center w-full h-5 touch-none select-none"
+                >
+                    <slider::Track class="relative h-2 w-full grow overflow-hidden rounded-full bg-secondary">
+                        <slider::Range class="absolute h-full bg-primary" />
+                    </slider::Track>
+                    <slider::Marks
+                        every=5
+                        class="absolute inset-x-0 top-6"
+                        tick_class="-translate-x-1/2 text-xs text-muted-foreground data-[in-range]:text-foreground"
+                        label={Callback::new(|v: f64| v.to_string())}
+                    />
+                    <slider::Thumb class={thumb_class} />
+                </slider::Root>
+            </div>
             <div class="flex flex-col gap-2">
                 <span class="text-sm font-medium text-muted-foreground">"Disabled"</span>
                 <slider::Root
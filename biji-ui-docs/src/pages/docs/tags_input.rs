@@ -0,0 +1,300 @@
+use leptos::prelude::*;
+
+use crate::components::{
+    api_table::{
+        DataAttrRow, DataAttrsTable, KeyboardRow, KeyboardTable, PropRow, PropsTable,
+        SectionHeading,
+    },
+    code::Code,
+};
+
+const INSTALL_CODE: &str = concat!(
+    "biji-ui = { version = \"",
+    env!("CARGO_PKG_VERSION"),
+    "\", features = [\"tags_input\"] }",
+);
+
+const USAGE_CODE: &str = r#"use leptos::prelude::*;
+use biji_ui::components::tags_input;
+
+#[component]
+pub fn MyTagsInput() -> impl IntoView {
+    let tags = RwSignal::new(vec!["rust".to_string(), "leptos".to_string()]);
+
+    view! {
+        <tags_input::Root value={tags} max=5 editable=true class="flex flex-wrap gap-1 border rounded-md p-1">
+            <For
+                each={move || tags.get().into_iter().enumerate()}
+                key={|(index, tag)| (*index, tag.clone())}
+                let((index, tag))
+            >
+                <tags_input::Tag index={index} value={tag.clone()} class="flex items-center gap-1 rounded px-2">
+                    {tag.clone()}
+                    <tags_input::TagRemove>"×"</tags_input::TagRemove>
+                </tags_input::Tag>
+            </For>
+            <tags_input::Input class="flex-1 outline-none" attr:placeholder="Add tag…" />
+        </tags_input::Root>
+    }
+}"#;
+
+const ROOT_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the root element.",
+    },
+    PropRow {
+        name: "value",
+        prop_type: "Option<RwSignal<Vec<String>>>",
+        default: "None",
+        description: "The tags. Pass a signal to render the tags from and to control them.",
+    },
+    PropRow {
+        name: "max",
+        prop_type: "Option<usize>",
+        default: "None",
+        description: "The most tags allowed. Further tags are rejected.",
+    },
+    PropRow {
+        name: "delimiter",
+        prop_type: "char",
+        default: "','",
+        description: "Typing this character creates a tag. Pasted text is split on it and on line breaks.",
+    },
+    PropRow {
+        name: "duplicates",
+        prop_type: "Duplicates",
+        default: "Duplicates::Reject",
+        description: "Whether a tag equal to an existing one is rejected (`Reject`, `RejectIgnoreCase`) or added (`Allow`).",
+    },
+    PropRow {
+        name: "validate",
+        prop_type: "Option<Callback<String, bool>>",
+        default: "None",
+        description: "Called with each new or edited tag. Returning false rejects it.",
+    },
+    PropRow {
+        name: "editable",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, tags can be edited in place on double-click, Enter or F2.",
+    },
+    PropRow {
+        name: "disabled",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, prevents tags from being added, edited or removed.",
+    },
+    PropRow {
+        name: "on_value_change",
+        prop_type: "Option<Callback<Vec<String>>>",
+        default: "None",
+        description: "Callback fired with the tags whenever they change.",
+    },
+    PropRow {
+        name: "on_invalid",
+        prop_type: "Option<Callback<String>>",
+        default: "None",
+        description: "Callback fired with each tag rejected by `max`, `duplicates` or `validate`.",
+    },
+];
+
+const TAG_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "index",
+        prop_type: "usize",
+        default: "—",
+        description: "Position of the tag in the root's value. It is fixed when the tag is created, so key the rows by (index, value) rather than by value alone.",
+    },
+    PropRow {
+        name: "value",
+        prop_type: "String",
+        default: "—",
+        description: "The tag's text, shown in the edit input and used for the remove button's label.",
+    },
+    PropRow {
+        name: "class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the tag element.",
+    },
+    PropRow {
+        name: "edit_class",
+        prop_type: "String",
+        default: "\"\"",
+        description: "CSS class applied to the input shown while the tag is edited.",
+    },
+    PropRow {
+        name: "disabled",
+        prop_type: "bool",
+        default: "false",
+        description: "When true, the tag cannot be removed or edited and is skipped by the arrow keys.",
+    },
+];
+
+const TAG_REMOVE_PROPS: &[PropRow] = &[PropRow {
+    name: "class",
+    prop_type: "String",
+    default: "\"\"",
+    description: "CSS class applied to the button element.",
+}];
+
+const INPUT_PROPS: &[PropRow] = &[PropRow {
+    name: "class",
+    prop_type: "String",
+    default: "\"\"",
+    description: "CSS class applied to the input element.",
+}];
+
+const DATA_ATTRS: &[DataAttrRow] = &[
+    DataAttrRow {
+        name: "data-selected",
+        description: "Present on the Tag that has focus, which the next Backspace deletes.",
+    },
+    DataAttrRow {
+        name: "data-editing",
+        description: "Present on the Tag being edited.",
+    },
+    DataAttrRow {
+        name: "data-full",
+        description: "Present on Root when `max` tags have been added.",
+    },
+    DataAttrRow {
+        name: "data-disabled",
+        description: "Present on Root, Input, Tag and TagRemove when disabled.",
+    },
+];
+
+const KEYBOARD: &[KeyboardRow] = &[
+    KeyboardRow {
+        key: "Enter",
+        description: "In the input, adds the typed text as a tag. On a tag, starts editing it when `editable` is set.",
+    },
+    KeyboardRow {
+        key: "Delimiter",
+        description: "Adds the text typed so far as a tag.",
+    },
+    KeyboardRow {
+        key: "Backspace",
+        description: "In an empty input, selects the last tag. On a tag, deletes it and selects the previous one.",
+    },
+    KeyboardRow {
+        key: "Delete",
+        description: "Deletes the selected tag and selects the next one.",
+    },
+    KeyboardRow {
+        key: "ArrowLeft",
+        description: "Moves to the previous tag, or from the start of the input to the last tag.",
+    },
+    KeyboardRow {
+        key: "ArrowRight",
+        description: "Moves to the next tag, or from the last tag back to the input.",
+    },
+    KeyboardRow {
+        key: "Escape",
+        description: "Discards an edit, or moves from a tag back to the input.",
+    },
+];
+
+#[component]
+pub fn TagsInputDocPage() -> impl IntoView {
+    use crate::pages::docs::{DocPage, DocPreview};
+
+    view! {
+        <DocPage title="Tags Input">
+            <p class="mt-3 mb-11 text-base text-balance">
+                "A text input that turns what is typed or pasted into a list of removable, editable tags."
+            </p>
+            <DocPreview>
+                <TagsInputExample />
+            </DocPreview>
+            <SectionHeading title="Installation" />
+            <Code
+                class="[&>.shiki]:overflow-x-auto [&>.shiki]:p-4 [&>.shiki]:rounded-lg [&>.shiki]:text-sm"
+                code={INSTALL_CODE}
+                language="toml"
+            />
+            <SectionHeading title="Usage" />
+            <Code
+                class="[&>.shiki]:overflow-x-auto [&>.shiki]:p-4 [&>.shiki]:rounded-lg [&>.shiki]:text-sm"
+                code={USAGE_CODE}
+                language="rust"
+            />
+            <SectionHeading title="API Reference" />
+            <PropsTable title="Root" rows={ROOT_PROPS} />
+            <PropsTable title="Tag" rows={TAG_PROPS} />
+            <PropsTable title="TagRemove" rows={TAG_REMOVE_PROPS} />
+            <PropsTable title="Input" rows={INPUT_PROPS} />
+            <DataAttrsTable rows={DATA_ATTRS} />
+            <KeyboardTable rows={KEYBOARD} />
+        </DocPage>
+    }
+}
+
+#[component]
+pub fn TagsInputExample() -> impl IntoView {
+    use biji_ui::components::tags_input::{self, Duplicates};
+
+    const ROOT_CLS: &str = "flex flex-wrap items-center gap-1.5 w-80 min-h-10 p-1.5 rounded-md border border-input \
+        bg-background cursor-text focus-within:ring-2 focus-within:ring-ring data-[disabled]:opacity-50";
+    const TAG_CLS: &str = "flex items-center gap-1 h-7 pl-2 pr-1 rounded bg-muted text-sm outline-none \
+        data-[selected]:ring-2 data-[selected]:ring-ring data-[editing]:bg-transparent data-[editing]:ring-1 \
+        data-[editing]:ring-input";
+    const EDIT_CLS: &str = "w-24 bg-transparent text-sm outline-none";
+    const REMOVE_CLS: &str = "flex items-center justify-center w-5 h-5 rounded text-muted-foreground \
+        hover:text-foreground hover:bg-background";
+    const INPUT_CLS: &str = "flex-1 min-w-24 h-7 px-1 bg-transparent text-sm outline-none";
+
+    let tags = RwSignal::new(vec!["rust".to_string(), "leptos".to_string()]);
+    let message = RwSignal::new(None::<String>);
+
+    view! {
+        <div class="flex flex-col gap-2">
+            <span class="text-sm font-medium">"Topics"</span>
+            <tags_input::Root
+                value={tags}
+                max=6
+                editable=true
+                duplicates={Duplicates::RejectIgnoreCase}
+                validate={Callback::new(|tag: String| tag.chars().count() <= 20)}
+                on_value_change={Callback::new(move |_| message.set(None))}
+                on_invalid={Callback::new(move |tag: String| {
+                    message.set(Some(format!("Can't add \u{201c}{tag}\u{201d}")))
+                })}
+                class={ROOT_CLS}
+            >
+                <For
+                    each={move || tags.get().into_iter().enumerate()}
+                    key={|(index, tag)| (*index, tag.clone())}
+                    let((index, tag))
+                >
+                    <tags_input::Tag
+                        index={index}
+                        value={tag.clone()}
+                        class={TAG_CLS}
+                        edit_class={EDIT_CLS}
+                    >
+                        <span>{tag.clone()}</span>
+                        <tags_input::TagRemove class={REMOVE_CLS}>"×"</tags_input::TagRemove>
+                    </tags_input::Tag>
+                </For>
+                <tags_input::Input
+                    class={INPUT_CLS}
+                    attr:placeholder="Add topic…"
+                    attr:aria-label="Add topic"
+                />
+            </tags_input::Root>
+            <span class="text-xs text-muted-foreground">
+                {move || {
+                    message
+                        .get()
+                        .unwrap_or_else(|| {
+                            "Press Enter or comma to add. Double-click a tag to edit it.".to_string()
+                        })
+                }}
+            </span>
+        </div>
+    }
+}
@@ -1,7 +1,7 @@
 use leptos::{portal::Portal, prelude::*};
 
 use crate::components::{
-    api_table::{PropRow, PropsTable, SectionHeading},
+    api_table::{DataAttrRow, DataAttrsTable, PropRow, PropsTable, SectionHeading},
     button::{Variant, btn},
     code::Code,
 };
@@ -61,6 +61,51 @@ const ROOT_PROPS: &[PropRow] = &[
         default: "Flip",
         description: "How the tooltip reacts when it would overflow the viewport.",
     },
+    PropRow {
+        name: "delay_duration",
+        prop_type: "Option<Duration>",
+        default: "None",
+        description: "Overrides the Provider's delay_duration for this tooltip. Outside a Provider, tooltips open without delay.",
+    },
+    PropRow {
+        name: "skip_delay_duration",
+        prop_type: "Option<Duration>",
+        default: "None",
+        description: "Overrides the Provider's skip_delay_duration for this tooltip.",
+    },
+    PropRow {
+        name: "disable_hoverable_content",
+        prop_type: "Option<bool>",
+        default: "None",
+        description: "Overrides the Provider's disable_hoverable_content for this tooltip.",
+    },
+    PropRow {
+        name: "long_press_threshold",
+        prop_type: "Duration",
+        default: "500ms",
+        description: "How long a touch must rest on the trigger to open. Taps outside close it again.",
+    },
+];
+
+const PROVIDER_PROPS: &[PropRow] = &[
+    PropRow {
+        name: "delay_duration",
+        prop_type: "Duration",
+        default: "700ms",
+        description: "How long the pointer must rest on a trigger before its tooltip opens.",
+    },
+    PropRow {
+        name: "skip_delay_duration",
+        prop_type: "Duration",
+        default: "300ms",
+        description: "After a tooltip closes, moving to another trigger within this window opens it instantly.",
+    },
+    PropRow {
+        name: "disable_hoverable_content",
+        prop_type: "bool",
+        default: "false",
+        description: "Close as soon as the pointer leaves the trigger instead of allowing it to move onto the content.",
+    },
 ];
 
 const TRIGGER_PROPS: &[PropRow] = &[PropRow {
@@ -91,6 +136,13 @@ const CONTENT_PROPS: &[PropRow] = &[
     },
 ];
 
+const ANCHOR_PROPS: &[PropRow] = &[PropRow {
+    name: "class",
+    prop_type: "String",
+    default: "\"\"",
+    description: "CSS class applied to the anchor element. When rendered, Content is positioned against the anchor instead of the Trigger; the Trigger still opens the tooltip.",
+}];
+
 const ARROW_PROPS: &[PropRow] = &[PropRow {
     name: "class",
     prop_type: "String",
@@ -98,6 +150,11 @@ const ARROW_PROPS: &[PropRow] = &[PropRow {
     description: "CSS class applied to the arrow indicator element. Position and rotation are handled automatically.",
 }];
 
+const DATA_ATTRS: &[DataAttrRow] = &[DataAttrRow {
+    name: "data-pointer-type",
+    description: "\"mouse\", \"touch\" or \"pen\" — the pointer that last interacted with the trigger. Present on Content.",
+}];
+
 const AVOID_COLLISIONS_PROPS: &[PropRow] = &[
     PropRow { name: "Flip", prop_type: "AvoidCollisions", default: "default", description: "Keeps the preferred side. Flips to the opposite side if it does not fit. If neither fits, uses whichever has more space." },
     PropRow { name: "AutoPlace", prop_type: "AvoidCollisions", default: "", description: "Always places the tooltip on the side with the most available space, regardless of the preferred positioning." },
@@ -144,12 +201,15 @@ pub fn TooltipDocPage() -> impl IntoView {
                 language="rust"
             />
             <SectionHeading title="API Reference" />
+            <PropsTable title="Provider" rows={PROVIDER_PROPS} />
             <PropsTable title="Root" rows={ROOT_PROPS} />
             <PropsTable title="Trigger" rows={TRIGGER_PROPS} />
+            <PropsTable title="Anchor" rows={ANCHOR_PROPS} />
             <PropsTable title="Content" rows={CONTENT_PROPS} />
             <PropsTable title="Arrow" rows={ARROW_PROPS} />
             <PropsTable title="AvoidCollisions" rows={AVOID_COLLISIONS_PROPS} />
             <PropsTable title="Positioning" rows={POSITIONING_PROPS} />
+            <DataAttrsTable rows={DATA_ATTRS} />
         </DocPage>
     }
 }
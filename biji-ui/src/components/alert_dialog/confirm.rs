@@ -0,0 +1,264 @@
+//! Imperative confirmation dialogs.
+//!
+//! Wrap the app in [`AlertDialogProvider`] with a template built from the
+//! `alert_dialog` parts, then call the function returned by [`use_confirm`]
+//! from any event handler and `.await` the answer.
+
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+use leptos::{context::Provider, prelude::*};
+
+use super::{
+    context::AlertDialogContext,
+    root::{RootEvents, next_alert_ids},
+};
+
+/// Text shown by a confirmation dialog. Read it in the provider's `template`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfirmOptions {
+    pub title: String,
+    pub description: String,
+    pub action_label: String,
+    pub cancel_label: String,
+}
+
+impl Default for ConfirmOptions {
+    fn default() -> Self {
+        Self {
+            title: String::from("Are you sure?"),
+            description: String::new(),
+            action_label: String::from("Continue"),
+            cancel_label: String::from("Cancel"),
+        }
+    }
+}
+
+/// Resolves to `true` when the user picks `Action`, and to `false` on `Cancel`,
+/// Escape, or when the provider unmounts first.
+pub struct ConfirmFuture(Arc<Mutex<Slot>>);
+
+impl Future for ConfirmFuture {
+    type Output = bool;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        let mut slot = self.0.lock().unwrap();
+        match slot.result {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Slot {
+    result: Option<bool>,
+    waker: Option<Waker>,
+}
+
+/// Completes a [`ConfirmFuture`]. Dropping it unresolved answers `false`.
+struct Resolver(Arc<Mutex<Slot>>);
+
+impl Resolver {
+    fn resolve(&self, confirmed: bool) {
+        let mut slot = self.0.lock().unwrap();
+        if slot.result.is_some() {
+            return;
+        }
+        slot.result = Some(confirmed);
+        if let Some(waker) = slot.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for Resolver {
+    fn drop(&mut self) {
+        self.resolve(false);
+    }
+}
+
+fn channel() -> (ConfirmFuture, Resolver) {
+    let slot = Arc::new(Mutex::new(Slot::default()));
+    (ConfirmFuture(Arc::clone(&slot)), Resolver(slot))
+}
+
+#[derive(Copy, Clone)]
+pub struct ConfirmContext {
+    /// Options of the confirmation currently shown. Kept after closing so the
+    /// template can finish its hide animation.
+    pub options: RwSignal<Option<ConfirmOptions>>,
+    open: RwSignal<bool>,
+    hide_delay: Duration,
+    queue: StoredValue<VecDeque<(ConfirmOptions, Resolver)>>,
+    active: StoredValue<Option<Resolver>>,
+    busy: StoredValue<bool>,
+}
+
+impl ConfirmContext {
+    /// Queue a confirmation. It is shown once every earlier one has been answered.
+    pub fn confirm(&self, options: ConfirmOptions) -> ConfirmFuture {
+        let (future, resolver) = channel();
+        self.queue.update_value(|q| q.push_back((options, resolver)));
+        if !self.busy.get_value() {
+            self.show_next();
+        }
+        future
+    }
+
+    fn show_next(&self) {
+        let Some((options, resolver)) = self.queue.try_update_value(|q| q.pop_front()).flatten()
+        else {
+            let _ = self.busy.try_set_value(false);
+            return;
+        };
+        self.busy.set_value(true);
+        self.active.set_value(Some(resolver));
+        self.options.set(Some(options));
+        self.open.set(true);
+    }
+
+    fn settle(&self, confirmed: bool) {
+        if let Some(resolver) = self.active.try_update_value(|a| a.take()).flatten() {
+            resolver.resolve(confirmed);
+        }
+    }
+
+    /// Answer the open confirmation with `false` and show the next queued one
+    /// once the hide animation has finished.
+    fn closed(&self) {
+        self.settle(false);
+        let ctx = *self;
+        let _ = leptos::leptos_dom::helpers::set_timeout_with_handle(
+            move || ctx.show_next(),
+            self.hide_delay,
+        );
+    }
+}
+
+/// Renders `template` for each call to the function returned by [`use_confirm`].
+///
+/// The template is rendered inside an alert dialog context, so it is built from
+/// the regular parts: `Overlay`, `Content`, `Title`, `Description`, `Cancel`
+/// and `Action`. `Action` answers `true`; `Cancel` and Escape answer `false`.
+/// Concurrent calls are queued and shown one after another.
+///
+/// # Example
+/// ```rust,ignore
+/// <alert_dialog::AlertDialogProvider template={|opts: ConfirmOptions| view! {
+///     <alert_dialog::Overlay class="fixed inset-0 bg-black/50" />
+///     <alert_dialog::Content class="fixed top-1/2 left-1/2 -translate-1/2">
+///         <alert_dialog::Title>{opts.title}</alert_dialog::Title>
+///         <alert_dialog::Description>{opts.description}</alert_dialog::Description>
+///         <alert_dialog::Cancel>{opts.cancel_label}</alert_dialog::Cancel>
+///         <alert_dialog::Action>{opts.action_label}</alert_dialog::Action>
+///     </alert_dialog::Content>
+/// }}>
+///     <App />
+/// </alert_dialog::AlertDialogProvider>
+///
+/// // Anywhere below the provider:
+/// let confirm = alert_dialog::use_confirm();
+/// spawn_local(async move {
+///     if confirm(ConfirmOptions { title: "Delete project?".into(), ..Default::default() }).await {
+///         delete_project();
+///     }
+/// });
+/// ```
+#[component]
+pub fn AlertDialogProvider<F, IV>(
+    children: Children,
+    /// Builds the dialog for the given options.
+    template: F,
+    #[prop(default = true)] prevent_scroll: bool,
+    #[prop(default = Duration::from_millis(200))] hide_delay: Duration,
+) -> impl IntoView
+where
+    F: Fn(ConfirmOptions) -> IV + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let open = RwSignal::new(false);
+    let confirm = ConfirmContext {
+        options: RwSignal::new(None),
+        open,
+        hide_delay,
+        queue: StoredValue::new(VecDeque::new()),
+        active: StoredValue::new(None),
+        busy: StoredValue::new(false),
+    };
+
+    let (title_id, desc_id) = next_alert_ids();
+    let dialog = AlertDialogContext {
+        open,
+        prevent_scroll,
+        hide_delay,
+        title_id: StoredValue::new(title_id),
+        desc_id: StoredValue::new(desc_id),
+        on_open_change: Some(Callback::new(move |is_open: bool| {
+            if !is_open {
+                confirm.closed();
+            }
+        })),
+        on_action: Some(Callback::new(move |_| confirm.settle(true))),
+        ..AlertDialogContext::default()
+    };
+
+    on_cleanup(move || {
+        // Dropping the resolvers answers every pending confirmation with `false`.
+        let _ = confirm.active.try_update_value(|a| a.take());
+        let _ = confirm.queue.try_update_value(|q| q.clear());
+    });
+
+    view! {
+        <Provider value={confirm}>
+            {children()}
+            <Provider value={dialog}>
+                <RootEvents>
+                    {move || confirm.options.get().map(&template)}
+                </RootEvents>
+            </Provider>
+        </Provider>
+    }
+}
+
+/// Returns a function that opens a confirmation dialog and resolves to the
+/// user's answer. Requires an [`AlertDialogProvider`] ancestor.
+pub fn use_confirm() -> impl Fn(ConfirmOptions) -> ConfirmFuture + Copy + Send + Sync + 'static {
+    let ctx = use_context::<ConfirmContext>()
+        .expect("alert_dialog::use_confirm requires an alert_dialog::AlertDialogProvider ancestor");
+    move |options| ctx.confirm(options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poll(future: &mut ConfirmFuture) -> Poll<bool> {
+        Pin::new(future).poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn resolves_with_answer() {
+        let (mut future, resolver) = channel();
+        assert_eq!(poll(&mut future), Poll::Pending);
+        resolver.resolve(true);
+        resolver.resolve(false);
+        assert_eq!(poll(&mut future), Poll::Ready(true));
+    }
+
+    #[test]
+    fn dropping_resolver_answers_false() {
+        let (mut future, resolver) = channel();
+        drop(resolver);
+        assert_eq!(poll(&mut future), Poll::Ready(false));
+    }
+}
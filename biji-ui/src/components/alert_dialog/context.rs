@@ -5,6 +5,8 @@ use leptos::{
     prelude::*,
 };
 
+use crate::utils::modal_stack::ModalLayer;
+
 #[derive(Copy, Clone)]
 pub struct AlertDialogContext {
     pub open: RwSignal<bool>,
@@ -15,7 +17,11 @@ pub struct AlertDialogContext {
     pub hide_delay: Duration,
     pub title_id: StoredValue<String>,
     pub desc_id: StoredValue<String>,
+    /// Position in the stack of open modals.
+    pub layer: ModalLayer,
     pub(crate) on_open_change: Option<Callback<bool>>,
+    /// Run by `Action` before closing the dialog.
+    pub(crate) on_action: Option<Callback<()>>,
 }
 
 impl Default for AlertDialogContext {
@@ -29,7 +35,9 @@ impl Default for AlertDialogContext {
             hide_delay: Duration::from_millis(200),
             title_id: StoredValue::new(String::new()),
             desc_id: StoredValue::new(String::new()),
+            layer: ModalLayer::new(),
             on_open_change: None,
+            on_action: None,
         }
     }
 }
@@ -1,6 +1,8 @@
+pub mod confirm;
 pub mod context;
 pub mod root;
 
+pub use confirm::{AlertDialogProvider, ConfirmFuture, ConfirmOptions, use_confirm};
 pub use root::Action;
 pub use root::Cancel;
 pub use root::Content;
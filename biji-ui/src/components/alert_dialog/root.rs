@@ -5,17 +5,22 @@ use std::{
 
 use leptos::{context::Provider, ev::click, leptos_dom::helpers::TimeoutHandle, prelude::*};
 use leptos_use::use_event_listener;
-use wasm_bindgen::JsCast;
 
 use crate::{
-    cn, custom_animated_show::CustomAnimatedShow, utils::prevent_scroll::use_prevent_scroll,
+    cn,
+    custom_animated_show::CustomAnimatedShow,
+    utils::{
+        focus_trap::{focus_first_element, trap_tab_focus},
+        modal_stack::use_modal_layer,
+        prevent_scroll::use_prevent_scroll,
+    },
 };
 
 use super::context::AlertDialogContext;
 
 static ALERT_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
-fn next_alert_ids() -> (String, String) {
+pub(crate) fn next_alert_ids() -> (String, String) {
     let id = ALERT_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
     (
         format!("biji-alert-title-{id}"),
@@ -53,13 +58,19 @@ pub fn Root(
 }
 
 #[component]
-fn RootEvents(children: Children) -> impl IntoView {
+pub(crate) fn RootEvents(children: Children) -> impl IntoView {
     let ctx = expect_context::<AlertDialogContext>();
 
     let eff = use_prevent_scroll(move || ctx.prevent_scroll && ctx.open.get(), ctx.hide_delay);
+    let layer_eff = use_modal_layer(
+        ctx.layer,
+        move || ctx.open.get(),
+        move || ctx.trigger_ref.get_untracked().map(Into::into),
+    );
 
     on_cleanup(move || {
         drop(eff);
+        drop(layer_eff);
     });
 
     children()
@@ -147,6 +158,9 @@ pub fn Content(
     });
 
     let _ = use_event_listener(content_ref, leptos::ev::keydown, move |evt| {
+        if !ctx.layer.is_top() {
+            return;
+        }
         match evt.key().as_str() {
             "Escape" => {
                 evt.prevent_default();
@@ -175,6 +189,8 @@ pub fn Content(
             attr:aria-modal="true"
             attr:aria-labelledby={ctx.title_id.get_value()}
             attr:aria-describedby={ctx.desc_id.get_value()}
+            attr:data-nested={move || ctx.layer.is_nested().then_some("")}
+            attr:data-nested-count={move || ctx.layer.nested_count()}
             attr:tabindex="-1"
         >
             {children()}
@@ -222,90 +238,21 @@ pub fn Cancel(children: Children, #[prop(into, optional)] class: String) -> impl
 
 #[component]
 pub fn Action(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
+    let ctx = expect_context::<AlertDialogContext>();
+
     view! {
-        <button type="button" class={class}>
+        <button
+            type="button"
+            class={class}
+            on:click={move |_| {
+                // Set by `AlertDialogProvider`; a plain `Root` leaves closing to the caller.
+                if let Some(cb) = ctx.on_action {
+                    cb.run(());
+                    ctx.close();
+                }
+            }}
+        >
             {children()}
         </button>
     }
 }
-
-fn get_focusable_elements(container: &web_sys::HtmlElement) -> Vec<web_sys::HtmlElement> {
-    let selector = r#"a[href], button:not([disabled]), textarea:not([disabled]), input:not([disabled]), select:not([disabled]), [tabindex]:not([tabindex="-1"])"#;
-    let Ok(node_list) = container.query_selector_all(selector) else {
-        return Vec::new();
-    };
-    let mut elements = Vec::new();
-    for i in 0..node_list.length() {
-        if let Some(node) = node_list.get(i) {
-            if let Ok(el) = node.dyn_into::<web_sys::HtmlElement>() {
-                elements.push(el);
-            }
-        }
-    }
-    elements
-}
-
-fn focus_first_element(container: &web_sys::HtmlElement) {
-    let focusable = get_focusable_elements(container);
-    if let Some(first) = focusable.first() {
-        let _ = first.focus();
-    } else {
-        let _ = container.focus();
-    }
-}
-
-fn trap_tab_focus(container: &web_sys::HtmlElement, shift_key: bool, evt: &web_sys::KeyboardEvent) {
-    let focusable = get_focusable_elements(container);
-    if focusable.is_empty() {
-        evt.prevent_default();
-        return;
-    }
-
-    let active = document().active_element();
-
-    if shift_key {
-        let is_first = active
-            .as_ref()
-            .and_then(|a| a.dyn_ref::<web_sys::HtmlElement>())
-            .map(|a| {
-                focusable
-                    .first()
-                    .map(|f| {
-                        let a_node: &web_sys::Node = a.as_ref();
-                        let f_node: &web_sys::Node = f.as_ref();
-                        a_node.is_same_node(Some(f_node))
-                    })
-                    .unwrap_or(false)
-            })
-            .unwrap_or(true);
-
-        if is_first {
-            evt.prevent_default();
-            if let Some(last) = focusable.last() {
-                let _ = last.focus();
-            }
-        }
-    } else {
-        let is_last = active
-            .as_ref()
-            .and_then(|a| a.dyn_ref::<web_sys::HtmlElement>())
-            .map(|a| {
-                focusable
-                    .last()
-                    .map(|f| {
-                        let a_node: &web_sys::Node = a.as_ref();
-                        let f_node: &web_sys::Node = f.as_ref();
-                        a_node.is_same_node(Some(f_node))
-                    })
-                    .unwrap_or(false)
-            })
-            .unwrap_or(true);
-
-        if is_last {
-            evt.prevent_default();
-            if let Some(first) = focusable.first() {
-                let _ = first.focus();
-            }
-        }
-    }
-}
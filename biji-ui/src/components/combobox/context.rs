@@ -12,10 +12,11 @@ use wasm_bindgen::JsCast;
 
 use crate::{
     items::{
-        FilterActiveItems, Focus, GetIndex, IsActive, ManageFocus, NavigateItems, filter_active,
-        next_item, previous_item,
+        Collection, FilterActiveItems, Focus, GetIndex, IsActive, ManageFocus, NavigateItems,
+        filter_active, next_item, previous_item,
     },
     utils::positioning::{AvoidCollisions, Positioning},
+    virtualizer::Virtualizer,
 };
 
 #[derive(Copy, Clone)]
@@ -31,11 +32,16 @@ pub struct ComboboxContext {
     pub query: RwSignal<String>,
     pub item_focus: RwSignal<Option<usize>>,
     pub items: RwSignal<HashMap<usize, ComboboxItemContext>>,
+    /// Data-driven items passed to `Root`, including those hidden by the query.
+    pub collection: Option<Collection>,
     pub hide_delay: Duration,
     pub positioning: Positioning,
     pub arrow_size: i32,
     pub combobox_id: StoredValue<String>,
     pub avoid_collisions: AvoidCollisions,
+    /// Set while a `VirtualItems` part is mounted. Navigation then moves by row
+    /// index and filtering is left to the caller.
+    pub virtualizer: StoredValue<Option<Virtualizer>>,
     pub(crate) on_value_change: Option<Callback<String>>,
     pub(crate) next_id: StoredValue<AtomicUsize>,
     /// True when using `InputTrigger` (the input IS the trigger, positioned above the dropdown).
@@ -57,11 +63,13 @@ impl Default for ComboboxContext {
             query: RwSignal::new(String::new()),
             item_focus: RwSignal::new(None),
             items: RwSignal::new(HashMap::new()),
+            collection: None,
             hide_delay: Duration::from_millis(200),
             positioning: Positioning::BottomStart,
             arrow_size: 0,
             combobox_id: StoredValue::new(String::new()),
             avoid_collisions: AvoidCollisions::Flip,
+            virtualizer: StoredValue::new(None),
             on_value_change: None,
             next_id: StoredValue::new(AtomicUsize::new(0)),
             inline_mode: false,
@@ -118,16 +126,34 @@ impl ComboboxContext {
     }
 
     /// Items that are active (not disabled) and match the current query.
+    ///
+    /// When [prefiltered](Self::is_prefiltered), every mounted item is visible.
     pub fn visible_items(&self) -> Vec<ComboboxItemContext> {
         let q = self.query.get().to_lowercase();
         let all = filter_active(self.items.get());
-        if q.is_empty() {
+        if q.is_empty() || self.is_prefiltered() {
             return all;
         }
         all.into_iter()
             .filter(|item| item.label.with_value(|l| l.to_lowercase().contains(&q)))
             .collect()
     }
+
+    /// `true` when only matching items are mounted: with a `VirtualItems` part the
+    /// caller filters, and with an `items` collection `Items` does.
+    pub fn is_prefiltered(&self) -> bool {
+        self.collection.is_some() || self.virtualizer.with_value(Option::is_some)
+    }
+
+    /// Resolve a virtual row index to its item. When the row is not mounted yet it
+    /// is scrolled into view and marked focused so it focuses itself on render.
+    fn resolve_virtual(&self, v: Virtualizer, target: Option<usize>) -> Option<ComboboxItemContext> {
+        let item = self.items.with(|m| v.resolve(target, m));
+        if item.is_none() && target.is_some() {
+            self.set_focus(target);
+        }
+        item
+    }
 }
 
 impl FilterActiveItems<ComboboxItemContext> for ComboboxContext {
@@ -149,19 +175,31 @@ impl ManageFocus for ComboboxContext {
 // Navigation moves through visible (filtered) items only.
 impl NavigateItems<ComboboxItemContext> for ComboboxContext {
     fn navigate_first_item(&self) -> Option<ComboboxItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.first_index());
+        }
         self.visible_items().into_iter().next()
     }
 
     fn navigate_last_item(&self) -> Option<ComboboxItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.last_index());
+        }
         self.visible_items().into_iter().last()
     }
 
     fn navigate_next_item(&self) -> Option<ComboboxItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.next_index(self.item_focus.get(), true));
+        }
         let items = self.visible_items();
         next_item(items, self.item_focus.get(), true)
     }
 
     fn navigate_previous_item(&self) -> Option<ComboboxItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.previous_index(self.item_focus.get(), true));
+        }
         let items = self.visible_items();
         previous_item(items, self.item_focus.get(), true)
     }
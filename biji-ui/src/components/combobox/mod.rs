@@ -3,4 +3,9 @@ pub mod root;
 
 pub use crate::utils::positioning::{AvoidCollisions, Positioning};
 pub use context::ComboboxContext;
-pub use root::{Content, Empty, Input, InputTrigger, Item, ItemIndicator, ItemText, Root, Trigger, Value};
+pub use root::{
+    Content, Empty, Input, InputTrigger, Item, ItemIndicator, ItemText, Items, Root, Trigger,
+    Value, VirtualItems,
+};
+pub use crate::items::{Collection, CollectionItem};
+pub use crate::virtualizer::{ItemSize, ScrollAlign, Virtualizer};
@@ -1,4 +1,5 @@
 use std::{
+    marker::PhantomData,
     sync::{
         Arc, Mutex,
         atomic::{AtomicUsize, Ordering},
@@ -21,8 +22,12 @@ use wasm_bindgen::JsCast;
 use crate::{
     cn,
     custom_animated_show::CustomAnimatedShow,
-    items::{Focus, ManageFocus, NavigateItems},
+    items::{
+        Collection, CollectionItem, Focus, ManageFocus, NavigateItems, filter_matching,
+        render_groups,
+    },
     utils::positioning::{AvoidCollisions, Positioning},
+    virtualizer::{VirtualList, VirtualRow, Virtualizer},
 };
 
 use super::context::{ComboboxContext, ComboboxItemContext};
@@ -46,11 +51,19 @@ pub fn Root(
     /// Set to `true` when using `InputTrigger` (the inline Headless-UI-style combobox).
     #[prop(default = false)]
     inline: bool,
+    /// Data-driven items, rendered with [`Items`] and filtered by the query
+    /// against their label and keywords.
+    #[prop(into, optional)]
+    items: Option<Collection>,
 ) -> impl IntoView {
+    let initial_label = value
+        .as_deref()
+        .and_then(|v| items.and_then(|c| untrack(|| c.label_of(v))));
     let ctx = ComboboxContext {
         open: RwSignal::new(false),
         value: RwSignal::new(value),
-        selected_label: RwSignal::new(None),
+        selected_label: RwSignal::new(initial_label),
+        collection: items,
         query: RwSignal::new(String::new()),
         hide_delay,
         positioning,
@@ -91,12 +104,21 @@ fn RootEvents(children: Children) -> impl IntoView {
     let ctx = expect_context::<ComboboxContext>();
 
     // Auto-highlight the first visible item when the query changes (always reset)
-    // or when the item list changes and nothing is focused yet (initial mount / open).
+    // or when the item list changes and the focused item is gone (initial mount /
+    // open, or `Items` re-rendering the filtered list).
     Effect::new(move |prev_query: Option<String>| {
         let query = ctx.query.get();
-        ctx.items.with(|_| {}); // reactive dep without cloning
+        let focused = ctx.item_focus.get_untracked();
+        let focus_lost = match ctx.virtualizer.get_value() {
+            // Rows scrolled out of the overscan window unmount, so only a row
+            // that left the data counts as lost.
+            Some(v) => focused.is_none_or(|i| i >= v.count.get() || v.is_disabled(i)),
+            None => ctx
+                .items
+                .with(|m| focused.is_none_or(|i| !m.contains_key(&i))),
+        };
         let query_changed = prev_query.as_deref() != Some(query.as_str());
-        if query_changed || ctx.item_focus.get_untracked().is_none() {
+        if query_changed || focus_lost {
             let first = ctx.navigate_first_item();
             ctx.set_focus(first.map(|i| i.index));
         }
@@ -217,7 +239,19 @@ pub fn Trigger(children: Children, #[prop(into, optional)] class: String) -> imp
 #[component]
 pub fn Value(#[prop(into, optional)] placeholder: String) -> impl IntoView {
     let ctx = expect_context::<ComboboxContext>();
-    view! { <span>{move || ctx.selected_label.get().unwrap_or_else(|| placeholder.clone())}</span> }
+    view! {
+        <span>
+            {move || {
+                ctx.selected_label
+                    .get()
+                    .or_else(|| {
+                        let value = ctx.value.get()?;
+                        ctx.collection?.label_of(&value)
+                    })
+                    .unwrap_or_else(|| placeholder.clone())
+            }}
+        </span>
+    }
 }
 
 #[component]
@@ -604,6 +638,91 @@ pub fn InputTrigger(
     }
 }
 
+/// Renders the `items` passed to [`Root`] that match the current query, one
+/// [`Item`] per entry.
+///
+/// Matching is a case-insensitive substring test against the label and the
+/// keywords, so items can be found by aliases that are not displayed; disabled
+/// entries that match stay listed as disabled items. Entries that share a
+/// `group` are wrapped in a `role="group"` element headed by the group name.
+#[component]
+pub fn Items<T, F, IV>(
+    /// Returns the content of each item.
+    render: F,
+    #[prop(into, optional)] item_class: String,
+    #[prop(into, optional)] group_class: String,
+    #[prop(into, optional)] group_label_class: String,
+    /// Ties `T` to the props; never set.
+    #[prop(optional)]
+    _item: PhantomData<T>,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(CollectionItem<T>) -> IV + Clone + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let ctx = expect_context::<ComboboxContext>();
+    let items = ctx
+        .collection
+        .expect("combobox::Items requires the `items` prop on combobox::Root")
+        .typed::<T>();
+
+    move || {
+        let query = ctx.query.get();
+        let matching = items?.with(|items| filter_matching(items, &query));
+        let groups = render_groups(&matching, &group_class, &group_label_class, |item| {
+            let render = render.clone();
+            view! {
+                <Item
+                    value={item.value.clone()}
+                    label={item.label.clone()}
+                    disabled={item.disabled}
+                    class={item_class.clone()}
+                >
+                    {render(item)}
+                </Item>
+            }
+        });
+        Some(groups)
+    }
+}
+
+/// Renders items through a [`Virtualizer`] so only the rows in view are mounted.
+///
+/// `render` is called with a row index and should return an [`Item`]. Items are
+/// not filtered by the query in this mode: filter the collection yourself from
+/// [`ComboboxContext::query`] and size the virtualizer's `count` to the result.
+/// Arrow keys, Home and End move over every row, scrolling rows that are not
+/// rendered into view.
+#[component]
+pub fn VirtualItems<F, IV>(
+    virtualizer: Virtualizer,
+    render: F,
+    /// Stable identity for the row at an index, e.g. the item's value.
+    #[prop(optional)]
+    key: Option<Callback<usize, String>>,
+    #[prop(into, optional)] class: String,
+) -> impl IntoView
+where
+    F: Fn(usize) -> IV + Clone + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let ctx = expect_context::<ComboboxContext>();
+    ctx.virtualizer.set_value(Some(virtualizer));
+    on_cleanup(move || {
+        ctx.virtualizer.set_value(None);
+    });
+
+    match key {
+        Some(key) => view! {
+            <VirtualList virtualizer={virtualizer} render={render} key={key} class={class} />
+        }
+        .into_any(),
+        None => view! { <VirtualList virtualizer={virtualizer} render={render} class={class} /> }
+            .into_any(),
+    }
+}
+
 #[component]
 pub fn Item(
     children: Children,
@@ -613,8 +732,9 @@ pub fn Item(
     #[prop(default = false)] disabled: bool,
 ) -> impl IntoView {
     let ctx = expect_context::<ComboboxContext>();
+    let row = use_context::<VirtualRow>();
 
-    let index = ctx.next_index();
+    let index = row.map_or_else(|| ctx.next_index(), |r| r.index);
     let label_text = label.unwrap_or_else(|| value.clone());
     let item_ctx = ComboboxItemContext {
         index,
@@ -630,10 +750,15 @@ pub fn Item(
         ctx.remove_item(index);
     });
 
-    // Item is hidden when it doesn't match the current query.
+    if let Some(row) = row {
+        row.focus_when_pending(item_ctx.item_ref);
+    }
+
+    // Item is hidden when it doesn't match the current query, unless the list is
+    // already filtered before items mount.
     let is_visible = Memo::new(move |_| {
         let q = ctx.query.get().to_lowercase();
-        if q.is_empty() {
+        if q.is_empty() || ctx.is_prefiltered() {
             return true;
         }
         item_ctx.label.with_value(|l| l.to_lowercase().contains(&q))
@@ -683,6 +808,8 @@ pub fn Item(
                 aria-selected={move || if is_selected.get() { "true" } else { "false" }}
                 aria-hidden={move || if !is_visible.get() { Some("true") } else { None }}
                 aria-disabled={if item_ctx.disabled { Some("true") } else { None }}
+                aria-setsize={move || row.map(|r| r.set_size())}
+                aria-posinset={row.map(|r| r.pos_in_set())}
                 data-state={move || if is_selected.get() { "checked" } else { "unchecked" }}
                 data-disabled={item_ctx.disabled}
                 data-highlighted={move || ctx.item_in_focus(item_ctx.index)}
@@ -719,7 +846,10 @@ pub fn ItemIndicator(children: ChildrenFn) -> impl IntoView {
 #[component]
 pub fn Empty(children: ChildrenFn) -> impl IntoView {
     let ctx = expect_context::<ComboboxContext>();
-    let has_visible = Memo::new(move |_| !ctx.visible_items().is_empty());
+    let has_visible = Memo::new(move |_| match ctx.virtualizer.get_value() {
+        Some(v) => v.count.get() > 0,
+        None => !ctx.visible_items().is_empty(),
+    });
 
     view! {
         <Show when={move || !has_visible.get()} fallback={|| ()}>
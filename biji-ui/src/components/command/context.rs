@@ -16,10 +16,13 @@ use leptos::{
 };
 use wasm_bindgen::JsCast;
 
+use super::history::CommandHistory;
+use crate::components::dialog::context::DialogContext;
 use crate::items::{
-    FilterActiveItems, Focus, GetIndex, IsActive, ManageFocus, NavigateItems, filter_active,
-    next_item, previous_item,
+    Collection, FilterActiveItems, Focus, GetIndex, IsActive, ManageFocus, NavigateItems,
+    filter_active, next_item, previous_item,
 };
+use crate::virtualizer::Virtualizer;
 
 #[derive(Copy, Clone)]
 pub struct CommandContext {
@@ -30,6 +33,13 @@ pub struct CommandContext {
     pub items: RwSignal<HashMap<usize, CommandItemContext>>,
     /// Generated ID shared between Input (aria-controls) and List (id).
     pub list_id: StoredValue<String>,
+    /// Recent and pinned values, when `Root` was given a `history`.
+    pub history: Option<CommandHistory>,
+    /// Data-driven items passed to `Root`, including those hidden by the query.
+    pub collection: Option<Collection>,
+    /// Set while a `VirtualItems` part is mounted. Navigation then moves by row
+    /// index and filtering is left to the caller.
+    pub virtualizer: StoredValue<Option<Virtualizer>>,
     pub(crate) next_id: StoredValue<AtomicUsize>,
 }
 
@@ -42,6 +52,9 @@ impl Default for CommandContext {
             item_focus: RwSignal::new(None),
             items: RwSignal::new(HashMap::new()),
             list_id: StoredValue::new(next_command_id()),
+            history: None,
+            collection: None,
+            virtualizer: StoredValue::new(None),
             next_id: StoredValue::new(AtomicUsize::new(0)),
         }
     }
@@ -65,28 +78,65 @@ impl CommandContext {
     }
 
     pub fn is_item_visible(&self, index: usize) -> bool {
-        let q = self.query.get().to_lowercase();
-        if q.is_empty() {
-            return true;
+        if self.is_virtual() {
+            return self.items.with(|m| m.contains_key(&index));
         }
+        let q = self.query.get().to_lowercase();
         self.items.with(|m| {
             m.get(&index)
                 .map(|item| {
-                    !item.disabled && item.label.with_value(|l| l.to_lowercase().contains(&q))
+                    if item.in_recent {
+                        return q.is_empty();
+                    }
+                    q.is_empty()
+                        || self.collection.is_some()
+                        || (!item.disabled
+                            && item.label.with_value(|l| l.to_lowercase().contains(&q)))
                 })
                 .unwrap_or(false)
         })
     }
 
+    /// Visible, enabled items in navigation order, which is the order they are
+    /// rendered in.
+    ///
+    /// Items rendered by `Recent` come first. With an `items` collection only
+    /// matching items are mounted, already ranked by the history; with a
+    /// virtualizer the caller has already filtered and ordered the rows.
     pub fn visible_items(&self) -> Vec<CommandItemContext> {
+        if self.is_virtual() {
+            return filter_active(self.items.get());
+        }
         let q = self.query.get().to_lowercase();
-        let all = filter_active(self.items.get());
-        if q.is_empty() {
-            return all;
+        let mut items: Vec<CommandItemContext> = filter_active(self.items.get())
+            .into_iter()
+            .filter(|item| {
+                if item.in_recent {
+                    q.is_empty()
+                } else {
+                    q.is_empty()
+                        || self.collection.is_some()
+                        || item.label.with_value(|l| l.to_lowercase().contains(&q))
+                }
+            })
+            .collect();
+        // Stable, so index order is kept within each part.
+        items.sort_by_key(|item| !item.in_recent);
+        items
+    }
+
+    pub fn is_virtual(&self) -> bool {
+        self.virtualizer.with_value(Option::is_some)
+    }
+
+    /// Resolve a virtual row index to its item. When the row is not mounted yet it
+    /// is scrolled into view and marked focused so it focuses itself on render.
+    fn resolve_virtual(&self, v: Virtualizer, target: Option<usize>) -> Option<CommandItemContext> {
+        let item = self.items.with(|m| v.resolve(target, m));
+        if item.is_none() && target.is_some() {
+            self.set_focus(target);
         }
-        all.into_iter()
-            .filter(|item| item.label.with_value(|l| l.to_lowercase().contains(&q)))
-            .collect()
+        item
     }
 }
 
@@ -108,19 +158,31 @@ impl ManageFocus for CommandContext {
 
 impl NavigateItems<CommandItemContext> for CommandContext {
     fn navigate_first_item(&self) -> Option<CommandItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.first_index());
+        }
         self.visible_items().into_iter().next()
     }
 
     fn navigate_last_item(&self) -> Option<CommandItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.last_index());
+        }
         self.visible_items().into_iter().last()
     }
 
     fn navigate_next_item(&self) -> Option<CommandItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.next_index(self.item_focus.get(), true));
+        }
         let items = self.visible_items();
         next_item(items, self.item_focus.get(), true)
     }
 
     fn navigate_previous_item(&self) -> Option<CommandItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.previous_index(self.item_focus.get(), true));
+        }
         let items = self.visible_items();
         previous_item(items, self.item_focus.get(), true)
     }
@@ -133,6 +195,9 @@ pub struct CommandItemContext {
     pub label: StoredValue<String>,
     pub disabled: bool,
     pub item_ref: NodeRef<Div>,
+    pub on_select: Option<Callback<String>>,
+    /// `true` for the copies rendered by `Recent`.
+    pub in_recent: bool,
 }
 
 impl GetIndex<usize> for CommandItemContext {
@@ -166,3 +231,15 @@ impl Focus for CommandItemContext {
 pub struct CommandGroupContext {
     pub visible_count: RwSignal<usize>,
 }
+
+/// Provided by [`Dialog`](super::Dialog) so the command parts inside it can close
+/// the surrounding dialog and share its [`CommandContext`].
+#[derive(Copy, Clone)]
+pub struct CommandDialogContext {
+    pub dialog: DialogContext,
+    pub command: CommandContext,
+}
+
+/// Marker provided by `Recent` so the items it renders know they are history copies.
+#[derive(Copy, Clone)]
+pub struct CommandRecentContext;
@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use leptos::{context::Provider, ev::keydown, prelude::*};
+use leptos_use::{use_document, use_event_listener};
+
+use crate::{
+    components::dialog::context::{DialogContext, RootContext},
+    utils::{modal_stack::use_modal_layer, prevent_scroll::use_prevent_scroll, shortcut::Shortcut},
+};
+
+use super::context::{CommandContext, CommandDialogContext};
+
+/// A command palette rendered inside a dialog and toggled by a global keyboard shortcut.
+///
+/// Provides both [`DialogContext`] and [`CommandContext`], so the `dialog` parts
+/// (`Overlay`, `Content`, `Close`, `Trigger`) and the `command` parts can be
+/// composed inside it. Place a [`Root`](super::Root) inside `dialog::Content`; it
+/// picks up the palette's shared command state.
+///
+/// * Opening moves focus to [`Input`](super::Input).
+/// * Closing clears the query and returns focus to the element that was focused
+///   before the dialog opened.
+/// * Selecting an item runs its `on_select` and then closes the dialog.
+///
+/// # Example
+/// ```rust,ignore
+/// <command::Dialog shortcut="Mod+K">
+///     <dialog::Overlay class="fixed inset-0 bg-black/40" />
+///     <dialog::Content class="fixed top-24 left-1/2 -translate-x-1/2 w-[32rem]">
+///         <command::Root>
+///             <command::Input placeholder="Type a command…" />
+///             <command::List>
+///                 <command::Item value="settings" on_select={Callback::new(open_settings)}>
+///                     "Settings"
+///                 </command::Item>
+///             </command::List>
+///         </command::Root>
+///     </dialog::Content>
+/// </command::Dialog>
+/// ```
+#[component]
+pub fn Dialog(
+    children: Children,
+    #[prop(into, optional)] class: String,
+    /// Keyboard shortcut that toggles the dialog, e.g. `"Mod+K"` or `"Ctrl+Shift+P"`.
+    /// `Mod` is ⌘ on Apple platforms and Ctrl elsewhere. An empty string disables it.
+    #[prop(into, default = String::from("Mod+K"))]
+    shortcut: String,
+    /// Prevent scrolling when the dialog is open
+    #[prop(default = true)]
+    prevent_scroll: bool,
+    /// The timeout after which the component will be unmounted if `when == false`
+    #[prop(default = Duration::from_millis(200))]
+    hide_delay: Duration,
+) -> impl IntoView {
+    let root_ctx = RootContext::default();
+    let dialog_ctx = DialogContext {
+        root: RwSignal::new(root_ctx),
+        prevent_scroll,
+        hide_delay,
+        ..Default::default()
+    };
+    let ctx = CommandDialogContext {
+        dialog: dialog_ctx,
+        command: CommandContext::default(),
+    };
+    let shortcut = StoredValue::new(Shortcut::parse(&shortcut));
+
+    view! {
+        <Provider value={dialog_ctx}>
+            <Provider value={ctx.command}>
+                <Provider value={ctx}>
+                    <DialogEvents shortcut={shortcut}>
+                        <div class={class}>
+                            <Provider value={root_ctx}>{children()}</Provider>
+                        </div>
+                    </DialogEvents>
+                </Provider>
+            </Provider>
+        </Provider>
+    }
+}
+
+#[component]
+fn DialogEvents(children: Children, shortcut: StoredValue<Option<Shortcut>>) -> impl IntoView {
+    let ctx = expect_context::<CommandDialogContext>();
+    let dialog_ctx = ctx.dialog;
+    let cmd_ctx = ctx.command;
+
+    let scroll_eff = use_prevent_scroll(
+        move || dialog_ctx.prevent_scroll && dialog_ctx.open.get(),
+        dialog_ctx.hide_delay,
+    );
+    // Registered so modals opened from the palette, or below it, stack correctly.
+    // The layer also returns focus on close.
+    let layer_eff = use_modal_layer(
+        dialog_ctx.layer,
+        move || dialog_ctx.open.get(),
+        move || dialog_ctx.trigger_ref.get_untracked().map(Into::into),
+    );
+
+    let _ = use_event_listener(use_document(), keydown, move |evt| {
+        let matched = shortcut.with_value(|s| s.as_ref().is_some_and(|s| s.matches(&evt)));
+        if !matched {
+            return;
+        }
+        evt.prevent_default();
+        if dialog_ctx.open.get_untracked() {
+            dialog_ctx.close();
+        } else {
+            dialog_ctx.open();
+        }
+    });
+
+    Effect::new(move |was_open: Option<bool>| {
+        let is_open = dialog_ctx.open.get();
+        match (was_open.unwrap_or(false), is_open) {
+            (false, true) => {
+                // Runs after dialog::Content's own autofocus so the input wins.
+                let _ = leptos::leptos_dom::helpers::set_timeout_with_handle(
+                    move || {
+                        if let Some(input) = cmd_ctx.input_ref.get() {
+                            let _ = input.focus();
+                        }
+                    },
+                    Duration::from_millis(20),
+                );
+            }
+            (true, false) => {
+                cmd_ctx.query.set(String::new());
+                cmd_ctx.item_focus.set(None);
+            }
+            _ => {}
+        }
+        is_open
+    });
+
+    on_cleanup(move || {
+        drop(scroll_eff);
+        drop(layer_eff);
+    });
+
+    children()
+}
@@ -0,0 +1,305 @@
+//! Recently used and pinned command values.
+//!
+//! A [`CommandHistory`] is passed to [`Root`](super::Root) via the `history` prop.
+//! Every `on_select` is recorded, [`Recent`](super::Recent) renders the pinned and
+//! recent items while the query is empty, and [`Items`](super::Items) ranks
+//! recent items first within their group while the user is typing. Persistence is
+//! pluggable through [`HistoryStore`].
+
+use std::collections::HashMap;
+
+use leptos::prelude::*;
+
+use crate::items::CollectionItem;
+
+/// Snapshot of the pinned and recently selected values, most recent first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HistoryData {
+    pub recent: Vec<String>,
+    pub pinned: Vec<String>,
+}
+
+/// Persistence backend for [`CommandHistory`].
+///
+/// `load` is only called on the client, after hydration, so implementations may
+/// touch browser APIs freely.
+pub trait HistoryStore: Send + Sync {
+    /// Read the persisted history. Return `HistoryData::default()` when empty.
+    fn load(&self) -> HistoryData;
+    /// Persist the given history.
+    fn save(&self, data: &HistoryData);
+}
+
+/// Keeps history in memory only; it is lost on reload.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MemoryHistory;
+
+impl HistoryStore for MemoryHistory {
+    fn load(&self) -> HistoryData {
+        HistoryData::default()
+    }
+
+    fn save(&self, _data: &HistoryData) {}
+}
+
+/// Persists history in `window.localStorage` under `{key}:recent` and `{key}:pinned`.
+#[derive(Clone, Debug)]
+pub struct LocalStorageHistory {
+    pub key: String,
+}
+
+impl LocalStorageHistory {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+
+    fn storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok().flatten()
+    }
+}
+
+impl HistoryStore for LocalStorageHistory {
+    fn load(&self) -> HistoryData {
+        let Some(storage) = Self::storage() else {
+            return HistoryData::default();
+        };
+        let read = |suffix: &str| {
+            storage
+                .get_item(&format!("{}:{suffix}", self.key))
+                .ok()
+                .flatten()
+                .map(|s| decode_list(&s))
+                .unwrap_or_default()
+        };
+        HistoryData {
+            recent: read("recent"),
+            pinned: read("pinned"),
+        }
+    }
+
+    fn save(&self, data: &HistoryData) {
+        let Some(storage) = Self::storage() else {
+            return;
+        };
+        let _ = storage.set_item(&format!("{}:recent", self.key), &encode_list(&data.recent));
+        let _ = storage.set_item(&format!("{}:pinned", self.key), &encode_list(&data.pinned));
+    }
+}
+
+/// Default number of recent values kept by [`CommandHistory`].
+pub const DEFAULT_HISTORY_LIMIT: usize = 5;
+
+/// Reactive handle to the command history. Cheap to copy.
+#[derive(Copy, Clone)]
+pub struct CommandHistory {
+    pub data: RwSignal<HistoryData>,
+    pub limit: usize,
+    store: StoredValue<Box<dyn HistoryStore>>,
+}
+
+impl CommandHistory {
+    /// Create a history backed by `store`, keeping at most `limit` recent values.
+    ///
+    /// The store is read in an effect so server-rendered markup stays identical to
+    /// the first client render.
+    pub fn new(store: impl HistoryStore + 'static, limit: usize) -> Self {
+        let history = Self {
+            data: RwSignal::new(HistoryData::default()),
+            limit,
+            store: StoredValue::new(Box::new(store)),
+        };
+        Effect::new(move |_| {
+            let loaded = history.store.with_value(|s| s.load());
+            history.data.set(loaded);
+        });
+        history
+    }
+
+    /// History persisted in `localStorage` under `key`.
+    pub fn local_storage(key: impl Into<String>) -> Self {
+        Self::new(LocalStorageHistory::new(key), DEFAULT_HISTORY_LIMIT)
+    }
+
+    /// History kept in memory for the lifetime of the page.
+    pub fn in_memory() -> Self {
+        Self::new(MemoryHistory, DEFAULT_HISTORY_LIMIT)
+    }
+
+    /// Move `value` to the front of the recent list.
+    pub fn record(&self, value: &str) {
+        let limit = self.limit;
+        self.update(|d| push_recent(&mut d.recent, value, limit));
+    }
+
+    pub fn pin(&self, value: &str) {
+        self.update(|d| {
+            if !d.pinned.iter().any(|v| v == value) {
+                d.pinned.push(value.to_string());
+            }
+        });
+    }
+
+    pub fn unpin(&self, value: &str) {
+        self.update(|d| d.pinned.retain(|v| v != value));
+    }
+
+    pub fn toggle_pin(&self, value: &str) {
+        if self.is_pinned(value) {
+            self.unpin(value);
+        } else {
+            self.pin(value);
+        }
+    }
+
+    pub fn is_pinned(&self, value: &str) -> bool {
+        self.data.with(|d| d.pinned.iter().any(|v| v == value))
+    }
+
+    /// Clear recent values. Pinned values are kept.
+    pub fn clear_recent(&self) {
+        self.update(|d| d.recent.clear());
+    }
+
+    /// Pinned values followed by recent values that are not pinned.
+    pub fn entries(&self) -> Vec<String> {
+        self.data.with(ordered_entries)
+    }
+
+    /// Position of every value in [`entries`](Self::entries), keyed by value;
+    /// lower ranks sort first.
+    pub fn ranks(&self) -> HashMap<String, usize> {
+        self.data.with(|d| {
+            ordered_entries(d)
+                .into_iter()
+                .enumerate()
+                .map(|(rank, value)| (value, rank))
+                .collect()
+        })
+    }
+
+    fn update(&self, f: impl FnOnce(&mut HistoryData)) {
+        self.data.update(f);
+        self.data
+            .with_untracked(|d| self.store.with_value(|s| s.save(d)));
+    }
+}
+
+fn ordered_entries(data: &HistoryData) -> Vec<String> {
+    data.pinned
+        .iter()
+        .chain(data.recent.iter().filter(|v| !data.pinned.contains(v)))
+        .cloned()
+        .collect()
+}
+
+/// Move ranked items ahead of the rest of their group, in rank order. Each run of
+/// items sharing a group stays in place, so group headings are not split.
+pub(super) fn sort_by_rank<T>(
+    items: Vec<CollectionItem<T>>,
+    ranks: &HashMap<String, usize>,
+) -> Vec<CollectionItem<T>> {
+    let mut run = 0;
+    let mut keyed: Vec<((usize, usize), CollectionItem<T>)> = Vec::with_capacity(items.len());
+    for item in items {
+        if keyed
+            .last()
+            .is_some_and(|(_, prev)| prev.group != item.group)
+        {
+            run += 1;
+        }
+        let rank = ranks.get(&item.value).copied().unwrap_or(usize::MAX);
+        keyed.push(((run, rank), item));
+    }
+    // Stable, so unranked items keep their order.
+    keyed.sort_by_key(|(key, _)| *key);
+    keyed.into_iter().map(|(_, item)| item).collect()
+}
+
+fn push_recent(recent: &mut Vec<String>, value: &str, limit: usize) {
+    recent.retain(|v| v != value);
+    recent.insert(0, value.to_string());
+    recent.truncate(limit);
+}
+
+/// Newline-separated list with `\` and newlines escaped.
+fn encode_list(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|v| v.replace('\\', "\\\\").replace('\n', "\\n"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn decode_list(s: &str) -> Vec<String> {
+    s.split('\n')
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut out = String::with_capacity(line.len());
+            let mut chars = line.chars();
+            while let Some(c) = chars.next() {
+                if c == '\\' {
+                    match chars.next() {
+                        Some('n') => out.push('\n'),
+                        Some(other) => out.push(other),
+                        None => out.push('\\'),
+                    }
+                } else {
+                    out.push(c);
+                }
+            }
+            out
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_recent_dedupes_and_truncates() {
+        let mut recent = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        push_recent(&mut recent, "c", 3);
+        assert_eq!(recent, ["c", "a", "b"]);
+        push_recent(&mut recent, "d", 3);
+        assert_eq!(recent, ["d", "c", "a"]);
+    }
+
+    #[test]
+    fn pinned_entries_come_first_without_duplicates() {
+        let data = HistoryData {
+            recent: vec!["a".into(), "b".into(), "c".into()],
+            pinned: vec!["b".into(), "z".into()],
+        };
+        assert_eq!(ordered_entries(&data), ["b", "z", "a", "c"]);
+    }
+
+    #[test]
+    fn ranked_items_move_up_within_their_group() {
+        let item = |value: &str, group: &str| CollectionItem::new(value, value).group(group);
+        let items = vec![
+            item("a", "x"),
+            item("b", "x"),
+            item("c", "x"),
+            item("d", "y"),
+            item("e", "y"),
+        ];
+        let ranks = HashMap::from([("e".to_string(), 0), ("c".to_string(), 1)]);
+        let sorted: Vec<String> = sort_by_rank(items, &ranks)
+            .into_iter()
+            .map(|item| item.value)
+            .collect();
+        assert_eq!(sorted, ["c", "a", "b", "e", "d"]);
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let values = vec![
+            "plain".to_string(),
+            "multi\nline".to_string(),
+            "back\\slash".to_string(),
+        ];
+        assert_eq!(decode_list(&encode_list(&values)), values);
+        assert!(decode_list("").is_empty());
+    }
+}
@@ -1,4 +1,10 @@
 pub mod context;
+pub mod dialog;
+pub mod history;
 pub mod root;
 
-pub use root::{Empty, Group, HighlightedText, Input, Item, List, Root};
+pub use dialog::Dialog;
+pub use history::{CommandHistory, HistoryData, HistoryStore, LocalStorageHistory, MemoryHistory};
+pub use root::{Empty, Group, HighlightedText, Input, Item, Items, List, Recent, Root, VirtualItems};
+pub use crate::items::{Collection, CollectionItem};
+pub use crate::virtualizer::{ItemSize, ScrollAlign, Virtualizer};
@@ -1,3 +1,5 @@
+use std::marker::PhantomData;
+
 use leptos::{
     context::Provider,
     ev::{click, focus, keydown, mouseover},
@@ -5,16 +7,44 @@ use leptos::{
 };
 use leptos_use::use_event_listener;
 
-use crate::items::{Focus, ManageFocus, NavigateItems};
+use crate::{
+    items::{
+        Collection, CollectionItem, Focus, ManageFocus, NavigateItems, filter_matching,
+        render_groups,
+    },
+    virtualizer::{VirtualList, VirtualRow, Virtualizer},
+};
 
-use super::context::{CommandContext, CommandGroupContext, CommandItemContext};
+use super::{
+    context::{
+        CommandContext, CommandDialogContext, CommandGroupContext, CommandItemContext,
+        CommandRecentContext,
+    },
+    history::{CommandHistory, sort_by_rank},
+};
 
 #[component]
 pub fn Root(
     children: Children,
     #[prop(into, optional)] class: String,
+    /// Records selected values so `Recent` can list them and `Items` can rank them
+    /// first while the user is typing.
+    #[prop(optional)]
+    history: Option<CommandHistory>,
+    /// Data-driven items, rendered with [`Items`] and filtered by the query
+    /// against their label and keywords.
+    #[prop(into, optional)]
+    items: Option<Collection>,
 ) -> impl IntoView {
-    let ctx = CommandContext::default();
+    // Inside a command `Dialog`, share its context so the dialog can reset the
+    // query and focus the input.
+    let ctx = CommandContext {
+        history,
+        collection: items,
+        ..use_context::<CommandDialogContext>()
+            .map(|d| d.command)
+            .unwrap_or_default()
+    };
 
     view! {
         <Provider value={ctx}>
@@ -32,12 +62,21 @@ fn RootEvents(children: Children) -> impl IntoView {
     let ctx = expect_context::<CommandContext>();
 
     // Auto-highlight the first visible item when the query changes (always reset)
-    // or when the item list changes and nothing is focused yet (initial mount).
+    // or when the item list changes and the focused item is gone (initial mount,
+    // or `Items` re-rendering the filtered list).
     Effect::new(move |prev_query: Option<String>| {
         let query = ctx.query.get();
-        ctx.items.with(|_| {}); // reactive dep without cloning
+        let focused = ctx.item_focus.get_untracked();
+        let focus_lost = match ctx.virtualizer.get_value() {
+            // Rows scrolled out of the overscan window unmount, so only a row
+            // that left the data counts as lost.
+            Some(v) => focused.is_none_or(|i| i >= v.count.get() || v.is_disabled(i)),
+            None => ctx
+                .items
+                .with(|m| focused.is_none_or(|i| !m.contains_key(&i))),
+        };
         let query_changed = prev_query.as_deref() != Some(query.as_str());
-        if query_changed || ctx.item_focus.get_untracked().is_none() {
+        if query_changed || focus_lost {
             let first = ctx.navigate_first_item();
             ctx.set_focus(first.map(|i| i.index));
         }
@@ -119,6 +158,7 @@ pub fn Input(
             aria-expanded="true"
             aria-autocomplete="list"
             aria-controls={ctx.list_id.get_value()}
+            prop:value={move || ctx.query.get()}
             placeholder={placeholder}
             autocomplete="off"
             class={class}
@@ -170,6 +210,118 @@ pub fn Group(
     }
 }
 
+/// Renders the `items` passed to [`Root`] that match the current query, one
+/// [`Item`] per entry.
+///
+/// Matching is a case-insensitive substring test against the label and the
+/// keywords; disabled entries that match stay listed as disabled items.
+/// Entries that share a `group` are wrapped in a `role="group"` element headed
+/// by the group name.
+///
+/// # Example
+/// ```rust,ignore
+/// let commands = vec![
+///     CollectionItem::new("new-file", "New File").group("File").keywords(["create"]),
+///     CollectionItem::new("settings", "Settings").group("Preferences"),
+/// ];
+/// view! {
+///     <command::Root items={commands}>
+///         <command::Input />
+///         <command::List>
+///             <command::Items
+///                 render={|item: CollectionItem| item.label}
+///                 on_select={Callback::new(run_command)}
+///             />
+///         </command::List>
+///     </command::Root>
+/// }
+/// ```
+#[component]
+pub fn Items<T, F, IV>(
+    /// Returns the content of each item.
+    render: F,
+    /// Called with the value of the selected item.
+    #[prop(optional)]
+    on_select: Option<Callback<String>>,
+    #[prop(into, optional)] item_class: String,
+    #[prop(into, optional)] group_class: String,
+    #[prop(into, optional)] group_label_class: String,
+    /// Ties `T` to the props; never set.
+    #[prop(optional)]
+    _item: PhantomData<T>,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(CollectionItem<T>) -> IV + Clone + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let ctx = expect_context::<CommandContext>();
+    let items = ctx
+        .collection
+        .expect("command::Items requires the `items` prop on command::Root")
+        .typed::<T>();
+    let on_select = on_select.unwrap_or_else(|| Callback::new(|_| {}));
+
+    move || {
+        let query = ctx.query.get();
+        let mut matching = items?.with(|items| filter_matching(items, &query));
+        if let Some(history) = ctx.history.filter(|_| !query.is_empty()) {
+            matching = sort_by_rank(matching, &history.ranks());
+        }
+        let groups = render_groups(&matching, &group_class, &group_label_class, |item| {
+            let render = render.clone();
+            view! {
+                <Item
+                    value={item.value.clone()}
+                    label={item.label.clone()}
+                    disabled={item.disabled}
+                    class={item_class.clone()}
+                    on_select={on_select}
+                >
+                    {render(item)}
+                </Item>
+            }
+        });
+        Some(groups)
+    }
+}
+
+/// Renders items through a [`Virtualizer`] so only the rows in view are mounted.
+///
+/// Place it inside [`List`]; `render` is called with a row index and should return
+/// an [`Item`]. Items are not filtered or ranked by the query in this mode: filter
+/// the collection yourself from [`CommandContext::query`] and size the
+/// virtualizer's `count` to the result. All items, including [`Recent`], must be
+/// rendered through it.
+#[component]
+pub fn VirtualItems<F, IV>(
+    virtualizer: Virtualizer,
+    render: F,
+    /// Stable identity for the row at an index, e.g. the item's value.
+    #[prop(optional)]
+    key: Option<Callback<usize, String>>,
+    #[prop(into, optional)] class: String,
+) -> impl IntoView
+where
+    F: Fn(usize) -> IV + Clone + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let ctx = expect_context::<CommandContext>();
+    ctx.virtualizer.set_value(Some(virtualizer));
+    on_cleanup(move || {
+        ctx.virtualizer.set_value(None);
+    });
+
+    match key {
+        Some(key) => view! {
+            <VirtualList virtualizer={virtualizer} render={render} key={key} class={class} />
+        }
+        .into_any(),
+        None => view! { <VirtualList virtualizer={virtualizer} render={render} class={class} /> }
+            .into_any(),
+    }
+}
+
 #[component]
 pub fn Item(
     children: Children,
@@ -181,8 +333,11 @@ pub fn Item(
 ) -> impl IntoView {
     let ctx = expect_context::<CommandContext>();
     let group_ctx = use_context::<CommandGroupContext>();
+    let dialog_ctx = use_context::<CommandDialogContext>();
+    let in_recent = use_context::<CommandRecentContext>().is_some();
+    let row = use_context::<VirtualRow>();
 
-    let index = ctx.next_index();
+    let index = row.map_or_else(|| ctx.next_index(), |r| r.index);
     let label_text = label.unwrap_or_else(|| value.clone());
     let item_ctx = CommandItemContext {
         index,
@@ -190,11 +345,17 @@ pub fn Item(
         label: StoredValue::new(label_text),
         disabled,
         item_ref: NodeRef::new(),
+        on_select,
+        in_recent,
     };
 
     ctx.upsert_item(index, item_ctx);
     on_cleanup(move || ctx.remove_item(index));
 
+    if let Some(row) = row {
+        row.focus_when_pending(item_ctx.item_ref);
+    }
+
     let is_visible = Memo::new(move |_| ctx.is_item_visible(index));
 
     // Keep group counter in sync
@@ -228,24 +389,31 @@ pub fn Item(
         });
     }
 
-    let _ = use_event_listener(item_ctx.item_ref, click, move |_| {
-        if item_ctx.disabled || !is_visible.get_untracked() {
-            return;
+    let select = move || {
+        let val = item_ctx.value.with_value(|v| v.clone());
+        if let Some(history) = ctx.history {
+            history.record(&val);
         }
         if let Some(cb) = on_select {
-            let val = item_ctx.value.with_value(|v| v.clone());
             cb.run(val);
         }
+        if let Some(d) = dialog_ctx {
+            d.dialog.close();
+        }
+    };
+
+    let _ = use_event_listener(item_ctx.item_ref, click, move |_| {
+        if item_ctx.disabled || !is_visible.get_untracked() {
+            return;
+        }
+        select();
     });
 
     let _ = use_event_listener(item_ctx.item_ref, keydown, move |evt| {
         if evt.key() == "Enter" {
             evt.prevent_default();
             if !item_ctx.disabled {
-                if let Some(cb) = on_select {
-                    let val = item_ctx.value.with_value(|v| v.clone());
-                    cb.run(val);
-                }
+                select();
             }
         }
     });
@@ -269,8 +437,16 @@ pub fn Item(
             tabindex="-1"
             aria-disabled={if item_ctx.disabled { Some("true") } else { None }}
             aria-selected="false"
+            aria-setsize={move || row.map(|r| r.set_size())}
+            aria-posinset={row.map(|r| r.pos_in_set())}
             data-disabled={if item_ctx.disabled { Some("true") } else { None }}
             data-highlighted={move || if ctx.item_in_focus(item_ctx.index) { Some("true") } else { None }}
+            data-pinned={move || {
+                let pinned = ctx
+                    .history
+                    .is_some_and(|h| item_ctx.value.with_value(|v| h.is_pinned(v)));
+                if pinned { Some("true") } else { None }
+            }}
             style={move || if is_visible.get() { "" } else { "display:none;" }}
             class={class}
         >
@@ -279,10 +455,100 @@ pub fn Item(
     }
 }
 
+/// Renders pinned and recently selected items as a group while the query is empty.
+///
+/// Requires a `history` on [`Root`]. Each entry reuses the label and `on_select` of
+/// the matching [`Item`] elsewhere in the list, so only values whose `Item` is
+/// mounted are shown. Pinned entries come first and carry `data-pinned`.
+///
+/// # Example
+/// ```rust,ignore
+/// <command::Root history={CommandHistory::local_storage("app-palette")}>
+///     <command::Input />
+///     <command::List>
+///         <command::Recent label="Recent" item_class={ITEM_CLS} />
+///         <command::Group label="Actions">
+///             <command::Item value="new-file">"New File"</command::Item>
+///         </command::Group>
+///     </command::List>
+/// </command::Root>
+/// ```
+#[component]
+pub fn Recent(
+    #[prop(into, optional)] class: String,
+    #[prop(into, default = String::from("Recent"))] label: String,
+    #[prop(into, optional)] label_class: String,
+    /// CSS class applied to each rendered item.
+    #[prop(into, optional)]
+    item_class: String,
+) -> impl IntoView {
+    let ctx = expect_context::<CommandContext>();
+    let item_class = StoredValue::new(item_class);
+
+    // Resolve history values to the label and callback of their source items.
+    let entries = Memo::new(move |_| {
+        let Some(history) = ctx.history else {
+            return Vec::new();
+        };
+        let values = history.entries();
+        ctx.items.with(|items| {
+            values
+                .into_iter()
+                .filter_map(|value| {
+                    items
+                        .values()
+                        .find(|item| {
+                            !item.in_recent
+                                && !item.disabled
+                                && item.value.with_value(|v| *v == value)
+                        })
+                        .map(|item| (value, item.label.get_value(), item.index))
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    view! {
+        <Provider value={CommandRecentContext}>
+            <Group class={class} label={label} label_class={label_class}>
+                {move || {
+                    // Not keyed: each entry takes a fresh index from
+                    // `next_index` when it is created, and `visible_items` orders
+                    // recent items by index. A kept row would hold its old index
+                    // after the history reorders, so every entry is re-created.
+                    entries
+                        .get()
+                        .into_iter()
+                        .map(|(value, label, source)| {
+                            let on_select = ctx
+                                .items
+                                .with_untracked(|m| m.get(&source).and_then(|i| i.on_select))
+                                .unwrap_or_else(|| Callback::new(|_| {}));
+                            view! {
+                                <Item
+                                    value={value}
+                                    label={label.clone()}
+                                    class={item_class.get_value()}
+                                    on_select={on_select}
+                                >
+                                    {label}
+                                </Item>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </Group>
+        </Provider>
+    }
+}
+
 #[component]
 pub fn Empty(children: ChildrenFn) -> impl IntoView {
     let ctx = expect_context::<CommandContext>();
-    let has_visible = Memo::new(move |_| !ctx.visible_items().is_empty());
+    let has_visible = Memo::new(move |_| match ctx.virtualizer.get_value() {
+        Some(v) => v.count.get() > 0,
+        None => !ctx.visible_items().is_empty(),
+    });
 
     view! {
         <Show when={move || !has_visible.get()} fallback={|| ()}>
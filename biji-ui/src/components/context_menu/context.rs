@@ -7,11 +7,22 @@ use std::{
 use leptos::{html::Div, prelude::*};
 use wasm_bindgen::JsCast;
 
-use crate::items::{
-    FilterActiveItems, Focus, GetIndex, IsActive, ManageFocus, NavigateItems, filter_active,
-    next_item, previous_item,
+use crate::{
+    items::{
+        Collection, FilterActiveItems, Focus, GetIndex, IsActive, ManageFocus, NavigateItems,
+        filter_active, next_item, previous_item,
+    },
+    utils::{
+        long_press::LongPress,
+        polygon::{make_hull, point_in_polygon},
+        positioning::{AvoidCollisions, Positioning},
+    },
 };
 
+/// How long the pointer may travel from a `SubTrigger` towards its open
+/// `SubContent` before the items it passes over react to it again.
+const POINTER_GRACE_MS: f64 = 300.0;
+
 #[derive(Copy, Clone)]
 pub struct ContextMenuContext {
     pub trigger_ref: NodeRef<Div>,
@@ -23,10 +34,15 @@ pub struct ContextMenuContext {
     pub pointer_y: RwSignal<f64>,
     pub item_focus: RwSignal<Option<usize>>,
     pub items: RwSignal<HashMap<usize, ContextMenuItemContext>>,
+    /// Data-driven items passed to `Root`, rendered with `Items`.
+    pub collection: Option<Collection>,
     pub allow_loop: bool,
     pub hide_delay: Duration,
+    /// Touch state; a long press on the trigger opens the menu.
+    pub long_press: LongPress,
     pub(crate) menu_id: StoredValue<String>,
     pub(crate) next_id: StoredValue<AtomicUsize>,
+    pub(crate) pointer_grace: StoredValue<Option<PointerGrace>>,
     pub(crate) on_open_change: Option<Callback<bool>>,
 }
 
@@ -57,6 +73,7 @@ impl ContextMenuContext {
     }
 
     pub fn close(&self) {
+        MenuLevel::Root(*self).close_subs_except(usize::MAX);
         self.open.set(false);
         self.item_focus.set(None);
         if let Some(cb) = self.on_open_change {
@@ -106,6 +123,10 @@ pub struct ContextMenuItemContext {
     pub index: usize,
     pub disabled: bool,
     pub item_ref: NodeRef<Div>,
+    /// Whether selecting the item closes the menu.
+    pub close_on_select: bool,
+    /// Set when the item is a `SubTrigger`; the submenu it opens.
+    pub sub_menu: Option<SubMenuContext>,
     pub(crate) on_select: Option<Callback<()>>,
 }
 
@@ -134,3 +155,246 @@ impl Focus for ContextMenuItemContext {
         false
     }
 }
+
+/// Provided by `CheckboxItem` and `RadioItem` to their `ItemIndicator`.
+#[derive(Copy, Clone)]
+pub struct CheckableItemContext {
+    pub checked: Signal<bool>,
+}
+
+/// Provided by `RadioGroup`; holds the value of the checked `RadioItem`.
+#[derive(Copy, Clone)]
+pub struct RadioGroupContext {
+    pub value: RwSignal<String>,
+    pub(crate) on_value_change: Option<Callback<String>>,
+}
+
+/// Provided by `Sub`; the state of one nested menu panel.
+#[derive(Copy, Clone)]
+pub struct SubMenuContext {
+    /// Index of the `SubTrigger` in the parent panel.
+    pub index: usize,
+    pub disabled: bool,
+    pub open: RwSignal<bool>,
+    pub trigger_ref: NodeRef<Div>,
+    pub content_ref: NodeRef<Div>,
+    pub item_focus: RwSignal<Option<usize>>,
+    pub items: RwSignal<HashMap<usize, ContextMenuItemContext>>,
+    pub allow_loop: bool,
+    pub positioning: Positioning,
+    pub avoid_collisions: AvoidCollisions,
+    pub hide_delay: Duration,
+    pub(crate) next_id: StoredValue<AtomicUsize>,
+    pub(crate) pointer_grace: StoredValue<Option<PointerGrace>>,
+    /// Focus the first item once the panel has mounted. Set when the submenu
+    /// is opened from the keyboard.
+    pub(crate) focus_first: StoredValue<bool>,
+}
+
+impl SubMenuContext {
+    pub fn next_index(&self) -> usize {
+        self.next_id.with_value(|c| c.fetch_add(1, Ordering::Relaxed))
+    }
+
+    pub fn open(&self, focus_first: bool) {
+        self.focus_first.set_value(focus_first);
+        if !self.open.get_untracked() {
+            self.open.set(true);
+        }
+    }
+
+    /// Closes this submenu and every submenu nested inside it.
+    pub fn close(&self) {
+        MenuLevel::Sub(*self).close_subs_except(usize::MAX);
+        if self.open.get_untracked() {
+            self.open.set(false);
+        }
+        self.item_focus.set(None);
+    }
+}
+
+/// The panel an item is rendered in: the root `Content` or a `SubContent`.
+#[derive(Copy, Clone)]
+pub enum MenuLevel {
+    Root(ContextMenuContext),
+    Sub(SubMenuContext),
+}
+
+impl MenuLevel {
+    /// The nearest panel: the enclosing `Sub`, or the menu root.
+    pub fn current() -> Self {
+        use_context::<SubMenuContext>()
+            .map(MenuLevel::Sub)
+            .unwrap_or_else(|| MenuLevel::Root(expect_context::<ContextMenuContext>()))
+    }
+
+    pub fn items(&self) -> RwSignal<HashMap<usize, ContextMenuItemContext>> {
+        match self {
+            MenuLevel::Root(ctx) => ctx.items,
+            MenuLevel::Sub(sub) => sub.items,
+        }
+    }
+
+    pub fn item_focus(&self) -> RwSignal<Option<usize>> {
+        match self {
+            MenuLevel::Root(ctx) => ctx.item_focus,
+            MenuLevel::Sub(sub) => sub.item_focus,
+        }
+    }
+
+    fn allow_loop(&self) -> bool {
+        match self {
+            MenuLevel::Root(ctx) => ctx.allow_loop,
+            MenuLevel::Sub(sub) => sub.allow_loop,
+        }
+    }
+
+    fn pointer_grace(&self) -> StoredValue<Option<PointerGrace>> {
+        match self {
+            MenuLevel::Root(ctx) => ctx.pointer_grace,
+            MenuLevel::Sub(sub) => sub.pointer_grace,
+        }
+    }
+
+    pub fn next_index(&self) -> usize {
+        match self {
+            MenuLevel::Root(ctx) => ctx.next_index(),
+            MenuLevel::Sub(sub) => sub.next_index(),
+        }
+    }
+
+    pub fn upsert_item(&self, index: usize, item: ContextMenuItemContext) {
+        self.items().update(|m| {
+            m.insert(index, item);
+        });
+    }
+
+    pub fn remove_item(&self, index: usize) {
+        self.items().update(|m| {
+            m.remove(&index);
+        });
+    }
+
+    /// The highlighted item, if any.
+    pub fn focused_item(&self) -> Option<ContextMenuItemContext> {
+        let index = self.item_focus().get_untracked()?;
+        self.items().with_untracked(|m| m.get(&index).copied())
+    }
+
+    /// Closes every open submenu of this panel except the one triggered by
+    /// the item at `except_index`.
+    pub fn close_subs_except(&self, except_index: usize) {
+        let subs = self.items().with_untracked(|m| {
+            m.values()
+                .filter(|item| item.index != except_index)
+                .filter_map(|item| item.sub_menu)
+                .collect::<Vec<_>>()
+        });
+        for sub in subs {
+            sub.close();
+        }
+    }
+
+    pub(crate) fn set_pointer_grace(&self, grace: Option<PointerGrace>) {
+        self.pointer_grace().set_value(grace);
+    }
+
+    /// `true` while the pointer is moving from a `SubTrigger` towards its
+    /// `SubContent`, so items it passes over should not react.
+    pub(crate) fn in_pointer_grace(&self, point: (f64, f64), now: f64) -> bool {
+        self.pointer_grace()
+            .with_value(|g| g.as_ref().is_some_and(|g| g.contains(point, now)))
+    }
+}
+
+impl FilterActiveItems<ContextMenuItemContext> for MenuLevel {
+    fn filter_active_items(&self) -> Vec<ContextMenuItemContext> {
+        filter_active(self.items().get())
+    }
+}
+
+impl ManageFocus for MenuLevel {
+    fn set_focus(&self, index: Option<usize>) {
+        self.item_focus().set(index);
+    }
+
+    fn item_in_focus(&self, index: usize) -> bool {
+        self.item_focus().get() == Some(index)
+    }
+}
+
+impl NavigateItems<ContextMenuItemContext> for MenuLevel {
+    fn navigate_first_item(&self) -> Option<ContextMenuItemContext> {
+        self.filter_active_items().into_iter().next()
+    }
+
+    fn navigate_last_item(&self) -> Option<ContextMenuItemContext> {
+        self.filter_active_items().into_iter().last()
+    }
+
+    fn navigate_next_item(&self) -> Option<ContextMenuItemContext> {
+        let items = self.filter_active_items();
+        next_item(items, self.item_focus().get(), self.allow_loop())
+    }
+
+    fn navigate_previous_item(&self) -> Option<ContextMenuItemContext> {
+        let items = self.filter_active_items();
+        previous_item(items, self.item_focus().get(), self.allow_loop())
+    }
+}
+
+/// Provided by `Sub` to its `SubTrigger`: the panel the trigger is rendered in.
+#[derive(Copy, Clone)]
+pub(crate) struct SubTriggerContext {
+    pub parent: MenuLevel,
+}
+
+/// Area the pointer may cross on its way from a `SubTrigger` to the open
+/// `SubContent`: the hull of the exit point and the content's corners.
+#[derive(Clone, Debug)]
+pub(crate) struct PointerGrace {
+    polygon: Vec<(f64, f64)>,
+    expires_at: f64,
+}
+
+impl PointerGrace {
+    /// `content` is the `(top, right, bottom, left)` rect of the `SubContent`.
+    pub fn new(exit: (f64, f64), content: (f64, f64, f64, f64), now: f64) -> Self {
+        let (top, right, bottom, left) = content;
+        // Move the apex slightly back over the trigger so the exit point itself
+        // lies inside the area.
+        let side = if left >= exit.0 { -1.0 } else { 1.0 };
+        let apex = (exit.0 + side * 5.0, exit.1);
+        Self {
+            polygon: make_hull(&[
+                apex,
+                (left, top),
+                (right, top),
+                (right, bottom),
+                (left, bottom),
+            ]),
+            expires_at: now + POINTER_GRACE_MS,
+        }
+    }
+
+    pub fn contains(&self, point: (f64, f64), now: f64) -> bool {
+        now < self.expires_at && point_in_polygon(point, &self.polygon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pointer_grace_covers_path_to_submenu() {
+        // Trigger exited at its right edge; submenu to the right.
+        let grace = PointerGrace::new((100.0, 50.0), (40.0, 300.0, 240.0, 110.0), 0.0);
+        assert!(grace.contains((100.0, 50.0), 10.0));
+        assert!(grace.contains((105.0, 60.0), 10.0));
+        // Moving down over the next item, away from the submenu.
+        assert!(!grace.contains((95.0, 80.0), 10.0));
+        // Expired.
+        assert!(!grace.contains((105.0, 60.0), 400.0));
+    }
+}
@@ -1,4 +1,9 @@
 pub mod context;
 pub mod root;
 
-pub use root::{Content, Item, Label, Root, Separator, Trigger};
+pub use crate::components::hotkeys::MenuShortcut;
+pub use crate::items::{Collection, CollectionItem};
+pub use root::{
+    CheckboxItem, Content, Item, ItemIndicator, Items, Label, RadioGroup, RadioItem, Root,
+    Separator, Shortcut, Sub, SubContent, SubTrigger, Trigger,
+};
@@ -1,4 +1,5 @@
 use std::{
+    marker::PhantomData,
     sync::{
         Arc, Mutex,
         atomic::{AtomicUsize, Ordering},
@@ -8,7 +9,7 @@ use std::{
 
 use leptos::{
     context::Provider,
-    ev::{focus, keydown, mouseover},
+    ev::{click, focus, keydown, pointerleave, pointermove},
     leptos_dom::helpers::TimeoutHandle,
     prelude::*,
 };
@@ -20,12 +21,26 @@ use wasm_bindgen::JsCast;
 
 use crate::{
     cn,
+    components::hotkeys::menu::{MenuShortcut, ShortcutHint, use_menu_shortcuts},
     custom_animated_show::CustomAnimatedShow,
-    items::{FilterActiveItems, Focus, ManageFocus, NavigateItems},
-    utils::prevent_scroll::use_prevent_scroll,
+    items::{
+        Collection, CollectionItem, FilterActiveItems, Focus, ManageFocus, NavigateItems,
+        render_groups,
+    },
+    utils::{
+        clock::now_ms,
+        long_press::{
+            DEFAULT_LONG_PRESS_THRESHOLD, DEFAULT_LONG_PRESS_TOLERANCE, LongPress, use_long_press,
+        },
+        positioning::{AvoidCollisions, Positioning},
+        prevent_scroll::use_prevent_scroll,
+    },
 };
 
-use super::context::{ContextMenuContext, ContextMenuItemContext};
+use super::context::{
+    CheckableItemContext, ContextMenuContext, ContextMenuItemContext, MenuLevel, PointerGrace,
+    RadioGroupContext, SubMenuContext, SubTriggerContext,
+};
 
 static CONTEXT_MENU_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
@@ -41,6 +56,19 @@ pub fn Root(
     #[prop(default = true)] allow_loop: bool,
     #[prop(default = Duration::from_millis(200))] hide_delay: Duration,
     #[prop(optional)] on_open_change: Option<Callback<bool>>,
+    /// Data-driven items, rendered with [`Items`].
+    #[prop(into, optional)]
+    items: Option<Collection>,
+    /// How long a touch must rest on the trigger to open the menu.
+    #[prop(default = DEFAULT_LONG_PRESS_THRESHOLD)]
+    long_press_threshold: Duration,
+    /// How far in CSS pixels the touch may move before the long press is cancelled.
+    #[prop(default = DEFAULT_LONG_PRESS_TOLERANCE)]
+    long_press_tolerance: f64,
+    /// Shortcuts that run their action when pressed, also while the menu is
+    /// closed. Requires a `hotkeys::Provider`.
+    #[prop(optional)]
+    shortcuts: Vec<MenuShortcut>,
 ) -> impl IntoView {
     let ctx = ContextMenuContext {
         trigger_ref: NodeRef::new(),
@@ -50,13 +78,18 @@ pub fn Root(
         pointer_y: RwSignal::new(0.0),
         item_focus: RwSignal::new(None),
         items: RwSignal::new(Default::default()),
+        collection: items,
         allow_loop,
         hide_delay,
+        long_press: LongPress::new(long_press_threshold).with_move_tolerance(long_press_tolerance),
         menu_id: StoredValue::new(next_menu_id()),
         next_id: StoredValue::new(AtomicUsize::new(0)),
+        pointer_grace: StoredValue::new(None),
         on_open_change,
     };
 
+    use_menu_shortcuts(shortcuts);
+
     view! {
         <Provider value={ctx}>
             <RootEvents>
@@ -103,17 +136,38 @@ fn RootEvents(children: Children) -> impl IntoView {
 pub fn Trigger(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
     let ctx = expect_context::<ContextMenuContext>();
 
+    let long_press = ctx.long_press;
+
     let _ = use_event_listener(ctx.trigger_ref, leptos::ev::contextmenu, move |evt| {
         evt.prevent_default();
+        // Android also fires `contextmenu` on long press; the menu is already open.
+        if ctx.open.get_untracked() && long_press.is_recent_touch() {
+            return;
+        }
         let x = evt.client_x() as f64;
         let y = evt.client_y() as f64;
         ctx.open_at(x, y);
     });
 
+    // Touch: iOS Safari does not fire `contextmenu`, so open on long press.
+    use_long_press(ctx.trigger_ref, long_press, move || {
+        if let Some((x, y)) = long_press.origin().filter(|_| !ctx.open.get_untracked()) {
+            ctx.open_at(x, y);
+        }
+    });
+
     view! {
         <div
             node_ref={ctx.trigger_ref}
             data-state={move || if ctx.open.get() { "open" } else { "closed" }}
+            // Keep a long press from selecting text or showing the native
+            // callout, without blocking selection otherwise.
+            style={move || {
+                long_press
+                    .pressing
+                    .get()
+                    .then_some("-webkit-touch-callout: none; -webkit-user-select: none; user-select: none;")
+            }}
             class={class}
         >
             {children()}
@@ -215,54 +269,7 @@ pub fn Content(
     });
 
     let _ = use_event_listener(content_ref, keydown, move |evt| {
-        match evt.key().as_str() {
-            "ArrowDown" => {
-                evt.prevent_default();
-                if let Some(item) = ctx.navigate_next_item() {
-                    item.focus();
-                    ctx.set_focus(Some(item.index));
-                }
-            }
-            "ArrowUp" => {
-                evt.prevent_default();
-                if let Some(item) = ctx.navigate_previous_item() {
-                    item.focus();
-                    ctx.set_focus(Some(item.index));
-                }
-            }
-            "Home" => {
-                evt.prevent_default();
-                if let Some(item) = ctx.navigate_first_item() {
-                    item.focus();
-                    ctx.set_focus(Some(item.index));
-                }
-            }
-            "End" => {
-                evt.prevent_default();
-                if let Some(item) = ctx.navigate_last_item() {
-                    item.focus();
-                    ctx.set_focus(Some(item.index));
-                }
-            }
-            "Enter" | " " => {
-                evt.prevent_default();
-                if let Some(focused_idx) = ctx.item_focus.get_untracked() {
-                    let item = ctx.items.with_untracked(|m| m.get(&focused_idx).copied());
-                    if let Some(item) = item {
-                        if !item.disabled {
-                            if let Some(cb) = item.on_select {
-                                cb.run(());
-                            }
-                            ctx.close();
-                        }
-                    }
-                }
-            }
-            "Tab" => {
-                ctx.close();
-            }
-            _ => {}
-        }
+        handle_menu_keydown(ctx, MenuLevel::Root(ctx), &evt);
     });
 
     view! {
@@ -288,53 +295,451 @@ pub fn Item(
     #[prop(into, optional)] class: String,
     #[prop(default = false)] disabled: bool,
     #[prop(optional)] on_select: Option<Callback<()>>,
+    /// Keyboard shortcut such as `"Mod+C"`, shown after the children. Declare it
+    /// in the `shortcuts` of [`Root`] as well to make pressing it run an action.
+    #[prop(into, optional)]
+    shortcut: Option<String>,
+    #[prop(into, optional)] shortcut_class: String,
+) -> impl IntoView {
+    view! {
+        <MenuItem
+            role="menuitem"
+            class={class}
+            disabled={disabled}
+            close_on_select=true
+            on_select={on_select}
+            shortcut={shortcut}
+            shortcut_class={shortcut_class}
+        >
+            {children()}
+        </MenuItem>
+    }
+}
+
+/// A menu item that toggles a boolean. Renders with `role="menuitemcheckbox"`
+/// and `aria-checked`.
+///
+/// # Example
+/// ```rust,ignore
+/// let show_grid = RwSignal::new(false);
+/// view! {
+///     <context_menu::CheckboxItem checked={show_grid} close_on_select={false}>
+///         <context_menu::ItemIndicator>"✓"</context_menu::ItemIndicator>
+///         "Show grid"
+///     </context_menu::CheckboxItem>
+/// }
+/// ```
+#[component]
+pub fn CheckboxItem(
+    children: Children,
+    checked: RwSignal<bool>,
+    #[prop(into, optional)] class: String,
+    #[prop(default = false)] disabled: bool,
+    /// Close the menu after toggling.
+    #[prop(default = true)]
+    close_on_select: bool,
+    #[prop(optional)] on_checked_change: Option<Callback<bool>>,
+    /// Keyboard shortcut hint; see [`Item`].
+    #[prop(into, optional)]
+    shortcut: Option<String>,
+    #[prop(into, optional)] shortcut_class: String,
+) -> impl IntoView {
+    let on_select = Callback::new(move |_| {
+        checked.update(|c| *c = !*c);
+        if let Some(cb) = on_checked_change {
+            cb.run(checked.get_untracked());
+        }
+    });
+
+    view! {
+        <Provider value={CheckableItemContext { checked: checked.into() }}>
+            <MenuItem
+                role="menuitemcheckbox"
+                class={class}
+                disabled={disabled}
+                close_on_select={close_on_select}
+                on_select={Some(on_select)}
+                checked={Signal::from(checked)}
+                shortcut={shortcut}
+                shortcut_class={shortcut_class}
+            >
+                {children()}
+            </MenuItem>
+        </Provider>
+    }
+}
+
+/// Groups [`RadioItem`]s so that exactly one of them is checked.
+#[component]
+pub fn RadioGroup(
+    children: Children,
+    /// Value of the checked item.
+    value: RwSignal<String>,
+    #[prop(into, optional)] class: String,
+    #[prop(optional)] on_value_change: Option<Callback<String>>,
+) -> impl IntoView {
+    let ctx = RadioGroupContext {
+        value,
+        on_value_change,
+    };
+
+    view! {
+        <Provider value={ctx}>
+            <div role="group" class={class}>
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// A menu item inside a [`RadioGroup`]. Renders with `role="menuitemradio"`.
+#[component]
+pub fn RadioItem(
+    children: Children,
+    #[prop(into)] value: String,
+    #[prop(into, optional)] class: String,
+    #[prop(default = false)] disabled: bool,
+    /// Close the menu after checking the item.
+    #[prop(default = true)]
+    close_on_select: bool,
+    /// Keyboard shortcut hint; see [`Item`].
+    #[prop(into, optional)]
+    shortcut: Option<String>,
+    #[prop(into, optional)] shortcut_class: String,
+) -> impl IntoView {
+    let group = expect_context::<RadioGroupContext>();
+    let value = StoredValue::new(value);
+
+    let checked = Signal::derive(move || group.value.with(|v| value.with_value(|own| v == own)));
+    let on_select = Callback::new(move |_| {
+        let own = value.get_value();
+        if group.value.with_untracked(|v| *v != own) {
+            group.value.set(own.clone());
+            if let Some(cb) = group.on_value_change {
+                cb.run(own);
+            }
+        }
+    });
+
+    view! {
+        <Provider value={CheckableItemContext { checked }}>
+            <MenuItem
+                role="menuitemradio"
+                class={class}
+                disabled={disabled}
+                close_on_select={close_on_select}
+                on_select={Some(on_select)}
+                checked={checked}
+                shortcut={shortcut}
+                shortcut_class={shortcut_class}
+            >
+                {children()}
+            </MenuItem>
+        </Provider>
+    }
+}
+
+/// Renders its children only while the enclosing [`CheckboxItem`] or
+/// [`RadioItem`] is checked.
+#[component]
+pub fn ItemIndicator(
+    children: ChildrenFn,
+    #[prop(into, optional)] class: String,
+) -> impl IntoView {
+    let checkable = expect_context::<CheckableItemContext>();
+    let children = StoredValue::new(children);
+
+    view! {
+        <Show when={move || checkable.checked.get()}>
+            <span aria-hidden="true" class={class.clone()}>
+                {children.with_value(|children| children())}
+            </span>
+        </Show>
+    }
+}
+
+#[component]
+fn MenuItem(
+    children: Children,
+    role: &'static str,
+    class: String,
+    disabled: bool,
+    close_on_select: bool,
+    on_select: Option<Callback<()>>,
+    #[prop(optional)] checked: Option<Signal<bool>>,
+    #[prop(default = None)] shortcut: Option<String>,
+    #[prop(optional)] shortcut_class: String,
 ) -> impl IntoView {
     let ctx = expect_context::<ContextMenuContext>();
+    let level = MenuLevel::current();
 
-    let index = ctx.next_index();
+    let index = level.next_index();
     let item_ctx = ContextMenuItemContext {
         index,
         disabled,
         item_ref: NodeRef::new(),
+        close_on_select,
+        sub_menu: None,
         on_select,
     };
 
-    ctx.upsert_item(index, item_ctx);
+    level.upsert_item(index, item_ctx);
 
     on_cleanup(move || {
-        ctx.remove_item(index);
+        level.remove_item(index);
     });
 
-    let _ = use_event_listener(item_ctx.item_ref, leptos::ev::click, move |_| {
+    let _ = use_event_listener(item_ctx.item_ref, click, move |_| {
         if item_ctx.disabled {
             return;
         }
         if let Some(cb) = item_ctx.on_select {
             cb.run(());
         }
-        ctx.close();
+        if item_ctx.close_on_select {
+            ctx.close();
+        }
+    });
+
+    use_item_highlight(level, item_ctx);
+
+    let is_checked = move || checked.map(|c| c.get());
+
+    view! {
+        <div
+            node_ref={item_ctx.item_ref}
+            role={role}
+            tabindex="-1"
+            aria-checked={move || is_checked().map(|c| if c { "true" } else { "false" })}
+            aria-disabled={if disabled { Some("true") } else { None }}
+            data-state={move || is_checked().map(|c| if c { "checked" } else { "unchecked" })}
+            data-disabled={disabled}
+            data-highlighted={move || level.item_in_focus(item_ctx.index)}
+            class={class}
+        >
+            {children()}
+            <ShortcutHint shortcut={shortcut} class={shortcut_class} />
+        </div>
+    }
+}
+
+/// Highlights an item of `level` on hover and focus, opening its submenu if it
+/// has one and closing sibling submenus.
+fn use_item_highlight(level: MenuLevel, item: ContextMenuItemContext) {
+    let _ = use_event_listener(item.item_ref, pointermove, move |evt| {
+        if item.disabled {
+            return;
+        }
+        let point = (evt.client_x() as f64, evt.client_y() as f64);
+        if now_ms().is_some_and(|now| level.in_pointer_grace(point, now)) {
+            return;
+        }
+        level.set_pointer_grace(None);
+        level.close_subs_except(item.index);
+        if let Some(sub) = item.sub_menu {
+            sub.open(false);
+        }
+        if level.item_focus().get_untracked() != Some(item.index) {
+            // Move DOM focus too, so keyboard navigation continues from the
+            // panel under the pointer rather than from an open submenu.
+            item.focus();
+        }
+    });
+
+    let _ = use_event_listener(item.item_ref, focus, move |_| {
+        if !item.disabled {
+            level.set_focus(Some(item.index));
+        }
+    });
+}
+
+/// Keyboard handling shared by `Content` and `SubContent`.
+fn handle_menu_keydown(ctx: ContextMenuContext, level: MenuLevel, evt: &web_sys::KeyboardEvent) {
+    let focus_item = |item: Option<ContextMenuItemContext>| {
+        if let Some(item) = item {
+            item.focus();
+            level.set_focus(Some(item.index));
+        }
+    };
+    match evt.key().as_str() {
+        "ArrowDown" => {
+            evt.prevent_default();
+            focus_item(level.navigate_next_item());
+        }
+        "ArrowUp" => {
+            evt.prevent_default();
+            focus_item(level.navigate_previous_item());
+        }
+        "Home" => {
+            evt.prevent_default();
+            focus_item(level.navigate_first_item());
+        }
+        "End" => {
+            evt.prevent_default();
+            focus_item(level.navigate_last_item());
+        }
+        "ArrowRight" => {
+            evt.prevent_default();
+            if let Some(sub) = level.focused_item().and_then(|item| item.sub_menu) {
+                if !sub.disabled {
+                    sub.open(true);
+                }
+            }
+        }
+        "ArrowLeft" => {
+            evt.prevent_default();
+            if let MenuLevel::Sub(sub) = level {
+                sub.close();
+                if let Some(trigger) = sub.trigger_ref.get_untracked() {
+                    let _ = trigger.focus();
+                }
+            }
+        }
+        "Enter" | " " => {
+            evt.prevent_default();
+            let Some(item) = level.focused_item().filter(|item| !item.disabled) else {
+                return;
+            };
+            if let Some(sub) = item.sub_menu {
+                sub.open(true);
+                return;
+            }
+            if let Some(cb) = item.on_select {
+                cb.run(());
+            }
+            if item.close_on_select {
+                ctx.close();
+            }
+        }
+        "Escape" => {
+            evt.prevent_default();
+            ctx.close();
+        }
+        "Tab" => {
+            ctx.close();
+        }
+        _ => {}
+    }
+}
+
+/// A nested menu. Contains a [`SubTrigger`] and a [`SubContent`], and may be
+/// nested inside another `SubContent`.
+///
+/// # Example
+/// ```rust,ignore
+/// <context_menu::Sub>
+///     <context_menu::SubTrigger>"Share"</context_menu::SubTrigger>
+///     <context_menu::SubContent class="w-48">
+///         <context_menu::Item>"Email link"</context_menu::Item>
+///         <context_menu::Item>"Copy link"</context_menu::Item>
+///     </context_menu::SubContent>
+/// </context_menu::Sub>
+/// ```
+#[component]
+pub fn Sub(
+    children: Children,
+    #[prop(default = false)] disabled: bool,
+    #[prop(default = Positioning::RightStart)] positioning: Positioning,
+    #[prop(default = AvoidCollisions::Flip)] avoid_collisions: AvoidCollisions,
+    /// The timeout after which the submenu is unmounted once closed.
+    #[prop(default = Duration::from_millis(200))]
+    hide_delay: Duration,
+) -> impl IntoView {
+    let ctx = expect_context::<ContextMenuContext>();
+    let parent = MenuLevel::current();
+
+    let index = parent.next_index();
+    let sub = SubMenuContext {
+        index,
+        disabled,
+        open: RwSignal::new(false),
+        trigger_ref: NodeRef::new(),
+        content_ref: NodeRef::new(),
+        item_focus: RwSignal::new(None),
+        items: RwSignal::new(Default::default()),
+        allow_loop: ctx.allow_loop,
+        positioning,
+        avoid_collisions,
+        hide_delay,
+        next_id: StoredValue::new(AtomicUsize::new(0)),
+        pointer_grace: StoredValue::new(None),
+        focus_first: StoredValue::new(false),
+    };
+
+    parent.upsert_item(
+        index,
+        ContextMenuItemContext {
+            index,
+            disabled,
+            item_ref: sub.trigger_ref,
+            close_on_select: false,
+            sub_menu: Some(sub),
+            on_select: None,
+        },
+    );
+
+    on_cleanup(move || {
+        parent.remove_item(index);
     });
 
-    let _ = use_event_listener(item_ctx.item_ref, mouseover, move |_| {
-        if !item_ctx.disabled {
-            ctx.set_focus(Some(item_ctx.index));
+    view! {
+        <Provider value={SubTriggerContext { parent }}>
+            <Provider value={sub}>{children()}</Provider>
+        </Provider>
+    }
+}
+
+/// The item that opens a [`Sub`] menu on hover, click, Enter, Space or
+/// ArrowRight.
+#[component]
+pub fn SubTrigger(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
+    let sub = expect_context::<SubMenuContext>();
+    let SubTriggerContext { parent } = expect_context::<SubTriggerContext>();
+    let item = parent
+        .items()
+        .with_untracked(|m| m.get(&sub.index).copied())
+        .expect("context_menu::SubTrigger must be inside context_menu::Sub");
+
+    use_item_highlight(parent, item);
+
+    let _ = use_event_listener(sub.trigger_ref, click, move |_| {
+        if !sub.disabled {
+            sub.open(false);
         }
     });
 
-    let _ = use_event_listener(item_ctx.item_ref, focus, move |_| {
-        if !item_ctx.disabled {
-            ctx.set_focus(Some(item_ctx.index));
+    // Hover intent: let the pointer cross sibling items on its way to the
+    // submenu without closing it.
+    let _ = use_event_listener(sub.trigger_ref, pointerleave, move |evt| {
+        if !sub.open.get_untracked() {
+            return;
         }
+        let Some(content) = sub.content_ref.get_untracked() else {
+            return;
+        };
+        let Some(now) = now_ms() else {
+            return;
+        };
+        let rect = content.get_bounding_client_rect();
+        let exit = (evt.client_x() as f64, evt.client_y() as f64);
+        parent.set_pointer_grace(Some(PointerGrace::new(
+            exit,
+            (rect.top(), rect.right(), rect.bottom(), rect.left()),
+            now,
+        )));
     });
 
     view! {
         <div
-            node_ref={item_ctx.item_ref}
+            node_ref={sub.trigger_ref}
             role="menuitem"
             tabindex="-1"
-            aria-disabled={if disabled { Some("true") } else { None }}
-            data-disabled={disabled}
-            data-highlighted={move || ctx.item_in_focus(item_ctx.index)}
+            aria-haspopup="menu"
+            aria-expanded={move || if sub.open.get() { "true" } else { "false" }}
+            aria-disabled={if sub.disabled { Some("true") } else { None }}
+            data-state={move || if sub.open.get() { "open" } else { "closed" }}
+            data-disabled={sub.disabled}
+            data-highlighted={move || parent.item_in_focus(sub.index)}
             class={class}
         >
             {children()}
@@ -342,6 +747,189 @@ pub fn Item(
     }
 }
 
+/// The panel of a [`Sub`] menu, positioned next to its [`SubTrigger`].
+#[component]
+pub fn SubContent(
+    children: ChildrenFn,
+    #[prop(into, optional)] class: String,
+    #[prop(into, optional)] show_class: String,
+    #[prop(into, optional)] hide_class: String,
+) -> impl IntoView {
+    let ctx = expect_context::<ContextMenuContext>();
+    let sub = expect_context::<SubMenuContext>();
+    let content_ref = sub.content_ref;
+
+    let UseElementBoundingReturn {
+        width: content_width,
+        height: content_height,
+        ..
+    } = use_element_bounding(content_ref);
+
+    let UseElementBoundingReturn {
+        top: trigger_top,
+        left: trigger_left,
+        width: trigger_width,
+        height: trigger_height,
+        ..
+    } = use_element_bounding(sub.trigger_ref);
+
+    let style_signal = Signal::derive(move || {
+        let raw_cw = *content_width.read();
+        let raw_ch = *content_height.read();
+        let _ = sub.open.get();
+        let _ = trigger_top.read();
+        let _ = trigger_left.read();
+        let _ = trigger_width.read();
+        let _ = trigger_height.read();
+        let hidden = || {
+            format!(
+                "position: fixed; top: 0; left: 0; visibility: hidden; --biji-transform-origin: {};",
+                sub.positioning.transform_origin()
+            )
+        };
+        if raw_cw == 0.0 && raw_ch == 0.0 {
+            return hidden();
+        }
+        let Some(content_div) = content_ref.get_untracked() else {
+            return hidden();
+        };
+        let cw = content_div.offset_width() as f64;
+        let ch = content_div.offset_height() as f64;
+        if cw == 0.0 && ch == 0.0 {
+            return hidden();
+        }
+        let Some(trigger) = sub.trigger_ref.get_untracked() else {
+            return hidden();
+        };
+        let rect = trigger.get_bounding_client_rect();
+        let (t_top, t_left, t_width, t_height) =
+            (rect.top(), rect.left(), rect.width(), rect.height());
+        let vp_w = web_sys::window()
+            .and_then(|w| w.inner_width().ok())
+            .and_then(|v| v.as_f64())
+            .unwrap_or(1920.0);
+        let vp_h = web_sys::window()
+            .and_then(|w| w.inner_height().ok())
+            .and_then(|v| v.as_f64())
+            .unwrap_or(1080.0);
+        let eff = sub.positioning.effective_positioning(
+            cw,
+            ch,
+            t_top,
+            t_left,
+            t_width,
+            t_height,
+            0.0,
+            vp_w,
+            vp_h,
+            sub.avoid_collisions,
+        );
+        eff.calculate_position_style_simple(t_top, t_left, t_width, t_height, ch, cw, 0.0)
+    });
+
+    // Focus the first item when the submenu was opened from the keyboard.
+    let focus_handle: StoredValue<Option<TimeoutHandle>> = StoredValue::new(None);
+    let focus_eff = RenderEffect::new(move |_| {
+        if let Some(h) = focus_handle.try_update_value(|h| h.take()).flatten() {
+            h.clear();
+        }
+        if sub.open.get() && sub.focus_first.get_value() {
+            sub.focus_first.set_value(false);
+            let h = leptos::leptos_dom::helpers::set_timeout_with_handle(
+                move || {
+                    focus_handle.set_value(None);
+                    let level = MenuLevel::Sub(sub);
+                    if let Some(item) = level.navigate_first_item() {
+                        item.focus();
+                        level.set_focus(Some(item.index));
+                    }
+                },
+                Duration::from_millis(10),
+            )
+            .ok();
+            focus_handle.set_value(h);
+        }
+    });
+
+    on_cleanup(move || {
+        if let Some(h) = focus_handle.try_update_value(|h| h.take()).flatten() {
+            h.clear();
+        }
+        drop(focus_eff);
+    });
+
+    let _ = use_event_listener(content_ref, keydown, move |evt| {
+        handle_menu_keydown(ctx, MenuLevel::Sub(sub), &evt);
+        // The submenu is rendered inside its parent panel; keep the parent
+        // from handling the same key.
+        evt.stop_propagation();
+    });
+
+    view! {
+        <CustomAnimatedShow
+            when={sub.open}
+            show_class={cn!(class, show_class)}
+            hide_class={cn!(class, hide_class)}
+            hide_delay={sub.hide_delay}
+            style_signal={style_signal}
+            node_ref={content_ref}
+            attr:role="menu"
+            attr:tabindex="-1"
+        >
+            {children()}
+        </CustomAnimatedShow>
+    }
+}
+
+/// Renders the `items` passed to [`Root`], one [`Item`] per entry.
+///
+/// Entries that share a `group` are wrapped in a `role="group"` element headed by
+/// the group name.
+#[component]
+pub fn Items<T, F, IV>(
+    /// Returns the content of each item.
+    render: F,
+    /// Called with the value of the selected item.
+    #[prop(optional)]
+    on_select: Option<Callback<String>>,
+    #[prop(into, optional)] item_class: String,
+    #[prop(into, optional)] group_class: String,
+    #[prop(into, optional)] group_label_class: String,
+    /// Ties `T` to the props; never set.
+    #[prop(optional)]
+    _item: PhantomData<T>,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(CollectionItem<T>) -> IV + Clone + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let ctx = expect_context::<ContextMenuContext>();
+    let items = ctx
+        .collection
+        .expect("context_menu::Items requires the `items` prop on context_menu::Root")
+        .typed::<T>();
+
+    move || {
+        Some(items?.with(|items| {
+            render_groups(items, &group_class, &group_label_class, |item| {
+                let render = render.clone();
+                let value = item.value.clone();
+                let select = Callback::new(move |_| {
+                    if let Some(cb) = on_select {
+                        cb.run(value.clone());
+                    }
+                });
+                view! {
+                    <Item disabled={item.disabled} class={item_class.clone()} on_select={select}>
+                        {render(item)}
+                    </Item>
+                }
+            })
+        }))
+    }
+}
+
 #[component]
 pub fn Separator(#[prop(into, optional)] class: String) -> impl IntoView {
     view! {
@@ -349,6 +937,24 @@ pub fn Separator(#[prop(into, optional)] class: String) -> impl IntoView {
     }
 }
 
+/// A right-aligned keyboard shortcut hint inside an item. Purely visual.
+///
+/// # Example
+/// ```rust,ignore
+/// <context_menu::Item class="flex">
+///     "Copy"
+///     <context_menu::Shortcut class="ml-auto text-xs opacity-60">"⌘C"</context_menu::Shortcut>
+/// </context_menu::Item>
+/// ```
+#[component]
+pub fn Shortcut(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
+    view! {
+        <span aria-hidden="true" class={class}>
+            {children()}
+        </span>
+    }
+}
+
 #[component]
 pub fn Label(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
     view! {
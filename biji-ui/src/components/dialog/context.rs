@@ -5,6 +5,8 @@ use leptos::{
     prelude::*,
 };
 
+use crate::utils::modal_stack::ModalLayer;
+
 #[derive(Copy, Clone)]
 pub struct DialogContext {
     pub trigger_ref: NodeRef<Button>,
@@ -12,6 +14,22 @@ pub struct DialogContext {
     pub root: RwSignal<RootContext>,
     pub prevent_scroll: bool,
     pub hide_delay: Duration,
+    pub on_open_change: Option<Callback<bool>>,
+    /// Consulted before a user-initiated close; returning `false` keeps the dialog open.
+    pub before_close: Option<Callback<CloseReason, bool>>,
+    /// Position in the stack of open modals.
+    pub layer: ModalLayer,
+}
+
+/// What asked the dialog to close, passed to `before_close`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CloseReason {
+    Escape,
+    Overlay,
+    /// The `Close` part was clicked.
+    Close,
+    /// The `Trigger` was clicked while the dialog was open.
+    Trigger,
 }
 
 impl Default for DialogContext {
@@ -22,23 +40,50 @@ impl Default for DialogContext {
             root: RwSignal::new(RootContext::default()),
             prevent_scroll: true,
             hide_delay: Duration::from_millis(200),
+            on_open_change: None,
+            before_close: None,
+            layer: ModalLayer::new(),
         }
     }
 }
 
 impl DialogContext {
     pub fn open(&self) {
+        if self.open.get_untracked() {
+            return;
+        }
         self.root.set(RootContext::default());
         self.open.set(true);
+        if let Some(cb) = self.on_open_change {
+            cb.run(true);
+        }
     }
 
+    /// Close unconditionally, without consulting `before_close`.
     pub fn close(&self) {
+        if !self.open.get_untracked() {
+            return;
+        }
         self.open.set(false);
+        if let Some(cb) = self.on_open_change {
+            cb.run(false);
+        }
+    }
+
+    /// Close unless `before_close` vetoes it. Returns `true` if the dialog closed.
+    pub fn request_close(&self, reason: CloseReason) -> bool {
+        if let Some(cb) = self.before_close {
+            if !cb.run(reason) {
+                return false;
+            }
+        }
+        self.close();
+        true
     }
 
     pub fn toggle(&self) {
         if self.open.get() {
-            self.close();
+            self.request_close(CloseReason::Trigger);
         } else {
             self.open();
         }
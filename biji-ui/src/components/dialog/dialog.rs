@@ -1,12 +1,14 @@
 use leptos::{ev::click, prelude::*};
 use leptos_use::use_event_listener;
-use wasm_bindgen::JsCast;
 
 use crate::{
-    cn, components::dialog::context::DialogContext, custom_animated_show::CustomAnimatedShow,
+    cn,
+    components::dialog::context::DialogContext,
+    custom_animated_show::CustomAnimatedShow,
+    utils::focus_trap::{focus_first_element, trap_tab_focus},
 };
 
-use super::context::RootContext;
+use super::context::{CloseReason, RootContext};
 
 #[component]
 pub fn Trigger(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
@@ -70,17 +72,18 @@ pub fn Content(
         }
     });
 
-    // Handle Tab / Shift+Tab focus trapping and Escape to close
+    // Handle Tab / Shift+Tab focus trapping and Escape to close. Only the top
+    // modal layer reacts, so keys from a nested dialog do not reach this one.
+    // Focus is returned by the modal layer once the dialog closes.
     let _ = use_event_listener(content_ref, leptos::ev::keydown, move |evt| {
+        if !dialog_ctx.layer.is_top() {
+            return;
+        }
         let key = evt.key();
         match key.as_str() {
             "Escape" => {
                 evt.prevent_default();
-                dialog_ctx.close();
-                // Return focus to the trigger
-                if let Some(trigger) = dialog_ctx.trigger_ref.get() {
-                    let _ = trigger.focus();
-                }
+                dialog_ctx.request_close(CloseReason::Escape);
             }
             "Tab" => {
                 if let Some(el) = content_ref.get() {
@@ -104,100 +107,14 @@ pub fn Content(
             node_ref={content_ref}
             attr:role="dialog"
             attr:aria-modal="true"
+            attr:data-nested={move || dialog_ctx.layer.is_nested().then_some("")}
+            attr:data-nested-count={move || dialog_ctx.layer.nested_count()}
         >
             {children()}
         </CustomAnimatedShow>
     }
 }
 
-/// Query all focusable elements inside the given container.
-fn get_focusable_elements(container: &web_sys::HtmlElement) -> Vec<web_sys::HtmlElement> {
-    let selector = r#"a[href], button:not([disabled]), textarea:not([disabled]), input:not([disabled]), select:not([disabled]), [tabindex]:not([tabindex="-1"])"#;
-    let Ok(node_list) = container.query_selector_all(selector) else {
-        return Vec::new();
-    };
-
-    let mut elements = Vec::new();
-    for i in 0..node_list.length() {
-        if let Some(node) = node_list.get(i) {
-            if let Ok(el) = node.dyn_into::<web_sys::HtmlElement>() {
-                elements.push(el);
-            }
-        }
-    }
-    elements
-}
-
-/// Focus the first focusable element inside the container, or the container itself.
-fn focus_first_element(container: &web_sys::HtmlElement) {
-    let focusable = get_focusable_elements(container);
-    if let Some(first) = focusable.first() {
-        let _ = first.focus();
-    } else {
-        // If no focusable element found, focus the container itself
-        let _ = container.focus();
-    }
-}
-
-/// Trap Tab / Shift+Tab focus within the container.
-fn trap_tab_focus(container: &web_sys::HtmlElement, shift_key: bool, evt: &web_sys::KeyboardEvent) {
-    let focusable = get_focusable_elements(container);
-    if focusable.is_empty() {
-        evt.prevent_default();
-        return;
-    }
-
-    let active = document().active_element();
-
-    if shift_key {
-        // Shift+Tab: if focus is on the first element, wrap to the last
-        let is_first = active
-            .as_ref()
-            .and_then(|a| a.dyn_ref::<web_sys::HtmlElement>())
-            .map(|a| {
-                focusable
-                    .first()
-                    .map(|f| {
-                        let a_node: &web_sys::Node = a.as_ref();
-                        let f_node: &web_sys::Node = f.as_ref();
-                        a_node.is_same_node(Some(f_node))
-                    })
-                    .unwrap_or(false)
-            })
-            .unwrap_or(true);
-
-        if is_first {
-            evt.prevent_default();
-            if let Some(last) = focusable.last() {
-                let _ = last.focus();
-            }
-        }
-    } else {
-        // Tab: if focus is on the last element, wrap to the first
-        let is_last = active
-            .as_ref()
-            .and_then(|a| a.dyn_ref::<web_sys::HtmlElement>())
-            .map(|a| {
-                focusable
-                    .last()
-                    .map(|f| {
-                        let a_node: &web_sys::Node = a.as_ref();
-                        let f_node: &web_sys::Node = f.as_ref();
-                        a_node.is_same_node(Some(f_node))
-                    })
-                    .unwrap_or(false)
-            })
-            .unwrap_or(true);
-
-        if is_last {
-            evt.prevent_default();
-            if let Some(first) = focusable.first() {
-                let _ = first.focus();
-            }
-        }
-    }
-}
-
 #[component]
 pub fn Overlay(
     #[prop(into, optional)] class: String,
@@ -233,7 +150,7 @@ pub fn OverlayEvents(children: Children) -> impl IntoView {
     let root_ctx = expect_context::<RootContext>();
 
     let _ = use_event_listener(root_ctx.overlay_ref, click, move |_| {
-        dialog_ctx.close();
+        dialog_ctx.request_close(CloseReason::Overlay);
     });
 
     children()
@@ -260,7 +177,7 @@ pub fn CloseEvents(children: Children) -> impl IntoView {
     let root_ctx = expect_context::<RootContext>();
 
     let _ = use_event_listener(root_ctx.close_ref, click, move |_| {
-        dialog_ctx.close();
+        dialog_ctx.request_close(CloseReason::Close);
     });
 
     children()
@@ -2,6 +2,7 @@ pub mod context;
 pub mod dialog;
 pub mod root;
 
+pub use context::CloseReason;
 pub use dialog::Close;
 pub use dialog::Content;
 pub use dialog::Overlay;
@@ -2,8 +2,8 @@ use std::time::Duration;
 
 use leptos::{context::Provider, prelude::*};
 
-use crate::components::dialog::context::{DialogContext, RootContext};
-use crate::utils::prevent_scroll::use_prevent_scroll;
+use crate::components::dialog::context::{CloseReason, DialogContext, RootContext};
+use crate::utils::{modal_stack::use_modal_layer, prevent_scroll::use_prevent_scroll};
 
 #[component]
 pub fn Root(
@@ -15,14 +15,29 @@ pub fn Root(
     /// The timeout after which the component will be unmounted if `when == false`
     #[prop(default = Duration::from_millis(200))]
     hide_delay: Duration,
+    /// Controls the open state. Set it to open or close the dialog from outside;
+    /// the dialog writes to it when opened or closed by the user.
+    #[prop(optional)]
+    open: Option<RwSignal<bool>>,
+    /// Called with the new state when the dialog is opened or closed through
+    /// its parts.
+    #[prop(optional)]
+    on_open_change: Option<Callback<bool>>,
+    /// Called before the dialog closes via Escape, the overlay, `Close` or
+    /// `Trigger`. Return `false` to keep it open, e.g. while a form is dirty.
+    #[prop(optional)]
+    before_close: Option<Callback<CloseReason, bool>>,
 ) -> impl IntoView {
     let root_ctx = RootContext {
         ..RootContext::default()
     };
     let ctx = DialogContext {
+        open: open.unwrap_or_else(|| RwSignal::new(false)),
         root: RwSignal::new(root_ctx),
         prevent_scroll,
         hide_delay,
+        on_open_change,
+        before_close,
         ..Default::default()
     };
 
@@ -46,8 +61,15 @@ pub fn RootEvents(children: Children) -> impl IntoView {
         dialog_ctx.hide_delay,
     );
 
+    let layer_eff = use_modal_layer(
+        dialog_ctx.layer,
+        move || dialog_ctx.open.get(),
+        move || dialog_ctx.trigger_ref.get_untracked().map(Into::into),
+    );
+
     on_cleanup(move || {
         drop(eff);
+        drop(layer_eff);
     });
 
     children()
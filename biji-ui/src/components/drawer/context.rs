@@ -8,6 +8,10 @@ use leptos::{
     prelude::*,
 };
 
+use crate::utils::modal_stack::ModalLayer;
+
+use super::drag::SnapPoint;
+
 static DRAWER_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
 pub fn next_drawer_id() -> String {
@@ -49,6 +53,28 @@ pub struct DrawerContext {
     pub title_id: StoredValue<String>,
     pub description_id: StoredValue<String>,
     pub on_open_change: Option<Callback<bool>>,
+    /// Position in the stack of open modals.
+    pub layer: ModalLayer,
+    /// Whether the panel can be dragged along its side's axis.
+    pub draggable: bool,
+    /// Whether dragging the panel towards its edge can close the drawer.
+    pub swipe_to_dismiss: bool,
+    /// Positions the open panel rests at, smallest first. Empty when the panel
+    /// simply rests fully open.
+    pub snap_points: StoredValue<Vec<SnapPoint>>,
+    /// Index into `snap_points` of the position the open panel rests at.
+    pub active_snap_point: RwSignal<usize>,
+    /// Offset of the panel towards its edge while it is being dragged.
+    pub drag_offset: RwSignal<Option<f64>>,
+    /// Overlay opacity while the panel is being dragged.
+    pub overlay_opacity: RwSignal<Option<f64>>,
+    /// The drawer this one is nested in, if any.
+    pub parent: Option<StoredValue<DrawerContext>>,
+    /// Number of nested drawers currently open on top of this one.
+    pub nested_open: RwSignal<usize>,
+    /// How far the directly nested drawer is from closed while it is being
+    /// dragged: `1.0` at rest, `0.0` fully closed.
+    pub nested_progress: RwSignal<Option<f64>>,
 }
 
 impl DrawerContext {
@@ -77,4 +103,58 @@ impl DrawerContext {
     pub fn data_state(&self) -> &'static str {
         if self.open.get() { "open" } else { "closed" }
     }
+
+    /// Records a nested drawer opening (`true`) or closing on top of this
+    /// drawer and each of its ancestors.
+    pub(crate) fn nest(&self, open: bool) {
+        self.nested_open.try_update(|n| {
+            *n = if open { *n + 1 } else { n.saturating_sub(1) };
+        });
+        if let Some(parent) = self.parent.and_then(|p| p.try_get_value()) {
+            parent.nest(open);
+        }
+    }
+
+    /// How far this drawer has receded behind nested drawers, in levels.
+    /// Fractional while the nested drawer on top is being dragged.
+    pub(crate) fn nested_level(&self) -> f64 {
+        match self.nested_open.get() {
+            0 => 0.0,
+            n => (n - 1) as f64 + self.nested_progress.get().unwrap_or(1.0),
+        }
+    }
+}
+
+/// Scale applied per nested drawer level, exposed as
+/// `--biji-drawer-nested-scale`.
+pub(crate) const NESTED_SCALE_STEP: f64 = 0.05;
+
+/// Offset in CSS pixels per nested drawer level, exposed as
+/// `--biji-drawer-nested-offset`.
+pub(crate) const NESTED_OFFSET_STEP: f64 = 16.0;
+
+/// CSS variables that let a drawer recede behind `level` nested drawers.
+pub(crate) fn nested_style(level: f64) -> String {
+    format!(
+        "--biji-drawer-nested-scale: {}; --biji-drawer-nested-offset: {}px;",
+        1.0 - NESTED_SCALE_STEP * level,
+        NESTED_OFFSET_STEP * level,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_style_per_level() {
+        assert_eq!(
+            nested_style(0.0),
+            "--biji-drawer-nested-scale: 1; --biji-drawer-nested-offset: 0px;"
+        );
+        assert_eq!(
+            nested_style(2.0),
+            "--biji-drawer-nested-scale: 0.9; --biji-drawer-nested-offset: 32px;"
+        );
+    }
 }
@@ -0,0 +1,173 @@
+//! Dragging the drawer panel along its side's axis.
+//!
+//! Positions are expressed as an offset: how far the panel is translated
+//! towards the edge it slides in from. `0.0` is fully open and the panel's own
+//! size is fully closed.
+
+use super::context::DrawerSide;
+
+/// Release speed in CSS pixels per millisecond above which a drag moves on to
+/// the next snap point, or dismisses the drawer, regardless of distance.
+pub(crate) const VELOCITY_THRESHOLD: f64 = 0.4;
+
+/// Distance in CSS pixels the pointer must travel before a press becomes a drag.
+pub(crate) const DRAG_THRESHOLD: f64 = 4.0;
+
+/// A position the open panel rests at, measured as how much of it is visible.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SnapPoint {
+    /// Fraction of the viewport height (top and bottom drawers) or width
+    /// (left and right drawers), e.g. `0.5` for half the screen.
+    Fraction(f64),
+    /// Visible size in CSS pixels.
+    Pixels(f64),
+}
+
+impl SnapPoint {
+    /// Offset of a panel of size `panel` resting at this snap point.
+    pub fn offset(&self, panel: f64, viewport: f64) -> f64 {
+        let visible = match *self {
+            SnapPoint::Fraction(fraction) => fraction * viewport,
+            SnapPoint::Pixels(pixels) => pixels,
+        };
+        (panel - visible).clamp(0.0, panel.max(0.0))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum Release {
+    Close,
+    Snap(usize),
+}
+
+/// Where a drag released at `offset` with `velocity` (positive towards the
+/// edge) settles. `snaps` holds the offset of each snap point; without snap
+/// points pass `&[0.0]`. `closed` is the offset of a fully closed panel.
+pub(crate) fn release(
+    offset: f64,
+    velocity: f64,
+    snaps: &[f64],
+    closed: f64,
+    dismissible: bool,
+) -> Release {
+    if velocity > VELOCITY_THRESHOLD {
+        // Flung towards the edge: the next snap point below, or close.
+        let below = snaps
+            .iter()
+            .enumerate()
+            .filter(|&(_, &s)| s > offset)
+            .min_by(|a, b| a.1.total_cmp(b.1));
+        return match below {
+            Some((i, _)) => Release::Snap(i),
+            None if dismissible => Release::Close,
+            None => Release::Snap(nearest(snaps, offset)),
+        };
+    }
+    if velocity < -VELOCITY_THRESHOLD {
+        // Flung away from the edge: the next snap point above.
+        let above = snaps
+            .iter()
+            .enumerate()
+            .filter(|&(_, &s)| s < offset)
+            .max_by(|a, b| a.1.total_cmp(b.1));
+        if let Some((i, _)) = above {
+            return Release::Snap(i);
+        }
+    }
+
+    let nearest = nearest(snaps, offset);
+    let nearest_offset = snaps.get(nearest).copied().unwrap_or(0.0);
+    if dismissible && (closed - offset).abs() < (nearest_offset - offset).abs() {
+        Release::Close
+    } else {
+        Release::Snap(nearest)
+    }
+}
+
+fn nearest(snaps: &[f64], offset: f64) -> usize {
+    snaps
+        .iter()
+        .enumerate()
+        .min_by(|a, b| (a.1 - offset).abs().total_cmp(&(b.1 - offset).abs()))
+        .map_or(0, |(i, _)| i)
+}
+
+/// Overlay opacity for a panel at `offset`: opaque down to the lowest resting
+/// position `rest`, then fading out towards `closed`.
+pub(crate) fn overlay_opacity(offset: f64, rest: f64, closed: f64) -> f64 {
+    if offset <= rest || closed <= rest {
+        return 1.0;
+    }
+    (1.0 - (offset - rest) / (closed - rest)).clamp(0.0, 1.0)
+}
+
+impl DrawerSide {
+    /// Pointer position along the drag axis, increasing towards the edge the
+    /// drawer slides in from.
+    pub(crate) fn drag_position(self, x: f64, y: f64) -> f64 {
+        match self {
+            DrawerSide::Top => -y,
+            DrawerSide::Right => x,
+            DrawerSide::Bottom => y,
+            DrawerSide::Left => -x,
+        }
+    }
+
+    /// Pointer position across the drag axis.
+    pub(crate) fn cross_position(self, x: f64, y: f64) -> f64 {
+        if self.is_vertical() { x } else { y }
+    }
+
+    /// `true` for drawers that slide along the vertical axis.
+    pub(crate) fn is_vertical(self) -> bool {
+        matches!(self, DrawerSide::Top | DrawerSide::Bottom)
+    }
+
+    /// CSS `transform` that moves the panel `offset` pixels towards its edge.
+    pub(crate) fn transform(self, offset: f64) -> String {
+        match self {
+            DrawerSide::Top => format!("translateY({}px)", -offset),
+            DrawerSide::Right => format!("translateX({offset}px)"),
+            DrawerSide::Bottom => format!("translateY({offset}px)"),
+            DrawerSide::Left => format!("translateX({}px)", -offset),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_point_offset() {
+        assert_eq!(SnapPoint::Fraction(0.5).offset(800.0, 1000.0), 300.0);
+        assert_eq!(SnapPoint::Pixels(200.0).offset(800.0, 1000.0), 600.0);
+        assert_eq!(SnapPoint::Fraction(1.0).offset(800.0, 1000.0), 0.0);
+    }
+
+    #[test]
+    fn release_without_snap_points() {
+        assert_eq!(release(100.0, 0.0, &[0.0], 400.0, true), Release::Snap(0));
+        assert_eq!(release(300.0, 0.0, &[0.0], 400.0, true), Release::Close);
+        assert_eq!(release(30.0, 1.0, &[0.0], 400.0, true), Release::Close);
+        assert_eq!(release(300.0, 1.0, &[0.0], 400.0, false), Release::Snap(0));
+    }
+
+    #[test]
+    fn release_with_snap_points() {
+        // Smallest first: 600px, 300px and 0px from the edge.
+        let snaps = [600.0, 300.0, 0.0];
+        assert_eq!(release(280.0, 0.0, &snaps, 800.0, true), Release::Snap(1));
+        assert_eq!(release(320.0, 1.0, &snaps, 800.0, true), Release::Snap(0));
+        assert_eq!(release(280.0, -1.0, &snaps, 800.0, true), Release::Snap(2));
+        assert_eq!(release(620.0, 1.0, &snaps, 800.0, true), Release::Close);
+        assert_eq!(release(-10.0, -1.0, &snaps, 800.0, true), Release::Snap(2));
+    }
+
+    #[test]
+    fn overlay_fades_below_rest() {
+        assert_eq!(overlay_opacity(100.0, 300.0, 800.0), 1.0);
+        assert_eq!(overlay_opacity(550.0, 300.0, 800.0), 0.5);
+        assert_eq!(overlay_opacity(900.0, 300.0, 800.0), 0.0);
+    }
+}
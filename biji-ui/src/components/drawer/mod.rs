@@ -1,5 +1,7 @@
 pub mod context;
+pub mod drag;
 pub mod root;
 
 pub use context::DrawerSide;
+pub use drag::SnapPoint;
 pub use root::{Close, Content, Description, Overlay, Root, Title, Trigger};
@@ -1,20 +1,37 @@
-use std::{sync::{Arc, Mutex}, time::Duration};
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
 
 use leptos::{
     context::Provider,
-    ev::{click, keydown},
+    ev::{click, keydown, pointercancel, pointerdown, pointermove, pointerup, touchmove},
     prelude::*,
 };
-use leptos_use::{on_click_outside, use_document, use_event_listener};
+use leptos_use::{
+    UseElementBoundingReturn, UseEventListenerOptions, on_click_outside, use_document,
+    use_element_bounding, use_event_listener, use_event_listener_with_options,
+};
 use wasm_bindgen::JsCast;
 
 use crate::{
     cn,
     custom_animated_show::CustomAnimatedShow,
-    utils::prevent_scroll::use_prevent_scroll,
+    utils::{
+        clock::now_ms,
+        focus_trap::{focus_first_element, trap_tab_focus},
+        modal_stack::{ModalLayer, use_modal_layer},
+        prevent_scroll::use_prevent_scroll,
+    },
 };
 
-use super::context::{DrawerContext, DrawerSide, next_drawer_id};
+use super::{
+    context::{DrawerContext, DrawerSide, nested_style, next_drawer_id},
+    drag::{DRAG_THRESHOLD, Release, SnapPoint, overlay_opacity, release},
+};
 
 #[component]
 pub fn Root(
@@ -27,8 +44,25 @@ pub fn Root(
     hide_delay: Duration,
     #[prop(default = false)] open: bool,
     #[prop(optional)] on_open_change: Option<Callback<bool>>,
+    /// Lets the user drag the panel along its side's axis. Off by default, as a
+    /// mouse drag would otherwise take over text selection in the panel.
+    #[prop(default = false)]
+    draggable: bool,
+    /// With `draggable`, closes the drawer when the panel is dragged far
+    /// enough, or flung, towards its edge.
+    #[prop(default = true)]
+    swipe_to_dismiss: bool,
+    /// Positions the open panel rests at and can be dragged between, smallest
+    /// first. The panel itself should be sized for the largest one.
+    #[prop(optional)]
+    snap_points: Vec<SnapPoint>,
+    /// Index into `snap_points` of the current resting position. Pass a signal
+    /// to control it or to react to the user's drags.
+    #[prop(optional)]
+    active_snap_point: Option<RwSignal<usize>>,
 ) -> impl IntoView {
     let base_id = next_drawer_id();
+    let parent = use_context::<DrawerContext>().map(StoredValue::new);
     let ctx = DrawerContext {
         trigger_ref: NodeRef::new(),
         overlay_ref: NodeRef::new(),
@@ -41,6 +75,16 @@ pub fn Root(
         description_id: StoredValue::new(format!("{base_id}-description")),
         drawer_id: StoredValue::new(base_id),
         on_open_change,
+        layer: ModalLayer::new(),
+        draggable,
+        swipe_to_dismiss,
+        snap_points: StoredValue::new(snap_points),
+        active_snap_point: active_snap_point.unwrap_or_else(|| RwSignal::new(0)),
+        drag_offset: RwSignal::new(None),
+        overlay_opacity: RwSignal::new(None),
+        parent,
+        nested_open: RwSignal::new(0),
+        nested_progress: RwSignal::new(None),
     };
 
     view! {
@@ -56,15 +100,62 @@ pub fn Root(
 fn RootEvents(children: Children) -> impl IntoView {
     let ctx = expect_context::<DrawerContext>();
 
+    // An open parent drawer already holds the scroll lock.
+    let parent_locks = move || {
+        ctx.parent
+            .and_then(|p| p.try_get_value())
+            .is_some_and(|p| p.prevent_scroll && p.open.get())
+    };
     let eff = use_prevent_scroll(
-        move || ctx.prevent_scroll && ctx.open.get(),
+        move || ctx.prevent_scroll && ctx.open.get() && !parent_locks(),
         ctx.hide_delay,
     );
-    on_cleanup(move || drop(eff));
+    let layer_eff = use_modal_layer(
+        ctx.layer,
+        move || ctx.open.get(),
+        move || ctx.trigger_ref.get_untracked().map(Into::into),
+    );
+    on_cleanup(move || {
+        drop(eff);
+        drop(layer_eff);
+    });
 
-    // Escape: close and return focus to the trigger.
+    // Register with the parent drawer while open, so it can recede behind this
+    // one and follow it while it is dragged.
+    if let Some(parent) = ctx.parent {
+        // Shared with `on_cleanup`, which may run after stored values are disposed.
+        let registered = Arc::new(AtomicBool::new(false));
+        let registered_cleanup = Arc::clone(&registered);
+        let nest_eff = RenderEffect::new(move |_| {
+            let open = ctx.open.get();
+            if registered.swap(open, Ordering::Relaxed) != open {
+                if let Some(parent) = parent.try_get_value() {
+                    parent.nest(open);
+                }
+            }
+        });
+        let progress_eff = RenderEffect::new(move |_| {
+            let opacity = ctx.overlay_opacity.get();
+            if let Some(parent) = parent.try_get_value() {
+                parent.nested_progress.set(opacity);
+            }
+        });
+        on_cleanup(move || {
+            drop(nest_eff);
+            drop(progress_eff);
+            if let Some(parent) = parent.try_get_value() {
+                if registered_cleanup.swap(false, Ordering::Relaxed) {
+                    parent.nest(false);
+                }
+                parent.nested_progress.try_set(None);
+            }
+        });
+    }
+
+    // Escape: close and return focus to the trigger. Ignored while a nested
+    // modal is open on top of this drawer.
     let _ = use_event_listener(use_document(), keydown, move |evt| {
-        if evt.key() == "Escape" && ctx.open.get() {
+        if evt.key() == "Escape" && ctx.open.get() && ctx.layer.is_top() {
             ctx.close();
             if let Some(trigger) = ctx.trigger_ref.get() {
                 let _ = trigger.focus();
@@ -75,7 +166,7 @@ fn RootEvents(children: Children) -> impl IntoView {
     // Click outside the panel: close (also handles overlay clicks when no Overlay
     // component is used).
     let _ = on_click_outside(ctx.content_ref, move |evt| {
-        if !ctx.open.get() {
+        if !ctx.open.get() || !ctx.layer.is_top() {
             return;
         }
         let is_trigger_click = evt
@@ -118,7 +209,7 @@ pub fn Trigger(children: Children, #[prop(into, optional)] class: String) -> imp
 
 /// A semi-transparent backdrop rendered behind the drawer panel.  Clicking it
 /// closes the drawer.  Use inside a `<Portal>` if you want it to cover the full
-/// viewport.
+/// viewport.  Fades out while the panel is dragged towards its edge.
 #[component]
 pub fn Overlay(
     #[prop(into, optional)] class: String,
@@ -133,6 +224,12 @@ pub fn Overlay(
             show_class={cn!(class, show_class)}
             hide_class={cn!(class, hide_class)}
             hide_delay={ctx.hide_delay}
+            style_signal={Signal::derive(move || {
+                ctx.overlay_opacity
+                    .get()
+                    .map(|opacity| format!("opacity: {opacity}; transition: none;"))
+                    .unwrap_or_default()
+            })}
             node_ref={ctx.overlay_ref}
         >
             <div on:click={move |_| {
@@ -147,6 +244,16 @@ pub fn Overlay(
 
 /// The sliding panel.  Position it with CSS classes; use `show_class` /
 /// `hide_class` for the slide-in animation.  Use inside a `<Portal>`.
+///
+/// With `draggable` on `Root`, the panel follows the pointer along its side's
+/// axis. A drag starting inside a scrollable element only begins once that
+/// element is scrolled to its edge, on touch screens too; inputs and elements
+/// marked `data-drawer-no-drag` never start one.
+///
+/// While a drawer nested inside this one is open, the panel gets
+/// `data-nested-open` and the `--biji-drawer-nested-scale` /
+/// `--biji-drawer-nested-offset` variables to recede with, e.g.
+/// `data-[nested-open]:scale-(--biji-drawer-nested-scale)`.
 #[component]
 pub fn Content(
     children: ChildrenFn,
@@ -189,19 +296,31 @@ pub fn Content(
 
     // Tab / Shift+Tab focus trap.
     let _ = use_event_listener(content_ref, keydown, move |evt| {
-        if evt.key() == "Tab" {
+        if evt.key() == "Tab" && ctx.layer.is_top() {
             if let Some(el) = content_ref.get() {
                 trap_tab_focus(&el, evt.shift_key(), &evt);
             }
         }
     });
 
+    let drag_style = use_drag(ctx);
+    let style_signal = Signal::derive(move || {
+        let mut style = drag_style.get();
+        if ctx.nested_open.get() > 0 {
+            style.push(' ');
+            style.push_str(&nested_style(ctx.nested_level()));
+        }
+        style
+    });
+    let has_snap_points = ctx.snap_points.with_value(|s| !s.is_empty());
+
     view! {
         <CustomAnimatedShow
             when={ctx.open}
             show_class={cn!(class, show_class)}
             hide_class={cn!(class, hide_class)}
             hide_delay={ctx.hide_delay}
+            style_signal={style_signal}
             node_ref={content_ref}
             attr:id={ctx.drawer_id.get_value()}
             attr:role="dialog"
@@ -210,6 +329,11 @@ pub fn Content(
             attr:aria-describedby={ctx.description_id.get_value()}
             attr:data-state={move || ctx.data_state()}
             attr:data-side={ctx.side.as_str()}
+            attr:data-nested={move || ctx.layer.is_nested().then_some("")}
+            attr:data-nested-count={move || ctx.layer.nested_count()}
+            attr:data-nested-open={move || (ctx.nested_open.get() > 0).then_some("")}
+            attr:data-dragging={move || ctx.drag_offset.get().is_some().then_some("")}
+            attr:data-snap-point={move || has_snap_points.then(|| ctx.active_snap_point.get())}
             attr:tabindex="-1"
         >
             {children()}
@@ -217,6 +341,318 @@ pub fn Content(
     }
 }
 
+/// Elements a drag may not start from.
+const NO_DRAG_SELECTOR: &str = "input, textarea, select, [contenteditable], [data-drawer-no-drag]";
+
+/// A pointer press on the panel that may turn into a drag.
+struct DragState {
+    pointer_id: i32,
+    /// Pointer position along and across the drag axis at the press.
+    origin: (f64, f64),
+    /// Panel offset at the press.
+    start_offset: f64,
+    /// `true` once the press has moved far enough along the axis to drag.
+    active: bool,
+    target: Option<web_sys::Element>,
+    /// `(time, offset)` of the two latest moves, for the release velocity.
+    samples: [(f64, f64); 2],
+}
+
+/// Drags the panel with the pointer along the side's axis and settles it on
+/// release: on the nearest snap point, the next one when flung, or closed.
+/// Returns the panel's style.
+fn use_drag(ctx: DrawerContext) -> Signal<String> {
+    let content_ref = ctx.content_ref;
+    let drag = StoredValue::new_local(None::<DragState>);
+    let suppress_click = StoredValue::new(false);
+
+    // Size of the panel and of the viewport along the drag axis.
+    let measure = move || -> Option<(f64, f64)> {
+        let el = content_ref.get_untracked()?;
+        let window = web_sys::window()?;
+        Some(if ctx.side.is_vertical() {
+            (
+                el.offset_height() as f64,
+                window.inner_height().ok()?.as_f64()?,
+            )
+        } else {
+            (
+                el.offset_width() as f64,
+                window.inner_width().ok()?.as_f64()?,
+            )
+        })
+    };
+    let snap_offsets = move |panel: f64, viewport: f64| -> Vec<f64> {
+        let offsets = ctx.snap_points.with_value(|points| {
+            points
+                .iter()
+                .map(|p| p.offset(panel, viewport))
+                .collect::<Vec<_>>()
+        });
+        if offsets.is_empty() {
+            vec![0.0]
+        } else {
+            offsets
+        }
+    };
+    let resting_offset = move |panel: f64, viewport: f64| -> f64 {
+        let offsets = snap_offsets(panel, viewport);
+        let index = ctx.active_snap_point.get().min(offsets.len() - 1);
+        offsets[index]
+    };
+
+    let _ = use_event_listener(content_ref, pointerdown, move |evt| {
+        suppress_click.set_value(false);
+        if !ctx.draggable || evt.button() != 0 || !ctx.layer.is_top() {
+            return;
+        }
+        let target = evt
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok());
+        if target
+            .as_ref()
+            .is_some_and(|t| t.closest(NO_DRAG_SELECTOR).ok().flatten().is_some())
+        {
+            return;
+        }
+        let Some((panel, viewport)) = measure() else {
+            return;
+        };
+        let (x, y) = (evt.client_x() as f64, evt.client_y() as f64);
+        let start_offset = untrack(|| resting_offset(panel, viewport));
+        let now = now_ms().unwrap_or_default();
+        drag.set_value(Some(DragState {
+            pointer_id: evt.pointer_id(),
+            origin: (ctx.side.drag_position(x, y), ctx.side.cross_position(x, y)),
+            start_offset,
+            active: false,
+            target,
+            samples: [(now, start_offset); 2],
+        }));
+    });
+
+    let _ = use_event_listener(content_ref, pointermove, move |evt| {
+        let Some(mut state) = drag.try_update_value(|d| d.take()).flatten() else {
+            return;
+        };
+        if evt.pointer_id() != state.pointer_id {
+            drag.set_value(Some(state));
+            return;
+        }
+        let Some(panel_el) = content_ref.get_untracked() else {
+            return;
+        };
+        let (x, y) = (evt.client_x() as f64, evt.client_y() as f64);
+        let delta = ctx.side.drag_position(x, y) - state.origin.0;
+        if !state.active {
+            let cross = (ctx.side.cross_position(x, y) - state.origin.1).abs();
+            if delta.abs() < DRAG_THRESHOLD && cross < DRAG_THRESHOLD {
+                drag.set_value(Some(state));
+                return;
+            }
+            // Mostly sideways, or an inner scroller takes the gesture: not a drag.
+            if cross > delta.abs()
+                || scrolls_first(state.target.as_ref(), &panel_el, ctx.side, delta)
+            {
+                return;
+            }
+            state.active = true;
+            let _ = panel_el.set_pointer_capture(state.pointer_id);
+        }
+        evt.prevent_default();
+        let offset = (state.start_offset + delta).max(0.0);
+        state.samples = [state.samples[1], (now_ms().unwrap_or_default(), offset)];
+        ctx.drag_offset.set(Some(offset));
+        if let Some((panel, viewport)) = measure() {
+            let rest = snap_offsets(panel, viewport)
+                .into_iter()
+                .fold(0.0, f64::max);
+            ctx.overlay_opacity
+                .set(Some(overlay_opacity(offset, rest, panel)));
+        }
+        drag.set_value(Some(state));
+    });
+
+    let end_drag = move |evt: web_sys::PointerEvent, cancelled: bool| {
+        let Some(state) = drag.try_update_value(|d| d.take()).flatten() else {
+            return;
+        };
+        if !state.active {
+            return;
+        }
+        if let Some(el) = content_ref.get_untracked() {
+            let _ = el.release_pointer_capture(evt.pointer_id());
+        }
+        let offset = ctx
+            .drag_offset
+            .get_untracked()
+            .unwrap_or(state.start_offset);
+        ctx.drag_offset.set(None);
+        ctx.overlay_opacity.set(None);
+        if cancelled {
+            return;
+        }
+        suppress_click.set_value(true);
+
+        let [(t0, o0), (t1, o1)] = state.samples;
+        let stale = now_ms().is_some_and(|now| now - t1 > 100.0);
+        let velocity = if t1 > t0 && !stale {
+            (o1 - o0) / (t1 - t0)
+        } else {
+            0.0
+        };
+        let Some((panel, viewport)) = measure() else {
+            return;
+        };
+        let snaps = snap_offsets(panel, viewport);
+        match release(offset, velocity, &snaps, panel, ctx.swipe_to_dismiss) {
+            Release::Close => {
+                ctx.close();
+                if let Some(trigger) = ctx.trigger_ref.get_untracked() {
+                    let _ = trigger.focus();
+                }
+            }
+            Release::Snap(index) => {
+                if ctx.snap_points.with_value(|s| !s.is_empty()) {
+                    ctx.active_snap_point.set(index);
+                }
+            }
+        }
+    };
+    // Inner scrollers allow native panning, and the browser cancels the
+    // pointer once it starts scrolling one. Cancelling `touchmove` while a
+    // drag is active keeps the gesture with the panel; this needs a
+    // non-passive listener.
+    let _ = use_event_listener_with_options(
+        content_ref,
+        touchmove,
+        move |evt| {
+            if drag.with_value(|d| d.as_ref().is_some_and(|state| state.active)) {
+                evt.prevent_default();
+            }
+        },
+        UseEventListenerOptions::default().passive(false),
+    );
+
+    let _ = use_event_listener(content_ref, pointerup, move |evt| end_drag(evt, false));
+    let _ = use_event_listener(content_ref, pointercancel, move |evt| end_drag(evt, true));
+
+    // The click that ends a drag must not activate the element under the pointer.
+    let _ = use_event_listener_with_options(
+        content_ref,
+        click,
+        move |evt| {
+            if suppress_click.get_value() {
+                suppress_click.set_value(false);
+                evt.prevent_default();
+                evt.stop_propagation();
+            }
+        },
+        UseEventListenerOptions::default().capture(true),
+    );
+
+    // Rest at the active snap point only once the panel has mounted, so that
+    // the slide-in animation starts from the closed position.
+    let settled = RwSignal::new(false);
+    Effect::new(move |_| {
+        if ctx.open.get() {
+            set_timeout(
+                move || {
+                    if ctx.open.get_untracked() {
+                        let _ = settled.try_set(true);
+                    }
+                },
+                Duration::from_millis(20),
+            );
+        } else {
+            settled.set(false);
+        }
+    });
+
+    let UseElementBoundingReturn { width, height, .. } = use_element_bounding(content_ref);
+
+    Signal::derive(move || {
+        let _ = width.read();
+        let _ = height.read();
+        let mut style = String::new();
+        if ctx.draggable {
+            style.push_str(if ctx.side.is_vertical() {
+                "touch-action: pan-x;"
+            } else {
+                "touch-action: pan-y;"
+            });
+        }
+        let offset = match ctx.drag_offset.get() {
+            Some(offset) => {
+                style.push_str(" transition: none;");
+                offset
+            }
+            None if ctx.open.get() && settled.get() => measure()
+                .map(|(panel, viewport)| resting_offset(panel, viewport))
+                .unwrap_or_default(),
+            None => 0.0,
+        };
+        // At rest and fully open, leave `transform` to the show/hide classes.
+        if offset > 0.0 || ctx.drag_offset.get().is_some() {
+            style.push_str(&format!(" transform: {};", ctx.side.transform(offset)));
+        }
+        style
+    })
+}
+
+/// `true` when an element between `target` and the panel can still scroll in
+/// the direction the pointer moves, so the gesture scrolls it instead of
+/// dragging the panel.
+fn scrolls_first(
+    target: Option<&web_sys::Element>,
+    panel: &web_sys::Element,
+    side: DrawerSide,
+    delta: f64,
+) -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    let vertical = side.is_vertical();
+    // Moving the pointer down or right scrolls content back towards its start.
+    let towards_start = match side {
+        DrawerSide::Bottom | DrawerSide::Right => delta > 0.0,
+        DrawerSide::Top | DrawerSide::Left => delta < 0.0,
+    };
+    let mut current = target.cloned();
+    while let Some(el) = current {
+        let overflow = window
+            .get_computed_style(&el)
+            .ok()
+            .flatten()
+            .and_then(|style| {
+                style
+                    .get_property_value(if vertical { "overflow-y" } else { "overflow-x" })
+                    .ok()
+            })
+            .unwrap_or_default();
+        if matches!(overflow.as_str(), "auto" | "scroll" | "overlay") {
+            let (position, client, size) = if vertical {
+                (el.scroll_top(), el.client_height(), el.scroll_height())
+            } else {
+                (el.scroll_left(), el.client_width(), el.scroll_width())
+            };
+            let can_scroll = if towards_start {
+                position > 0
+            } else {
+                position + client < size - 1
+            };
+            if can_scroll {
+                return true;
+            }
+        }
+        if el.is_same_node(Some(panel.as_ref())) {
+            break;
+        }
+        current = el.parent_element();
+    }
+    false
+}
+
 /// A button that closes the drawer when clicked.
 #[component]
 pub fn Close(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
@@ -249,80 +685,3 @@ pub fn Description(children: Children, #[prop(into, optional)] class: String) ->
     let ctx = expect_context::<DrawerContext>();
     view! { <p id={ctx.description_id.get_value()} class={class}>{children()}</p> }
 }
-
-// ── Focus helpers (mirrored from dialog) ─────────────────────────────────────
-
-fn get_focusable_elements(container: &web_sys::HtmlElement) -> Vec<web_sys::HtmlElement> {
-    let selector = r#"a[href], button:not([disabled]), textarea:not([disabled]), input:not([disabled]), select:not([disabled]), [tabindex]:not([tabindex="-1"])"#;
-    let Ok(node_list) = container.query_selector_all(selector) else {
-        return Vec::new();
-    };
-    let mut elements = Vec::new();
-    for i in 0..node_list.length() {
-        if let Some(node) = node_list.get(i) {
-            if let Ok(el) = node.dyn_into::<web_sys::HtmlElement>() {
-                elements.push(el);
-            }
-        }
-    }
-    elements
-}
-
-fn focus_first_element(container: &web_sys::HtmlElement) {
-    let focusable = get_focusable_elements(container);
-    if let Some(first) = focusable.first() {
-        let _ = first.focus();
-    } else {
-        let _ = container.focus();
-    }
-}
-
-fn trap_tab_focus(
-    container: &web_sys::HtmlElement,
-    shift_key: bool,
-    evt: &web_sys::KeyboardEvent,
-) {
-    let focusable = get_focusable_elements(container);
-    if focusable.is_empty() {
-        evt.prevent_default();
-        return;
-    }
-    let active = document().active_element();
-    if shift_key {
-        let is_first = active
-            .as_ref()
-            .and_then(|a| a.dyn_ref::<web_sys::HtmlElement>())
-            .map(|a| {
-                focusable.first().map(|f| {
-                    let a_node: &web_sys::Node = a.as_ref();
-                    let f_node: &web_sys::Node = f.as_ref();
-                    a_node.is_same_node(Some(f_node))
-                }).unwrap_or(false)
-            })
-            .unwrap_or(true);
-        if is_first {
-            evt.prevent_default();
-            if let Some(last) = focusable.last() {
-                let _ = last.focus();
-            }
-        }
-    } else {
-        let is_last = active
-            .as_ref()
-            .and_then(|a| a.dyn_ref::<web_sys::HtmlElement>())
-            .map(|a| {
-                focusable.last().map(|f| {
-                    let a_node: &web_sys::Node = a.as_ref();
-                    let f_node: &web_sys::Node = f.as_ref();
-                    a_node.is_same_node(Some(f_node))
-                }).unwrap_or(false)
-            })
-            .unwrap_or(true);
-        if is_last {
-            evt.prevent_default();
-            if let Some(first) = focusable.first() {
-                let _ = first.focus();
-            }
-        }
-    }
-}
@@ -0,0 +1,437 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use leptos::{html::Div, logging, prelude::*};
+use wasm_bindgen::JsCast;
+
+use crate::utils::{
+    clock::now_ms,
+    shortcut::{Shortcut, is_apple_platform},
+};
+
+static HOTKEY_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn next_hotkey_id() -> usize {
+    HOTKEY_ID_COUNTER.fetch_add(1, Ordering::Relaxed)
+}
+
+/// A chord such as `"Mod+Shift+P"`, or a sequence of chords pressed one after
+/// another such as `"g then i"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hotkey {
+    pub steps: Vec<Shortcut>,
+}
+
+impl Hotkey {
+    /// Parse chords separated by the word `then`. Each chord uses the
+    /// [`Shortcut`] syntax. Returns `None` if any chord is invalid.
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut steps = Vec::new();
+        let mut chord = String::new();
+        for token in input.split_whitespace() {
+            if token.eq_ignore_ascii_case("then") {
+                steps.push(Shortcut::parse(&chord)?);
+                chord.clear();
+            } else {
+                chord.push_str(token);
+            }
+        }
+        steps.push(Shortcut::parse(&chord)?);
+        Some(Self { steps })
+    }
+
+    /// Human-readable hint, e.g. `⇧⌘P` or `G I`.
+    pub fn label(&self, apple: bool) -> String {
+        self.steps
+            .iter()
+            .map(|s| s.label(apple))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// `true` when pressing one hotkey always passes through (or completes)
+    /// the other, so that both cannot be told apart.
+    pub fn conflicts_with(&self, other: &Hotkey) -> bool {
+        self.steps.iter().zip(&other.steps).all(|(a, b)| a == b)
+    }
+
+    fn match_presses(&self, presses: &[KeyPress], apple: bool) -> SequenceMatch {
+        if presses.len() > self.steps.len()
+            || !self
+                .steps
+                .iter()
+                .zip(presses)
+                .all(|(step, press)| press.matches(step, apple))
+        {
+            SequenceMatch::None
+        } else if presses.len() == self.steps.len() {
+            SequenceMatch::Exact
+        } else {
+            SequenceMatch::Partial
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SequenceMatch {
+    None,
+    Partial,
+    Exact,
+}
+
+/// Where a hotkey is active.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HotkeyScope {
+    /// Anywhere on the page.
+    Global,
+    /// Only while focus is inside the `hotkeys::Region` with this id.
+    Region(usize),
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct HotkeyOptions {
+    /// Also fire while focus is in a text input, textarea, select or
+    /// contenteditable element.
+    pub allow_in_inputs: bool,
+    /// Call `preventDefault` on the final key press.
+    pub prevent_default: bool,
+    /// Register globally even when called inside a `hotkeys::Region`.
+    pub global: bool,
+    /// Temporarily disable the hotkey.
+    pub enabled: Option<Signal<bool>>,
+}
+
+impl Default for HotkeyOptions {
+    fn default() -> Self {
+        Self {
+            allow_in_inputs: false,
+            prevent_default: true,
+            global: false,
+            enabled: None,
+        }
+    }
+}
+
+/// Two hotkeys registered in the same scope that cannot be told apart, e.g.
+/// `"g"` and `"g then i"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HotkeyConflict {
+    pub hotkey: String,
+    pub existing: String,
+    pub scope: HotkeyScope,
+}
+
+#[derive(Clone)]
+pub(crate) struct Registration {
+    id: usize,
+    keys: String,
+    hotkey: Hotkey,
+    scope: HotkeyScope,
+    options: HotkeyOptions,
+    handler: Callback<()>,
+}
+
+/// A key press, reduced to what [`Shortcut`] matching needs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct KeyPress {
+    key: String,
+    code: String,
+    ctrl: bool,
+    meta: bool,
+    alt: bool,
+    shift: bool,
+}
+
+impl KeyPress {
+    fn from_event(evt: &web_sys::KeyboardEvent) -> Self {
+        Self {
+            key: evt.key(),
+            code: evt.code(),
+            ctrl: evt.ctrl_key(),
+            meta: evt.meta_key(),
+            alt: evt.alt_key(),
+            shift: evt.shift_key(),
+        }
+    }
+
+    fn matches(&self, shortcut: &Shortcut, apple: bool) -> bool {
+        shortcut.matches_keys(
+            &self.key, &self.code, self.ctrl, self.meta, self.alt, self.shift, apple,
+        )
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct HotkeysContext {
+    /// How long to wait for the next chord of a sequence.
+    pub sequence_timeout: Duration,
+    pub(crate) registrations: StoredValue<Vec<Registration>>,
+    pub(crate) regions: StoredValue<HashMap<usize, NodeRef<Div>>>,
+    pub(crate) pending: StoredValue<Vec<KeyPress>>,
+    pub(crate) last_press: StoredValue<Option<f64>>,
+    pub(crate) on_conflict: Option<Callback<HotkeyConflict>>,
+}
+
+impl HotkeysContext {
+    /// Register `keys` in `scope`. Returns the registration id, or `None` when
+    /// `keys` cannot be parsed. Conflicts with existing hotkeys are reported to
+    /// the provider's `on_conflict`, or logged.
+    pub fn register(
+        &self,
+        keys: &str,
+        scope: HotkeyScope,
+        options: HotkeyOptions,
+        handler: Callback<()>,
+    ) -> Option<usize> {
+        let Some(hotkey) = Hotkey::parse(keys) else {
+            logging::warn!("hotkeys: cannot parse {keys:?}");
+            return None;
+        };
+        let conflicts = self.registrations.with_value(|regs| {
+            regs.iter()
+                .filter(|r| r.scope == scope && r.hotkey.conflicts_with(&hotkey))
+                .map(|r| HotkeyConflict {
+                    hotkey: keys.to_string(),
+                    existing: r.keys.clone(),
+                    scope,
+                })
+                .collect::<Vec<_>>()
+        });
+        for conflict in conflicts {
+            match self.on_conflict {
+                Some(cb) => cb.run(conflict),
+                None => logging::warn!(
+                    "hotkeys: {:?} conflicts with {:?}",
+                    conflict.hotkey,
+                    conflict.existing
+                ),
+            }
+        }
+        let id = next_hotkey_id();
+        self.registrations.update_value(|regs| {
+            regs.push(Registration {
+                id,
+                keys: keys.to_string(),
+                hotkey,
+                scope,
+                options,
+                handler,
+            })
+        });
+        Some(id)
+    }
+
+    pub fn unregister(&self, id: usize) {
+        let _ = self
+            .registrations
+            .try_update_value(|regs| regs.retain(|r| r.id != id));
+    }
+
+    /// Human-readable hints of every registered hotkey, e.g. for a help dialog.
+    pub fn registered(&self) -> Vec<(String, HotkeyScope)> {
+        let apple = is_apple_platform();
+        self.registrations.with_value(|regs| {
+            regs.iter()
+                .map(|r| (r.hotkey.label(apple), r.scope))
+                .collect()
+        })
+    }
+
+    pub(crate) fn handle_keydown(&self, evt: &web_sys::KeyboardEvent) {
+        if evt.default_prevented()
+            || evt.is_composing()
+            || matches!(evt.key().as_str(), "Shift" | "Control" | "Alt" | "Meta")
+        {
+            return;
+        }
+        let target = evt
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok());
+        let in_input = target.as_ref().is_some_and(is_text_input);
+        let active_regions = self.regions.with_value(|regions| {
+            regions
+                .iter()
+                .filter(|(_, region)| {
+                    region.get_untracked().is_some_and(|el| {
+                        target
+                            .as_ref()
+                            .is_some_and(|t| el.contains(Some(t.unchecked_ref())))
+                    })
+                })
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>()
+        });
+        let candidates = self.registrations.with_value(|regs| {
+            regs.iter()
+                .filter(|r| r.options.enabled.is_none_or(|e| e.get_untracked()))
+                .filter(|r| !in_input || r.options.allow_in_inputs)
+                .filter(|r| match r.scope {
+                    HotkeyScope::Global => true,
+                    HotkeyScope::Region(id) => active_regions.contains(&id),
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+
+        let now = now_ms().unwrap_or_default();
+        let timed_out = self
+            .last_press
+            .get_value()
+            .is_some_and(|last| now - last > self.sequence_timeout.as_millis() as f64);
+        self.last_press.set_value(Some(now));
+        let mut pending = self.pending.get_value();
+        if timed_out {
+            pending.clear();
+        }
+        pending.push(KeyPress::from_event(evt));
+
+        let hit = resolve(&candidates, &mut pending, is_apple_platform());
+        if hit.is_some() {
+            pending.clear();
+        }
+        self.pending.set_value(pending);
+
+        if let Some(hit) = hit {
+            if hit.options.prevent_default {
+                evt.prevent_default();
+            }
+            hit.handler.run(());
+        }
+    }
+}
+
+/// Drop presses from the front of `pending` until it is the start of some
+/// candidate, then return the registration it completes, if any. Region
+/// hotkeys win over global ones, and later registrations over earlier ones.
+fn resolve(
+    candidates: &[Registration],
+    pending: &mut Vec<KeyPress>,
+    apple: bool,
+) -> Option<Registration> {
+    while !pending.is_empty() {
+        let mut partial = false;
+        let mut exact: Option<&Registration> = None;
+        for reg in candidates {
+            match reg.hotkey.match_presses(pending, apple) {
+                SequenceMatch::Exact => {
+                    let better = exact.is_none_or(|e| {
+                        matches!(reg.scope, HotkeyScope::Region(_))
+                            || matches!(e.scope, HotkeyScope::Global)
+                    });
+                    if better {
+                        exact = Some(reg);
+                    }
+                }
+                SequenceMatch::Partial => partial = true,
+                SequenceMatch::None => {}
+            }
+        }
+        if let Some(exact) = exact {
+            return Some(exact.clone());
+        }
+        if partial {
+            return None;
+        }
+        pending.remove(0);
+    }
+    None
+}
+
+fn is_text_input(el: &web_sys::Element) -> bool {
+    match el.tag_name().to_ascii_lowercase().as_str() {
+        "textarea" | "select" => true,
+        "input" => !matches!(
+            el.get_attribute("type")
+                .unwrap_or_default()
+                .to_ascii_lowercase()
+                .as_str(),
+            "checkbox" | "radio" | "button" | "submit" | "reset" | "range" | "color" | "file"
+        ),
+        _ => el
+            .dyn_ref::<web_sys::HtmlElement>()
+            .is_some_and(|el| el.is_content_editable()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(key: &str, ctrl: bool) -> KeyPress {
+        KeyPress {
+            key: key.to_string(),
+            code: String::new(),
+            ctrl,
+            meta: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    fn registration(keys: &str, scope: HotkeyScope) -> Registration {
+        Registration {
+            id: next_hotkey_id(),
+            keys: keys.to_string(),
+            hotkey: Hotkey::parse(keys).unwrap(),
+            scope,
+            options: HotkeyOptions::default(),
+            handler: Callback::new(|_| {}),
+        }
+    }
+
+    #[test]
+    fn parse_sequences() {
+        let hotkey = Hotkey::parse("g then i").unwrap();
+        assert_eq!(hotkey.steps.len(), 2);
+        assert_eq!(hotkey.label(false), "G I");
+        assert_eq!(Hotkey::parse("Mod+Shift+P").unwrap().steps.len(), 1);
+        assert_eq!(Hotkey::parse("g then"), None);
+        assert_eq!(Hotkey::parse(""), None);
+    }
+
+    #[test]
+    fn prefixes_conflict() {
+        let g = Hotkey::parse("g").unwrap();
+        let gi = Hotkey::parse("g then i").unwrap();
+        let gp = Hotkey::parse("g then p").unwrap();
+        assert!(g.conflicts_with(&gi));
+        assert!(gi.conflicts_with(&g));
+        assert!(!gi.conflicts_with(&gp));
+    }
+
+    #[test]
+    fn resolves_sequences() {
+        let _owner = Owner::new();
+        let candidates = vec![
+            registration("g then i", HotkeyScope::Global),
+            registration("Ctrl+K", HotkeyScope::Global),
+        ];
+        let mut pending = vec![press("g", false)];
+        assert!(resolve(&candidates, &mut pending, false).is_none());
+        assert_eq!(pending.len(), 1);
+
+        pending.push(press("i", false));
+        let hit = resolve(&candidates, &mut pending, false).unwrap();
+        assert_eq!(hit.keys, "g then i");
+
+        // A stray key is dropped so a following chord still matches.
+        let mut pending = vec![press("x", false), press("k", true)];
+        let hit = resolve(&candidates, &mut pending, false).unwrap();
+        assert_eq!(hit.keys, "Ctrl+K");
+    }
+
+    #[test]
+    fn region_wins_over_global() {
+        let _owner = Owner::new();
+        let candidates = vec![
+            registration("Ctrl+K", HotkeyScope::Region(1)),
+            registration("Ctrl+K", HotkeyScope::Global),
+        ];
+        let mut pending = vec![press("k", true)];
+        let hit = resolve(&candidates, &mut pending, false).unwrap();
+        assert_eq!(hit.scope, HotkeyScope::Region(1));
+    }
+}
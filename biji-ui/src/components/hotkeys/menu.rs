@@ -0,0 +1,96 @@
+//! Keyboard shortcuts of menu items.
+//!
+//! Menu content is only mounted while the menu is open, so the shortcuts that
+//! should fire while it is closed are declared on the menu root through its
+//! `shortcuts` prop. The items' own `shortcut` prop renders the hint.
+
+use std::sync::Arc;
+
+use leptos::prelude::*;
+
+use crate::utils::shortcut::is_apple_platform;
+
+use super::{
+    context::{Hotkey, HotkeyOptions, HotkeysContext},
+    root::current_scope,
+};
+
+/// A shortcut declared on a menu root, pressed to run an action whether or not
+/// the menu is open.
+///
+/// # Example
+/// ```rust,ignore
+/// let shortcuts = vec![
+///     MenuShortcut::new("Mod+S", save),
+///     MenuShortcut::new("Mod+Shift+S", save_as).disabled(read_only),
+/// ];
+/// view! {
+///     <menubar::Root shortcuts={shortcuts}>
+///         // ...
+///         <menubar::Item shortcut="Mod+S" on_select={Callback::new(move |_| save())}>
+///             <button>"Save"</button>
+///         </menubar::Item>
+///     </menubar::Root>
+/// }
+/// ```
+#[derive(Clone)]
+pub struct MenuShortcut {
+    keys: String,
+    action: Arc<dyn Fn() + Send + Sync>,
+    disabled: Signal<bool>,
+}
+
+impl MenuShortcut {
+    pub fn new(keys: impl Into<String>, action: impl Fn() + Send + Sync + 'static) -> Self {
+        Self {
+            keys: keys.into(),
+            action: Arc::new(action),
+            disabled: Signal::default(),
+        }
+    }
+
+    /// Stop the shortcut from firing while `disabled` is true, typically the same
+    /// signal that disables its item.
+    pub fn disabled(mut self, disabled: impl Into<Signal<bool>>) -> Self {
+        self.disabled = disabled.into();
+        self
+    }
+}
+
+/// Registers the `shortcuts` of a menu root for as long as the root is mounted.
+/// Does nothing without a [`Provider`](super::Provider).
+pub(crate) fn use_menu_shortcuts(shortcuts: Vec<MenuShortcut>) {
+    let Some(ctx) = use_context::<HotkeysContext>() else {
+        return;
+    };
+    for shortcut in shortcuts {
+        let disabled = shortcut.disabled;
+        let options = HotkeyOptions {
+            enabled: Some(Signal::derive(move || !disabled.get())),
+            ..Default::default()
+        };
+        let action = shortcut.action;
+        let handler = Callback::new(move |_| action());
+        let scope = current_scope(&options);
+        if let Some(id) = ctx.register(&shortcut.keys, scope, options, handler) {
+            on_cleanup(move || ctx.unregister(id));
+        }
+    }
+}
+
+/// Hint shown next to a menu item, e.g. `⇧⌘P` on Apple platforms.
+fn shortcut_label(keys: &str) -> Option<String> {
+    Hotkey::parse(keys).map(|hotkey| hotkey.label(is_apple_platform()))
+}
+
+/// The `shortcut` hint rendered after a menu item's children.
+#[component]
+pub(crate) fn ShortcutHint(shortcut: Option<String>, class: String) -> impl IntoView {
+    shortcut.as_deref().and_then(shortcut_label).map(|label| {
+        view! {
+            <span aria-hidden="true" data-shortcut="" class={class}>
+                {label}
+            </span>
+        }
+    })
+}
@@ -0,0 +1,10 @@
+pub mod context;
+#[cfg(any(feature = "menubar", feature = "context_menu"))]
+pub(crate) mod menu;
+pub mod root;
+
+pub use crate::utils::shortcut::Shortcut;
+pub use context::{Hotkey, HotkeyConflict, HotkeyOptions, HotkeyScope, HotkeysContext};
+#[cfg(any(feature = "menubar", feature = "context_menu"))]
+pub use menu::MenuShortcut;
+pub use root::{Provider, Region, use_hotkey};
@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use leptos::{context::Provider as ContextProvider, ev::keydown, html::Div, prelude::*};
+use leptos_use::{use_document, use_event_listener};
+
+use super::context::{HotkeyConflict, HotkeyOptions, HotkeyScope, HotkeysContext, next_hotkey_id};
+
+/// Provided by [`Region`] to the hotkeys registered inside it.
+#[derive(Copy, Clone)]
+struct RegionContext {
+    id: usize,
+}
+
+/// Application-wide registry of keyboard shortcuts.
+///
+/// Register hotkeys below it with [`use_hotkey`], or with the `shortcuts` prop of
+/// the `menubar`, `menu` and `context_menu` roots.
+///
+/// # Example
+/// ```rust,ignore
+/// <hotkeys::Provider>
+///     <App />
+/// </hotkeys::Provider>
+///
+/// // Anywhere below the provider:
+/// hotkeys::use_hotkey("Mod+Shift+P", move || open_palette(), Default::default());
+/// hotkeys::use_hotkey("g then i", move || go_to_inbox(), Default::default());
+/// ```
+#[component]
+pub fn Provider(
+    children: Children,
+    /// How long to wait for the next chord of a sequence such as `"g then i"`.
+    #[prop(default = Duration::from_millis(1000))]
+    sequence_timeout: Duration,
+    /// Called when a hotkey is registered in a scope that already has a hotkey
+    /// it cannot be told apart from. Conflicts are logged when unset.
+    #[prop(optional)]
+    on_conflict: Option<Callback<HotkeyConflict>>,
+) -> impl IntoView {
+    let ctx = HotkeysContext {
+        sequence_timeout,
+        registrations: StoredValue::new(Vec::new()),
+        regions: StoredValue::new(Default::default()),
+        pending: StoredValue::new(Vec::new()),
+        last_press: StoredValue::new(None),
+        on_conflict,
+    };
+
+    let _ = use_event_listener(use_document(), keydown, move |evt| {
+        ctx.handle_keydown(&evt);
+    });
+
+    view! { <ContextProvider value={ctx}>{children()}</ContextProvider> }
+}
+
+/// Hotkeys registered inside a region only fire while focus is inside it. When
+/// a region hotkey and a global one match the same keys, the region one wins.
+#[component]
+pub fn Region(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
+    let ctx = expect_context::<HotkeysContext>();
+    let region_ref = NodeRef::<Div>::new();
+    let id = next_hotkey_id();
+
+    ctx.regions.update_value(|regions| {
+        regions.insert(id, region_ref);
+    });
+    on_cleanup(move || {
+        let _ = ctx.regions.try_update_value(|regions| regions.remove(&id));
+    });
+
+    view! {
+        <ContextProvider value={RegionContext { id }}>
+            <div node_ref={region_ref} tabindex="-1" data-hotkey-region="" class={class}>
+                {children()}
+            </div>
+        </ContextProvider>
+    }
+}
+
+/// Run `handler` when `keys` are pressed, for as long as the calling component
+/// is mounted. Inside a [`Region`] the hotkey is scoped to it unless
+/// `options.global` is set. Requires a [`Provider`] ancestor.
+pub fn use_hotkey(keys: &str, handler: impl Fn() + Send + Sync + 'static, options: HotkeyOptions) {
+    let ctx = use_context::<HotkeysContext>()
+        .expect("hotkeys::use_hotkey requires a hotkeys::Provider ancestor");
+    register(ctx, keys, options, Callback::new(move |_| handler()));
+}
+
+/// The scope a hotkey registered from the current component belongs to.
+pub(super) fn current_scope(options: &HotkeyOptions) -> HotkeyScope {
+    match use_context::<RegionContext>() {
+        Some(region) if !options.global => HotkeyScope::Region(region.id),
+        _ => HotkeyScope::Global,
+    }
+}
+
+fn register(ctx: HotkeysContext, keys: &str, options: HotkeyOptions, handler: Callback<()>) {
+    let scope = current_scope(&options);
+    if let Some(id) = ctx.register(keys, scope, options, handler) {
+        on_cleanup(move || ctx.unregister(id));
+    }
+}
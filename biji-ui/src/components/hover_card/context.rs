@@ -12,7 +12,10 @@ use leptos::{
     prelude::*,
 };
 
-use crate::utils::positioning::{AvoidCollisions, Positioning};
+use crate::utils::{
+    long_press::LongPress,
+    positioning::{AvoidCollisions, Positioning, anchor_or_trigger},
+};
 
 static HOVER_CARD_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
@@ -25,6 +28,9 @@ pub fn next_hover_card_id() -> String {
 pub struct HoverCardContext {
     /// Wraps the trigger children — used for bounding rect + event listeners.
     pub trigger_ref: NodeRef<Span>,
+    /// Set by the `Anchor` part. When rendered, Content is positioned against it
+    /// instead of the trigger.
+    pub anchor_ref: NodeRef<Div>,
     pub content_ref: NodeRef<Div>,
     pub open: RwSignal<bool>,
     /// Delay before showing the card on hover.
@@ -38,11 +44,19 @@ pub struct HoverCardContext {
     pub hover_card_id: StoredValue<String>,
     pub avoid_collisions: AvoidCollisions,
     pub on_open_change: Option<Callback<bool>>,
+    /// Touch state; a long press on the trigger opens the card.
+    pub long_press: LongPress,
     pub(crate) open_timer: StoredValue<Arc<Mutex<Option<TimeoutHandle>>>>,
     pub(crate) close_timer: StoredValue<Arc<Mutex<Option<TimeoutHandle>>>>,
 }
 
 impl HoverCardContext {
+    /// Element Content is positioned against: the `Anchor` part when rendered,
+    /// otherwise the trigger.
+    pub fn anchor_element(&self) -> Option<web_sys::Element> {
+        anchor_or_trigger(self.anchor_ref, self.trigger_ref)
+    }
+
     pub fn cancel_open_timer(&self) {
         self.open_timer.with_value(|arc| {
             if let Some(h) = arc.lock().unwrap().take() {
@@ -105,6 +119,18 @@ impl HoverCardContext {
         });
     }
 
+    /// Immediately open, cancelling any pending timers.
+    pub fn open_immediate(&self) {
+        self.cancel_open_timer();
+        self.cancel_close_timer();
+        if !self.open.get_untracked() {
+            self.open.set(true);
+            if let Some(cb) = self.on_open_change {
+                cb.run(true);
+            }
+        }
+    }
+
     /// Immediately close, cancelling any pending timers.
     pub fn close_immediate(&self) {
         self.cancel_open_timer();
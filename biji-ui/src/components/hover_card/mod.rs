@@ -2,4 +2,4 @@ pub mod context;
 pub mod root;
 
 pub use crate::utils::positioning::{AvoidCollisions, Positioning};
-pub use root::{Arrow, Content, Root, Trigger};
+pub use root::{Anchor, Arrow, Content, Root, Trigger};
@@ -12,7 +12,10 @@ use wasm_bindgen::JsCast;
 use crate::{
     cn,
     custom_animated_show::CustomAnimatedShow,
-    utils::positioning::{AvoidCollisions, Positioning},
+    utils::{
+        long_press::{DEFAULT_LONG_PRESS_THRESHOLD, LongPress, is_touch, use_long_press},
+        positioning::{AvoidCollisions, Positioning},
+    },
 };
 
 use super::context::{HoverCardContext, next_hover_card_id};
@@ -35,6 +38,9 @@ pub fn Root(
     #[prop(default = 8)] arrow_size: i32,
     #[prop(default = false)] open: bool,
     #[prop(optional)] on_open_change: Option<Callback<bool>>,
+    /// How long a touch must rest on the trigger to open the card.
+    #[prop(default = DEFAULT_LONG_PRESS_THRESHOLD)]
+    long_press_threshold: Duration,
 ) -> impl IntoView {
     let open_arc = Arc::new(Mutex::new(None));
     let close_arc = Arc::new(Mutex::new(None));
@@ -44,6 +50,7 @@ pub fn Root(
 
     let ctx = HoverCardContext {
         trigger_ref: NodeRef::new(),
+        anchor_ref: NodeRef::new(),
         content_ref: NodeRef::new(),
         open: RwSignal::new(open),
         open_delay,
@@ -54,6 +61,7 @@ pub fn Root(
         arrow_size,
         hover_card_id: StoredValue::new(next_hover_card_id()),
         on_open_change,
+        long_press: LongPress::new(long_press_threshold),
         open_timer: StoredValue::new(open_arc),
         close_timer: StoredValue::new(close_arc),
     };
@@ -110,40 +118,94 @@ pub fn Trigger(children: Children, #[prop(into, optional)] class: String) -> imp
     }
 }
 
+/// Optional element that Content is positioned against instead of the Trigger,
+/// e.g. a whole table row. Hovering the Trigger still opens the card.
+#[component]
+pub fn Anchor(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
+    let ctx = expect_context::<HoverCardContext>();
+
+    view! {
+        <div node_ref={ctx.anchor_ref} class={class}>
+            {children()}
+        </div>
+    }
+}
+
 #[component]
 fn TriggerEvents(children: Children) -> impl IntoView {
     let ctx = expect_context::<HoverCardContext>();
 
+    let long_press = ctx.long_press;
+
+    // Touch: long press opens immediately; hover events are ignored.
+    use_long_press(ctx.trigger_ref, long_press, move || ctx.open_immediate());
+
+    // Touch: tapping anywhere outside the trigger and content closes the card.
+    let _ = use_event_listener(use_document(), leptos::ev::pointerdown, move |evt| {
+        if !is_touch(&evt) || !ctx.open.get_untracked() {
+            return;
+        }
+        let Some(target) = evt.target().and_then(|t| t.dyn_into::<web_sys::Node>().ok()) else {
+            return;
+        };
+        let inside =
+            |el: Option<web_sys::HtmlElement>| el.is_some_and(|el| el.contains(Some(&target)));
+        if inside(ctx.trigger_ref.get_untracked().map(Into::into))
+            || inside(ctx.content_ref.get_untracked().map(Into::into))
+        {
+            return;
+        }
+        ctx.close_immediate();
+    });
+
     // Trigger: pointer enters → cancel any pending close, schedule open.
-    let _ = use_event_listener(ctx.trigger_ref, leptos::ev::pointerenter, move |_| {
+    let _ = use_event_listener(ctx.trigger_ref, leptos::ev::pointerenter, move |evt| {
+        long_press.pointer_type.set(evt.pointer_type());
+        if is_touch(&evt) {
+            return;
+        }
         ctx.cancel_close_timer();
         ctx.schedule_open();
     });
 
     // Trigger: pointer leaves → cancel any pending open, schedule close.
-    let _ = use_event_listener(ctx.trigger_ref, leptos::ev::pointerleave, move |_| {
+    let _ = use_event_listener(ctx.trigger_ref, leptos::ev::pointerleave, move |evt| {
+        if is_touch(&evt) {
+            return;
+        }
         ctx.cancel_open_timer();
         ctx.schedule_close();
     });
 
     // Content: pointer enters → cancel pending close so the card stays open.
-    let _ = use_event_listener(ctx.content_ref, leptos::ev::pointerenter, move |_| {
-        ctx.cancel_close_timer();
+    let _ = use_event_listener(ctx.content_ref, leptos::ev::pointerenter, move |evt| {
+        if !is_touch(&evt) {
+            ctx.cancel_close_timer();
+        }
     });
 
     // Content: pointer leaves → schedule close.
-    let _ = use_event_listener(ctx.content_ref, leptos::ev::pointerleave, move |_| {
-        ctx.schedule_close();
+    let _ = use_event_listener(ctx.content_ref, leptos::ev::pointerleave, move |evt| {
+        if !is_touch(&evt) {
+            ctx.schedule_close();
+        }
     });
 
-    // Trigger: focus → schedule open (keyboard / touch accessibility).
+    // Trigger: focus → schedule open (keyboard accessibility). A tap also
+    // focuses the trigger; on touch only a long press opens the card.
     let _ = use_event_listener(ctx.trigger_ref, leptos::ev::focus, move |_| {
+        if long_press.is_recent_touch() {
+            return;
+        }
         ctx.cancel_close_timer();
         ctx.schedule_open();
     });
 
-    // Trigger: blur → schedule close.
+    // Trigger: blur → schedule close. On touch, tapping outside closes instead.
     let _ = use_event_listener(ctx.trigger_ref, leptos::ev::blur, move |_| {
+        if long_press.pointer_type.with_untracked(|t| t == "touch") {
+            return;
+        }
         ctx.cancel_open_timer();
         ctx.schedule_close();
     });
@@ -175,7 +237,7 @@ pub fn Content(
         width,
         height,
         ..
-    } = use_element_bounding(ctx.trigger_ref);
+    } = use_element_bounding(Signal::derive_local(move || ctx.anchor_element()));
 
     let style_signal = Signal::derive(move || {
         let raw_cw = *content_width.read();
@@ -206,14 +268,10 @@ pub fn Content(
         if cw == 0.0 && ch == 0.0 {
             return hidden();
         }
-        let Some(trigger) = ctx.trigger_ref.get_untracked() else {
-            return hidden();
-        };
-        let trigger_node: &web_sys::Node = trigger.as_ref();
-        let Some(trigger_el) = trigger_node.dyn_ref::<web_sys::Element>() else {
+        let Some(anchor) = ctx.anchor_element() else {
             return hidden();
         };
-        let rect = trigger_el.get_bounding_client_rect();
+        let rect = anchor.get_bounding_client_rect();
         let (t_top, t_left, t_width, t_height) =
             (rect.top(), rect.left(), rect.width(), rect.height());
         let vp_w = web_sys::window()
@@ -257,6 +315,7 @@ pub fn Content(
             style_signal={style_signal}
             node_ref={content_ref}
             attr:id={ctx.hover_card_id.get_value()}
+            attr:data-pointer-type={move || ctx.long_press.pointer_type.get()}
         >
             {children()}
         </CustomAnimatedShow>
@@ -1,14 +1,24 @@
 use std::time::Duration;
 
-use crate::components::menubar::{context::RootContext, menu::Menu as MenuComponent};
+use crate::components::{
+    hotkeys::menu::use_menu_shortcuts,
+    menubar::{context::RootContext, menu::Menu as MenuComponent},
+};
 use leptos::prelude::*;
 
+pub use crate::components::hotkeys::MenuShortcut;
+pub use crate::components::menubar::item::CheckboxItem;
 pub use crate::components::menubar::item::Item;
+pub use crate::components::menubar::item::ItemIndicator;
+pub use crate::components::menubar::item::Items;
+pub use crate::components::menubar::item::RadioGroup;
+pub use crate::components::menubar::item::RadioItem;
 pub use crate::components::menubar::item::SubMenuItem as SubMenu;
 pub use crate::components::menubar::item::SubMenuItemContent as SubMenuContent;
 pub use crate::components::menubar::item::SubMenuItemTrigger as SubMenuTrigger;
 pub use crate::components::menubar::menu::MenuContent as Content;
 pub use crate::components::menubar::menu::MenuTrigger as Trigger;
+pub use crate::items::{Collection, CollectionItem};
 pub use crate::utils::positioning::{AvoidCollisions, Positioning};
 
 #[component]
@@ -20,6 +30,13 @@ pub fn Menu(
     #[prop(default = AvoidCollisions::Flip)] avoid_collisions: AvoidCollisions,
     #[prop(default = Duration::from_millis(200))] hide_delay: Duration,
     #[prop(default = false)] prevent_scroll: bool,
+    /// Data-driven items, rendered with [`Items`].
+    #[prop(into, optional)]
+    items: Option<Collection>,
+    /// Shortcuts that run their action when pressed, also while the menu is
+    /// closed. Requires a `hotkeys::Provider`.
+    #[prop(optional)]
+    shortcuts: Vec<MenuShortcut>,
     children: Children,
 ) -> impl IntoView {
     let ctx = RootContext {
@@ -29,16 +46,33 @@ pub fn Menu(
     };
 
     provide_context(ctx);
+    use_menu_shortcuts(shortcuts);
 
-    view! {
-        <MenuComponent
-            disabled={disabled}
-            class={class}
-            positioning={positioning}
-            avoid_collisions={avoid_collisions}
-            hide_delay={hide_delay}
-        >
-            {children()}
-        </MenuComponent>
+    match items {
+        Some(items) => view! {
+            <MenuComponent
+                disabled={disabled}
+                class={class}
+                positioning={positioning}
+                avoid_collisions={avoid_collisions}
+                hide_delay={hide_delay}
+                items={items}
+            >
+                {children()}
+            </MenuComponent>
+        }
+        .into_any(),
+        None => view! {
+            <MenuComponent
+                disabled={disabled}
+                class={class}
+                positioning={positioning}
+                avoid_collisions={avoid_collisions}
+                hide_delay={hide_delay}
+            >
+                {children()}
+            </MenuComponent>
+        }
+        .into_any(),
     }
 }
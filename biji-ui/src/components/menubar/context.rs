@@ -8,8 +8,8 @@ use leptos::{html::Div, prelude::*};
 
 use crate::{
     items::{
-        FilterActiveItems, Focus, GetIndex, IsActive, ManageFocus, NavigateItems, Toggle,
-        filter_active, next_item, previous_item,
+        Collection, FilterActiveItems, Focus, GetIndex, IsActive, ManageFocus, NavigateItems,
+        Toggle, filter_active, next_item, previous_item,
     },
     utils::positioning::{AvoidCollisions, Positioning},
 };
@@ -133,6 +133,8 @@ pub struct MenuContext {
     pub trigger_ref: NodeRef<Div>,
     pub item_focus: RwSignal<Option<usize>>,
     pub items: RwSignal<HashMap<usize, ItemData>>,
+    /// Data-driven items passed to `Menu`, rendered with `Items`.
+    pub collection: Option<Collection>,
     pub allow_loop: bool,
     pub positioning: Positioning,
     pub avoid_collisions: AvoidCollisions,
@@ -154,6 +156,7 @@ impl Default for MenuContext {
             trigger_ref: NodeRef::default(),
             item_focus: RwSignal::new(None),
             items: RwSignal::new(HashMap::new()),
+            collection: None,
             allow_loop: false,
             positioning: Positioning::BottomStart,
             avoid_collisions: AvoidCollisions::Flip,
@@ -378,3 +381,20 @@ impl Focus for ItemData {
         }
     }
 }
+
+/// Provided by `CheckboxItem` and `RadioItem` to their `ItemIndicator` and to the
+/// shared item keyboard handling.
+#[derive(Copy, Clone)]
+pub struct CheckableItemContext {
+    pub checked: Signal<bool>,
+    /// Whether selecting the item closes every open menu.
+    pub close_on_select: bool,
+    pub(crate) on_select: Callback<()>,
+}
+
+/// Provided by `RadioGroup`; holds the value of the checked `RadioItem`.
+#[derive(Copy, Clone)]
+pub struct RadioGroupContext {
+    pub value: RwSignal<String>,
+    pub(crate) on_value_change: Option<Callback<String>>,
+}
@@ -1,8 +1,8 @@
-use std::time::Duration;
+use std::{marker::PhantomData, time::Duration};
 
 use leptos::{
     context::Provider,
-    ev::{focus, keydown, mouseover},
+    ev::{click, focus, keydown, mouseover},
     prelude::*,
 };
 use leptos_use::{UseElementBoundingReturn, use_element_bounding, use_event_listener};
@@ -11,18 +11,30 @@ use web_sys::{HtmlAnchorElement, HtmlButtonElement};
 
 use crate::{
     cn,
-    components::menubar::context::ItemData,
+    components::{
+        hotkeys::menu::ShortcutHint,
+        menubar::context::ItemData,
+    },
     custom_animated_show::CustomAnimatedShow,
-    items::{Focus, GetIndex, ManageFocus, NavigateItems, Toggle},
+    items::{self, Focus, GetIndex, ManageFocus, NavigateItems, Toggle, render_groups},
     utils::positioning::Positioning,
 };
 
-use super::context::{MenuContext, RootContext};
+use super::context::{CheckableItemContext, MenuContext, RadioGroupContext, RootContext};
 
 #[component]
 pub fn Item(
     #[prop(default = false)] disabled: bool,
     #[prop(into, optional)] class: String,
+    /// Called when the item is clicked or activated with Enter.
+    #[prop(optional)]
+    on_select: Option<Callback<()>>,
+    /// Keyboard shortcut such as `"Mod+S"`, shown after the children. Declare it
+    /// in the `shortcuts` of the menubar [`Root`](super::Root) as well to make
+    /// pressing it run an action.
+    #[prop(into, optional)]
+    shortcut: Option<String>,
+    #[prop(into, optional)] shortcut_class: String,
     children: Children,
 ) -> impl IntoView {
     let menu_ctx = expect_context::<MenuContext>();
@@ -46,6 +58,12 @@ pub fn Item(
         menu_ctx.remove_item(index);
     });
 
+    let _ = use_event_listener(trigger_ref, click, move |_| {
+        if let Some(cb) = on_select.filter(|_| !disabled) {
+            cb.run(());
+        }
+    });
+
     view! {
         <Provider value={item_ctx}>
             <ItemTriggerEvents>
@@ -60,27 +78,289 @@ pub fn Item(
                     data-highlighted={move || menu_ctx.item_in_focus(item_ctx.get_index())}
                 >
                     {children()}
+                    <ShortcutHint shortcut={shortcut} class={shortcut_class} />
                 </div>
             </ItemTriggerEvents>
         </Provider>
     }
 }
 
+/// A menu item that toggles a boolean, e.g. "View → Show sidebar".
+///
+/// Renders with `role="menuitemcheckbox"` and `aria-checked`. Click, Enter and
+/// Space toggle `checked`; set `close_on_select` to `false` to keep the menu open.
+///
+/// # Example
+/// ```rust,ignore
+/// let show_sidebar = RwSignal::new(true);
+/// view! {
+///     <menubar::CheckboxItem checked={show_sidebar} close_on_select={false}>
+///         <menubar::ItemIndicator>"✓"</menubar::ItemIndicator>
+///         "Show sidebar"
+///     </menubar::CheckboxItem>
+/// }
+/// ```
+#[component]
+pub fn CheckboxItem(
+    children: Children,
+    checked: RwSignal<bool>,
+    #[prop(default = false)] disabled: bool,
+    #[prop(into, optional)] class: String,
+    /// Close every open menu after toggling.
+    #[prop(default = true)]
+    close_on_select: bool,
+    #[prop(optional)] on_checked_change: Option<Callback<bool>>,
+    /// Keyboard shortcut hint; see [`Item`].
+    #[prop(into, optional)]
+    shortcut: Option<String>,
+    #[prop(into, optional)] shortcut_class: String,
+) -> impl IntoView {
+    let checkable = CheckableItemContext {
+        checked: checked.into(),
+        close_on_select,
+        on_select: Callback::new(move |_| {
+            checked.update(|c| *c = !*c);
+            if let Some(cb) = on_checked_change {
+                cb.run(checked.get_untracked());
+            }
+        }),
+    };
+
+    view! {
+        <CheckableItem
+            checkable={checkable}
+            role="menuitemcheckbox"
+            disabled={disabled}
+            class={class}
+            shortcut={shortcut}
+            shortcut_class={shortcut_class}
+        >
+            {children()}
+        </CheckableItem>
+    }
+}
+
+/// Groups [`RadioItem`]s so that exactly one of them is checked, e.g. "Sort by".
+#[component]
+pub fn RadioGroup(
+    children: Children,
+    /// Value of the checked item.
+    value: RwSignal<String>,
+    #[prop(into, optional)] class: String,
+    #[prop(optional)] on_value_change: Option<Callback<String>>,
+) -> impl IntoView {
+    let ctx = RadioGroupContext {
+        value,
+        on_value_change,
+    };
+
+    view! {
+        <Provider value={ctx}>
+            <div role="group" class={class}>
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// A menu item inside a [`RadioGroup`]. Renders with `role="menuitemradio"`.
+#[component]
+pub fn RadioItem(
+    children: Children,
+    #[prop(into)] value: String,
+    #[prop(default = false)] disabled: bool,
+    #[prop(into, optional)] class: String,
+    /// Close every open menu after checking the item.
+    #[prop(default = true)]
+    close_on_select: bool,
+    /// Keyboard shortcut hint; see [`Item`].
+    #[prop(into, optional)]
+    shortcut: Option<String>,
+    #[prop(into, optional)] shortcut_class: String,
+) -> impl IntoView {
+    let group = expect_context::<RadioGroupContext>();
+    let value = StoredValue::new(value);
+
+    let checkable = CheckableItemContext {
+        checked: Signal::derive(move || group.value.with(|v| value.with_value(|own| v == own))),
+        close_on_select,
+        on_select: Callback::new(move |_| {
+            let own = value.get_value();
+            if group.value.with_untracked(|v| *v != own) {
+                group.value.set(own.clone());
+                if let Some(cb) = group.on_value_change {
+                    cb.run(own);
+                }
+            }
+        }),
+    };
+
+    view! {
+        <CheckableItem
+            checkable={checkable}
+            role="menuitemradio"
+            disabled={disabled}
+            class={class}
+            shortcut={shortcut}
+            shortcut_class={shortcut_class}
+        >
+            {children()}
+        </CheckableItem>
+    }
+}
+
+/// Renders its children only while the enclosing [`CheckboxItem`] or
+/// [`RadioItem`] is checked.
+#[component]
+pub fn ItemIndicator(
+    children: ChildrenFn,
+    #[prop(into, optional)] class: String,
+) -> impl IntoView {
+    let checkable = expect_context::<CheckableItemContext>();
+    let children = StoredValue::new(children);
+
+    view! {
+        <Show when={move || checkable.checked.get()}>
+            <span aria-hidden="true" class={class.clone()}>
+                {children.with_value(|children| children())}
+            </span>
+        </Show>
+    }
+}
+
+#[component]
+fn CheckableItem(
+    children: Children,
+    checkable: CheckableItemContext,
+    role: &'static str,
+    disabled: bool,
+    class: String,
+    #[prop(default = None)] shortcut: Option<String>,
+    #[prop(optional)] shortcut_class: String,
+) -> impl IntoView {
+    let menu_ctx = expect_context::<MenuContext>();
+    let root_ctx = expect_context::<RootContext>();
+    let parent_item = use_context::<ItemData>();
+
+    let trigger_ref = NodeRef::new();
+    let index = menu_ctx.next_index();
+    let item_ctx = ItemData::Item {
+        index,
+        disabled,
+        trigger_ref,
+        is_submenu: parent_item.is_some(),
+    };
+
+    menu_ctx.upsert_item(index, item_ctx);
+
+    on_cleanup(move || {
+        menu_ctx.remove_item(index);
+    });
+
+    let _ = use_event_listener(trigger_ref, click, move |_| {
+        if disabled {
+            return;
+        }
+        checkable.on_select.run(());
+        if checkable.close_on_select {
+            root_ctx.close_all();
+        }
+    });
+
+    view! {
+        <Provider value={item_ctx}>
+            <Provider value={checkable}>
+                <ItemTriggerEvents>
+                    <div
+                        node_ref={trigger_ref}
+                        class={class}
+                        tabindex=0
+                        role={role}
+                        aria-checked={move || if checkable.checked.get() { "true" } else { "false" }}
+                        aria-disabled={if disabled { Some("true") } else { None }}
+                        data-state={move || if checkable.checked.get() { "checked" } else { "unchecked" }}
+                        data-disabled={disabled}
+                        data-highlighted={move || menu_ctx.item_in_focus(index)}
+                    >
+                        {children()}
+                        <ShortcutHint shortcut={shortcut} class={shortcut_class} />
+                    </div>
+                </ItemTriggerEvents>
+            </Provider>
+        </Provider>
+    }
+}
+
+/// Renders the `items` passed to the enclosing [`Menu`](super::menu::Menu), one
+/// [`Item`] per entry.
+///
+/// `render` returns each item's content, typically the `<button>` or `<a>` that
+/// Enter activates. Entries that share a `group` are wrapped in a `role="group"`
+/// element headed by the group name.
+#[component]
+pub fn Items<T, F, IV>(
+    render: F,
+    /// Called with the value of the selected item.
+    #[prop(optional)]
+    on_select: Option<Callback<String>>,
+    #[prop(into, optional)] item_class: String,
+    #[prop(into, optional)] group_class: String,
+    #[prop(into, optional)] group_label_class: String,
+    /// Ties `T` to the props; never set.
+    #[prop(optional)]
+    _item: PhantomData<T>,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(items::CollectionItem<T>) -> IV + Clone + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let menu_ctx = expect_context::<MenuContext>();
+    let entries = menu_ctx
+        .collection
+        .expect("Items requires the `items` prop on the enclosing Menu")
+        .typed::<T>();
+
+    move || {
+        Some(entries?.with(|entries| {
+            render_groups(entries, &group_class, &group_label_class, |entry| {
+                let render = render.clone();
+                let value = entry.value.clone();
+                let select = Callback::new(move |_| {
+                    if let Some(cb) = on_select {
+                        cb.run(value.clone());
+                    }
+                });
+                view! {
+                    <Item disabled={entry.disabled} class={item_class.clone()} on_select={select}>
+                        {render(entry)}
+                    </Item>
+                }
+            })
+        }))
+    }
+}
+
 #[component]
 pub fn ItemTriggerEvents(children: Children) -> impl IntoView {
     let root_ctx = expect_context::<RootContext>();
     let menu_ctx = expect_context::<MenuContext>();
     let item_ctx = expect_context::<ItemData>();
+    let checkable = use_context::<CheckableItemContext>();
 
     let handle_on_click = move || {
         if let Some(trigger_ref) = item_ctx.get_trigger_ref().get() {
             if let Some(child) = trigger_ref.children().get_with_index(0) {
                 if let Ok(child) = child.clone().dyn_into::<HtmlButtonElement>() {
                     let _ = child.click();
+                    return;
                 } else if let Ok(child) = child.dyn_into::<HtmlAnchorElement>() {
                     let _ = child.click();
+                    return;
                 }
             }
+            // No button or link inside: click the item itself so `on_select` runs.
+            trigger_ref.click();
         }
     };
 
@@ -155,6 +435,20 @@ pub fn ItemTriggerEvents(children: Children) -> impl IntoView {
                     menu_ctx.close_with_submenus();
                 }
             }
+            "Enter" | " " if checkable.is_some() => {
+                evt.prevent_default();
+                let Some(checkable) = checkable else {
+                    return;
+                };
+                if item_ctx.get_disabled() {
+                    return;
+                }
+                checkable.on_select.run(());
+                if checkable.close_on_select {
+                    root_ctx.close_all();
+                    root_ctx.focus_active_item();
+                }
+            }
             "Enter" => {
                 if let ItemData::Item { .. } = item_ctx {
                     handle_on_click();
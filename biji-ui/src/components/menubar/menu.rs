@@ -13,7 +13,7 @@ use wasm_bindgen::JsCast;
 use crate::{
     cn,
     custom_animated_show::CustomAnimatedShow,
-    items::{Focus, ManageFocus, NavigateItems, Toggle},
+    items::{Collection, Focus, ManageFocus, NavigateItems, Toggle},
     utils::{positioning::{AvoidCollisions, Positioning}, prevent_scroll::use_prevent_scroll},
 };
 
@@ -53,6 +53,9 @@ pub fn Menu(
     /// The timeout after which the component will be unmounted if `when == false`
     #[prop(default = Duration::from_millis(200))]
     hide_delay: Duration,
+    /// Data-driven items, rendered with [`Items`](super::item::Items).
+    #[prop(into, optional)]
+    items: Option<Collection>,
     children: Children,
 ) -> impl IntoView {
     let ctx = expect_context::<RootContext>();
@@ -62,6 +65,7 @@ pub fn Menu(
     let menu_ctx = MenuContext {
         index,
         disabled,
+        collection: items,
         allow_loop: ctx.allow_item_loop,
         positioning,
         avoid_collisions,
@@ -4,7 +4,14 @@ pub mod menu;
 pub mod root;
 
 pub use crate::utils::positioning::{AvoidCollisions, Positioning};
+pub use crate::components::hotkeys::MenuShortcut;
+pub use crate::items::{Collection, CollectionItem};
+pub use item::CheckboxItem;
 pub use item::Item;
+pub use item::ItemIndicator;
+pub use item::Items;
+pub use item::RadioGroup;
+pub use item::RadioItem;
 pub use item::SubMenuItem as SubMenu;
 pub use item::SubMenuItemContent as SubMenuContent;
 pub use item::SubMenuItemTrigger as SubMenuTrigger;
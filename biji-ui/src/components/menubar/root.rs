@@ -1,6 +1,9 @@
 use leptos::{context::Provider, prelude::*};
 
-use crate::components::menubar::context::{MenubarContext, RootContext};
+use crate::components::{
+    hotkeys::menu::{MenuShortcut, use_menu_shortcuts},
+    menubar::context::{MenubarContext, RootContext},
+};
 
 #[component]
 pub fn Root(
@@ -9,6 +12,10 @@ pub fn Root(
     #[prop(default = false)] allow_menu_loop: bool,
     #[prop(default = false)] allow_item_loop: bool,
     #[prop(default = false)] prevent_scroll: bool,
+    /// Shortcuts that run their action when pressed, also while every menu is
+    /// closed. Requires a `hotkeys::Provider`.
+    #[prop(optional)]
+    shortcuts: Vec<MenuShortcut>,
 ) -> impl IntoView {
     let menubar_ref = NodeRef::new();
 
@@ -23,6 +30,8 @@ pub fn Root(
         root: RwSignal::new(root_ctx),
     };
 
+    use_menu_shortcuts(shortcuts);
+
     view! {
         <Provider value={ctx}>
             <div node_ref={menubar_ref} class={class} role="menubar">
@@ -28,6 +28,9 @@ pub mod dialog;
 #[cfg(feature = "drawer")]
 pub mod drawer;
 
+#[cfg(feature = "hotkeys")]
+pub mod hotkeys;
+
 #[cfg(feature = "hover_card")]
 pub mod hover_card;
 
@@ -40,6 +43,9 @@ pub mod menubar;
 #[cfg(feature = "navigation_menu")]
 pub mod navigation_menu;
 
+#[cfg(feature = "number_field")]
+pub mod number_field;
+
 #[cfg(feature = "pin_input")]
 pub mod pin_input;
 
@@ -67,6 +73,9 @@ pub mod switch;
 #[cfg(feature = "tabs")]
 pub mod tabs;
 
+#[cfg(feature = "tags_input")]
+pub mod tags_input;
+
 #[cfg(feature = "toggle_group")]
 pub mod toggle_group;
 
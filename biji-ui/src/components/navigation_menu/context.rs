@@ -21,6 +21,48 @@ use crate::{
     utils::positioning::{AvoidCollisions, Positioning},
 };
 
+#[derive(Copy, Clone, Default, PartialEq)]
+pub enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl Orientation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Orientation::Horizontal => "horizontal",
+            Orientation::Vertical => "vertical",
+        }
+    }
+}
+
+/// How a `Link`'s `href` is compared with the current path to decide whether
+/// the link is active. Only used with the `router` feature.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ActiveMatch {
+    /// Active when the path equals `href`, ignoring a trailing slash.
+    #[default]
+    Exact,
+    /// Active when the path equals `href` or lies below it, e.g. `/docs`
+    /// matches `/docs/tabs` but not `/docsearch`.
+    Prefix,
+}
+
+impl ActiveMatch {
+    pub fn matches(&self, href: &str, path: &str) -> bool {
+        let href = href.split(['?', '#']).next().unwrap_or_default();
+        let href = href.trim_end_matches('/');
+        let path = path.trim_end_matches('/');
+        match self {
+            ActiveMatch::Exact => path == href,
+            ActiveMatch::Prefix => path
+                .strip_prefix(href)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/')),
+        }
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct NavMenuContext {
     /// Value of the currently-open item, or `None` when all items are closed.
@@ -28,6 +70,9 @@ pub struct NavMenuContext {
     /// Roving-tabindex focus index (mirrors Tabs pattern).
     pub item_focus: RwSignal<Option<usize>>,
     pub items: RwSignal<HashMap<usize, NavMenuItemContext>>,
+    /// Direction of the list. Selects the arrow keys that move between
+    /// triggers and the one that opens a panel.
+    pub orientation: Orientation,
     pub positioning: Positioning,
     pub avoid_collisions: AvoidCollisions,
     /// Hover-close delay: how long after leaving a trigger/content before closing.
@@ -40,6 +85,73 @@ pub struct NavMenuContext {
     pub close_timer: StoredValue<Arc<Mutex<Option<TimeoutHandle>>>>,
     pub root_id: usize,
     pub(crate) next_id: StoredValue<AtomicUsize>,
+    /// Value that was open before the last change of `open_value`. Drives
+    /// `data-motion` on the panels.
+    pub previous_value: RwSignal<Option<String>>,
+    /// Set while a `Viewport` is mounted; `Content` then renders inside it.
+    pub has_viewport: RwSignal<bool>,
+    pub viewport_ref: NodeRef<Div>,
+    /// Panels registered by every `Content`, rendered by the `Viewport`.
+    pub(crate) panels: RwSignal<Vec<ViewportPanel>>,
+    /// The menu whose panel contains this one, when this is a `Sub` menu.
+    pub(crate) parent: Option<StoredValue<NavMenuContext>>,
+    /// `Sub` menus rendered inside this menu's panels.
+    pub(crate) subs: RwSignal<Vec<NavMenuContext>>,
+}
+
+/// A `Content` panel waiting to be rendered by the `Viewport`.
+#[derive(Clone)]
+pub(crate) struct ViewportPanel {
+    pub item: NavMenuItemContext,
+    pub children: ChildrenFn,
+    pub show_class: String,
+    pub hide_class: String,
+}
+
+/// Direction a panel animates in or out when the open item changes, relative
+/// to the order of the items in the list.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Motion {
+    FromStart,
+    FromEnd,
+    ToStart,
+    ToEnd,
+}
+
+impl Motion {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Motion::FromStart => "from-start",
+            Motion::FromEnd => "from-end",
+            Motion::ToStart => "to-start",
+            Motion::ToEnd => "to-end",
+        }
+    }
+
+    /// Motion of the item at `index` when the open item moves from `previous`
+    /// to `current`. `None` when the menu opens from closed, closes, or the
+    /// item is not involved.
+    pub fn between(previous: Option<usize>, current: Option<usize>, index: usize) -> Option<Self> {
+        let (previous, current) = (previous?, current?);
+        if previous == current {
+            return None;
+        }
+        if index == current {
+            Some(if previous < current {
+                Motion::FromEnd
+            } else {
+                Motion::FromStart
+            })
+        } else if index == previous {
+            Some(if current > previous {
+                Motion::ToStart
+            } else {
+                Motion::ToEnd
+            })
+        } else {
+            None
+        }
+    }
 }
 
 impl NavMenuContext {
@@ -59,23 +171,31 @@ impl NavMenuContext {
         });
     }
 
-    /// Cancel any in-flight close timer.
+    /// Cancel any in-flight close timer, including those of the parent menus,
+    /// whose panels contain this one.
     pub fn cancel_close_timer(&self) {
-        self.close_timer.with_value(|arc| {
+        let _ = self.close_timer.try_with_value(|arc| {
             if let Some(h) = arc.lock().unwrap().take() {
                 h.clear();
             }
         });
+        if let Some(parent) = self.parent() {
+            parent.cancel_close_timer();
+        }
+    }
+
+    pub(crate) fn parent(&self) -> Option<NavMenuContext> {
+        self.parent.and_then(|p| p.try_get_value())
     }
 
     /// Schedule `open_value → None` after `close_delay`.  Cancels any existing timer first.
     pub fn schedule_close(&self) {
         self.cancel_close_timer();
-        let open_value = self.open_value;
+        let ctx = *self;
         let delay = self.close_delay;
         let handle = leptos::leptos_dom::helpers::set_timeout_with_handle(
             move || {
-                open_value.set(None);
+                ctx.set_open_value(None);
             },
             delay,
         )
@@ -88,13 +208,59 @@ impl NavMenuContext {
     /// Immediately open an item, cancelling any pending close.
     pub fn open(&self, value: String) {
         self.cancel_close_timer();
-        self.open_value.set(Some(value));
+        self.set_open_value(Some(value));
     }
 
     /// Immediately close all items, cancelling any pending close timer.
     pub fn close_immediate(&self) {
         self.cancel_close_timer();
-        self.open_value.set(None);
+        self.set_open_value(None);
+    }
+
+    /// Close this menu and every menu it is nested in.
+    pub fn close_all(&self) {
+        self.close_immediate();
+        if let Some(parent) = self.parent() {
+            parent.close_all();
+        }
+    }
+
+    /// The deepest open menu: an open `Sub` menu if there is one, otherwise
+    /// this menu if it is open.
+    pub(crate) fn innermost_open(&self) -> Option<NavMenuContext> {
+        let sub = self
+            .subs
+            .with_untracked(|subs| subs.iter().find_map(|sub| sub.innermost_open()));
+        sub.or_else(|| self.open_value.get_untracked().is_some().then_some(*self))
+    }
+
+    /// `true` if `target` is inside one of this menu's triggers, links, panels
+    /// or viewport, or inside a `Sub` menu.
+    pub(crate) fn contains(&self, target: &web_sys::Node) -> bool {
+        let in_items = self.items.with_untracked(|items| {
+            items.values().any(|item| {
+                node_contains(item.trigger_ref.get_untracked(), target)
+                    || node_contains(item.link_ref.get_untracked(), target)
+                    || node_contains(item.content_ref.get_untracked(), target)
+            })
+        });
+        in_items
+            || node_contains(self.viewport_ref.get_untracked(), target)
+            || self
+                .subs
+                .with_untracked(|subs| subs.iter().any(|sub| sub.contains(target)))
+    }
+
+    /// Change the open item, remembering the previous one for `data-motion`.
+    fn set_open_value(&self, value: Option<String>) {
+        let Some(current) = self.open_value.try_get_untracked() else {
+            return;
+        };
+        if current == value {
+            return;
+        }
+        self.previous_value.set(current);
+        self.open_value.set(value);
     }
 
     pub fn is_open(&self, value: &str) -> bool {
@@ -104,6 +270,27 @@ impl NavMenuContext {
     pub fn any_open(&self) -> bool {
         self.open_value.get().is_some()
     }
+
+    /// Index of the item registered with `value`.
+    pub fn index_of(&self, value: &str) -> Option<usize> {
+        self.items.with(|items| {
+            items
+                .values()
+                .find(|item| item.value.with_value(|v| v == value))
+                .map(|item| item.index)
+        })
+    }
+
+    /// `data-motion` of the item at `index`; see [`Motion::between`].
+    pub fn motion(&self, index: usize) -> Option<Motion> {
+        let previous = self.previous_value.get().and_then(|v| self.index_of(&v));
+        let current = self.open_value.get().and_then(|v| self.index_of(&v));
+        Motion::between(previous, current, index)
+    }
+}
+
+fn node_contains(el: Option<impl AsRef<web_sys::Node>>, target: &web_sys::Node) -> bool {
+    el.is_some_and(|el| el.as_ref().contains(Some(target)))
 }
 
 impl FilterActiveItems<NavMenuItemContext> for NavMenuContext {
@@ -184,3 +371,35 @@ impl Focus for NavMenuItemContext {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn active_match() {
+        assert!(ActiveMatch::Exact.matches("/docs", "/docs"));
+        assert!(ActiveMatch::Exact.matches("/docs/", "/docs"));
+        assert!(ActiveMatch::Exact.matches("/docs?tab=1#top", "/docs"));
+        assert!(!ActiveMatch::Exact.matches("/docs", "/docs/tabs"));
+        assert!(ActiveMatch::Exact.matches("/", "/"));
+        assert!(ActiveMatch::Prefix.matches("/docs", "/docs/tabs"));
+        assert!(ActiveMatch::Prefix.matches("/docs", "/docs"));
+        assert!(!ActiveMatch::Prefix.matches("/docs", "/docsearch"));
+        assert!(ActiveMatch::Prefix.matches("/", "/anything"));
+    }
+
+    #[test]
+    fn motion_follows_item_order() {
+        assert_eq!(Motion::between(Some(0), Some(2), 2), Some(Motion::FromEnd));
+        assert_eq!(Motion::between(Some(0), Some(2), 0), Some(Motion::ToStart));
+        assert_eq!(
+            Motion::between(Some(2), Some(0), 0),
+            Some(Motion::FromStart)
+        );
+        assert_eq!(Motion::between(Some(2), Some(0), 2), Some(Motion::ToEnd));
+        assert_eq!(Motion::between(Some(0), Some(2), 1), None);
+        assert_eq!(Motion::between(None, Some(2), 2), None);
+        assert_eq!(Motion::between(Some(2), None, 2), None);
+    }
+}
@@ -2,4 +2,5 @@ pub mod context;
 pub mod root;
 
 pub use crate::utils::positioning::{AvoidCollisions, Positioning};
-pub use root::{Content, Item, Link, List, Root, Trigger};
+pub use context::{ActiveMatch, Motion, Orientation};
+pub use root::{Content, Indicator, Item, Link, List, Root, Sub, Trigger, Viewport};
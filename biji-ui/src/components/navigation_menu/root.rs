@@ -9,6 +9,7 @@ use std::{
 use leptos::{
     context::Provider,
     ev::{click, focus, keydown, pointerenter, pointerleave},
+    leptos_dom::helpers::TimeoutHandle,
     prelude::*,
 };
 use leptos_use::{
@@ -23,14 +24,16 @@ use crate::{
     utils::positioning::{AvoidCollisions, Positioning},
 };
 
-use super::context::{NavMenuContext, NavMenuItemContext};
+use super::context::{ActiveMatch, NavMenuContext, NavMenuItemContext, Orientation, ViewportPanel};
 
 static NAV_MENU_ROOT_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
 /// Marker placed in context by `Content` so that `Link` can distinguish
 /// "inside a panel" (don't register as nav item) from "top-level nav link".
+/// Holds the `root_id` of the menu owning the panel, so that the links of a
+/// `Sub` menu inside a panel still register with the sub menu.
 #[derive(Copy, Clone)]
-struct InsideNavContent;
+struct InsideNavContent(usize);
 
 fn next_root_id() -> usize {
     NAV_MENU_ROOT_COUNTER.fetch_add(1, Ordering::Relaxed)
@@ -50,7 +53,14 @@ pub fn Root(
     /// Optional `aria-label` for the `<nav>` element.
     #[prop(into, optional)]
     aria_label: Option<String>,
-    #[prop(default = Positioning::BottomStart)] positioning: Positioning,
+    /// Layout of the list. Vertical menus move between triggers with Up/Down
+    /// and open panels with ArrowRight.
+    #[prop(default = Orientation::Horizontal)]
+    orientation: Orientation,
+    /// Where panels open relative to their trigger. Defaults to `BottomStart`
+    /// for horizontal menus and `RightStart` for vertical ones.
+    #[prop(optional)]
+    positioning: Option<Positioning>,
     #[prop(default = AvoidCollisions::Flip)] avoid_collisions: AvoidCollisions,
     /// How long after the pointer leaves before the open panel closes.
     #[prop(default = Duration::from_millis(200))]
@@ -59,32 +69,19 @@ pub fn Root(
     #[prop(default = Duration::from_millis(200))]
     hide_delay: Duration,
 ) -> impl IntoView {
-    let close_timer = Arc::new(Mutex::new(None));
-    let cleanup_timer = Arc::clone(&close_timer);
-
-    let ctx = NavMenuContext {
-        open_value: RwSignal::new(None),
-        item_focus: RwSignal::new(None),
-        items: RwSignal::new(Default::default()),
+    let ctx = new_context(
+        orientation,
         positioning,
         avoid_collisions,
         close_delay,
         hide_delay,
-        close_timer: StoredValue::new(close_timer),
-        root_id: next_root_id(),
-        next_id: StoredValue::new(AtomicUsize::new(0)),
-    };
-
-    on_cleanup(move || {
-        if let Some(h) = cleanup_timer.lock().unwrap().take() {
-            h.clear();
-        }
-    });
+        None,
+    );
 
     view! {
         <Provider value={ctx}>
             <RootEvents>
-                <nav aria-label={aria_label} class={class}>
+                <nav aria-label={aria_label} class={class} data-orientation={orientation.as_str()}>
                     {children()}
                 </nav>
             </RootEvents>
@@ -92,19 +89,66 @@ pub fn Root(
     }
 }
 
+fn new_context(
+    orientation: Orientation,
+    positioning: Option<Positioning>,
+    avoid_collisions: AvoidCollisions,
+    close_delay: Duration,
+    hide_delay: Duration,
+    parent: Option<NavMenuContext>,
+) -> NavMenuContext {
+    let close_timer: Arc<Mutex<Option<TimeoutHandle>>> = Arc::new(Mutex::new(None));
+    let cleanup_timer = Arc::clone(&close_timer);
+
+    on_cleanup(move || {
+        if let Some(h) = cleanup_timer.lock().unwrap().take() {
+            h.clear();
+        }
+    });
+
+    let positioning = positioning.unwrap_or(match orientation {
+        Orientation::Horizontal => Positioning::BottomStart,
+        Orientation::Vertical => Positioning::RightStart,
+    });
+
+    NavMenuContext {
+        open_value: RwSignal::new(None),
+        item_focus: RwSignal::new(None),
+        items: RwSignal::new(Default::default()),
+        orientation,
+        positioning,
+        avoid_collisions,
+        close_delay,
+        hide_delay,
+        close_timer: StoredValue::new(close_timer),
+        root_id: next_root_id(),
+        next_id: StoredValue::new(AtomicUsize::new(0)),
+        previous_value: RwSignal::new(None),
+        has_viewport: RwSignal::new(false),
+        viewport_ref: NodeRef::new(),
+        panels: RwSignal::new(Vec::new()),
+        parent: parent.map(StoredValue::new),
+        subs: RwSignal::new(Vec::new()),
+    }
+}
+
 #[component]
 fn RootEvents(children: Children) -> impl IntoView {
     let ctx = expect_context::<NavMenuContext>();
 
-    // Escape: close immediately and return focus to the previously-open trigger.
+    // Escape: close the innermost open menu immediately and return focus to
+    // its previously-open trigger.
     let _ = use_event_listener(use_document(), keydown, move |evt| {
-        if evt.key() != "Escape" || !ctx.any_open() {
+        if evt.key() != "Escape" {
             return;
         }
-        let open_val = ctx.open_value.get_untracked();
-        ctx.close_immediate();
+        let Some(menu) = ctx.innermost_open() else {
+            return;
+        };
+        let open_val = menu.open_value.get_untracked();
+        menu.close_immediate();
         if let Some(val) = open_val {
-            ctx.items.with_untracked(|items| {
+            menu.items.with_untracked(|items| {
                 for item in items.values() {
                     if item.value.with_value(|v| *v == val) {
                         item.focus();
@@ -115,6 +159,19 @@ fn RootEvents(children: Children) -> impl IntoView {
         }
     });
 
+    // Navigating elsewhere closes the open panel.
+    #[cfg(feature = "router")]
+    {
+        let pathname = leptos_router::hooks::use_location().pathname;
+        Effect::new(move |previous: Option<String>| {
+            let path = pathname.get();
+            if previous.is_some_and(|previous| previous != path) {
+                ctx.close_all();
+            }
+            path
+        });
+    }
+
     // Document click: close if the click lands outside all triggers and content panels.
     let _ = use_event_listener(use_document(), click, move |evt| {
         if !ctx.any_open() {
@@ -124,31 +181,7 @@ fn RootEvents(children: Children) -> impl IntoView {
             .target()
             .and_then(|t| t.dyn_into::<web_sys::Element>().ok());
         let Some(target) = target else { return };
-        let inside = ctx.items.with_untracked(|items| {
-            items.values().any(|item| {
-                let t = target.as_ref();
-                if let Some(el) = item.trigger_ref.get() {
-                    let node: &web_sys::Node = el.as_ref();
-                    if node.contains(Some(t)) {
-                        return true;
-                    }
-                }
-                if let Some(el) = item.link_ref.get() {
-                    let node: &web_sys::Node = el.as_ref();
-                    if node.contains(Some(t)) {
-                        return true;
-                    }
-                }
-                if let Some(el) = item.content_ref.get() {
-                    let node: &web_sys::Node = el.as_ref();
-                    if node.contains(Some(t)) {
-                        return true;
-                    }
-                }
-                false
-            })
-        });
-        if !inside {
+        if !ctx.contains(target.as_ref()) {
             ctx.close_immediate();
         }
     });
@@ -158,8 +191,10 @@ fn RootEvents(children: Children) -> impl IntoView {
 
 #[component]
 pub fn List(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
+    let ctx = expect_context::<NavMenuContext>();
+
     view! {
-        <ul class={class}>
+        <ul class={class} data-orientation={ctx.orientation.as_str()}>
             {children()}
         </ul>
     }
@@ -304,7 +339,14 @@ fn TriggerEvents(children: Children) -> impl IntoView {
             return;
         }
         let value = item_ctx.value.with_value(|v| v.clone());
-        match evt.key().as_str() {
+        // Horizontal: Left/Right move between triggers, Down opens the panel.
+        // Vertical: Up/Down move between triggers, Right opens the panel.
+        let (next_key, previous_key, open_key) = match ctx.orientation {
+            Orientation::Horizontal => ("ArrowRight", "ArrowLeft", "ArrowDown"),
+            Orientation::Vertical => ("ArrowDown", "ArrowUp", "ArrowRight"),
+        };
+        let key = evt.key();
+        match key.as_str() {
             "Enter" | " " => {
                 evt.prevent_default();
                 if ctx.is_open(&value) {
@@ -313,7 +355,7 @@ fn TriggerEvents(children: Children) -> impl IntoView {
                     ctx.open(value);
                 }
             }
-            "ArrowRight" => {
+            k if k == next_key => {
                 evt.prevent_default();
                 if let Some(next) = ctx.navigate_next_item() {
                     next.focus();
@@ -323,7 +365,9 @@ fn TriggerEvents(children: Children) -> impl IntoView {
                     }
                 }
             }
-            "ArrowLeft" | "ArrowUp" => {
+            k if k == previous_key
+                || (k == "ArrowUp" && ctx.orientation == Orientation::Horizontal) =>
+            {
                 evt.prevent_default();
                 if let Some(prev) = ctx.navigate_previous_item() {
                     prev.focus();
@@ -353,7 +397,7 @@ fn TriggerEvents(children: Children) -> impl IntoView {
                     }
                 }
             }
-            "ArrowDown" => {
+            k if k == open_key => {
                 evt.prevent_default();
                 // Open the panel and focus its first focusable element
                 if item_ctx.has_content.get_untracked() {
@@ -412,6 +456,11 @@ fn focus_first_in_content(container: &web_sys::HtmlElement) {
     let _ = container.focus();
 }
 
+/// The panel shown while its `Item` is open.
+///
+/// Positioned next to its trigger by default. When the menu has a [`Viewport`],
+/// the panel is rendered inside the viewport instead, so that switching items
+/// morphs one shared surface rather than closing one panel and opening another.
 #[component]
 pub fn Content(
     children: ChildrenFn,
@@ -429,7 +478,25 @@ pub fn Content(
     });
 
     // Mark descendants so top-level Links don't mistakenly register as nav items.
-    provide_context(InsideNavContent);
+    provide_context(InsideNavContent(ctx.root_id));
+
+    let show_class = cn!(class, show_class);
+    let hide_class = cn!(class, hide_class);
+
+    // Hand the panel to the viewport, which renders it while one is mounted.
+    ctx.panels.update(|panels| {
+        panels.push(ViewportPanel {
+            item: item_ctx,
+            children: children.clone(),
+            show_class: show_class.clone(),
+            hide_class: hide_class.clone(),
+        });
+    });
+    on_cleanup(move || {
+        let _ = ctx
+            .panels
+            .try_update(|panels| panels.retain(|p| p.item.index != item_ctx.index));
+    });
 
     let value = StoredValue::new(item_ctx.value.with_value(|v| v.clone()));
     let is_open = Signal::derive(move || {
@@ -521,6 +588,94 @@ pub fn Content(
         eff.calculate_position_style_simple(t_top, t_left, t_width, t_height, ch, cw, 0.0)
     });
 
+    let show_class = StoredValue::new(show_class);
+    let hide_class = StoredValue::new(hide_class);
+
+    move || {
+        if ctx.has_viewport.get() {
+            return None;
+        }
+        let children = children.clone();
+        Some(view! {
+            <CustomAnimatedShow
+                when={is_open}
+                show_class={show_class.get_value()}
+                hide_class={hide_class.get_value()}
+                hide_delay={ctx.hide_delay}
+                style_signal={style_signal}
+                node_ref={item_ctx.content_ref}
+                attr:id={item_ctx.content_id.get_value()}
+                attr:role="region"
+                attr:aria-labelledby={item_ctx.trigger_id.get_value()}
+                attr:tabindex="-1"
+                attr:data-state={move || if is_open.get() { "open" } else { "closed" }}
+                attr:data-motion={move || ctx.motion(item_ctx.index).map(|m| m.as_str())}
+            >
+                {children()}
+            </CustomAnimatedShow>
+        })
+    }
+}
+
+/// Shared surface that hosts the open item's `Content`.
+///
+/// Render it once inside `Root`, usually right after `List`. Each `Content` is
+/// then rendered inside the viewport instead of next to its trigger, and the
+/// viewport exposes the open panel's size as
+/// `--biji-navigation-menu-viewport-width` and
+/// `--biji-navigation-menu-viewport-height`, so its own size can be
+/// transitioned while panels switch. Outgoing and incoming panels are both
+/// mounted for `hide_delay` and carry `data-motion`. The viewport is not
+/// positioned by the component; place it with CSS, e.g. `absolute top-full`
+/// inside a `relative` root.
+#[component]
+pub fn Viewport(
+    #[prop(into, optional)] class: String,
+    #[prop(into, optional)] show_class: String,
+    #[prop(into, optional)] hide_class: String,
+) -> impl IntoView {
+    let ctx = expect_context::<NavMenuContext>();
+
+    ctx.has_viewport.set(true);
+    on_cleanup(move || {
+        let _ = ctx.has_viewport.try_set(false);
+    });
+
+    let is_open = Signal::derive(move || ctx.any_open());
+
+    let _ = use_event_listener(ctx.viewport_ref, pointerenter, move |_| {
+        ctx.cancel_close_timer();
+    });
+    let _ = use_event_listener(ctx.viewport_ref, pointerleave, move |_| {
+        ctx.schedule_close();
+    });
+
+    let active_panel = Signal::derive_local(move || {
+        let index = ctx.open_value.get().and_then(|v| ctx.index_of(&v))?;
+        ctx.items
+            .with(|items| items.get(&index).map(|item| item.content_ref))?
+            .get()
+    });
+
+    let UseElementBoundingReturn { width, height, .. } = use_element_bounding(active_panel);
+
+    // Keep the last measured size while the viewport animates out.
+    let size = StoredValue::new((0.0, 0.0));
+    let style_signal = Signal::derive(move || {
+        let _ = width.read();
+        let _ = height.read();
+        if let Some(panel) = active_panel.get() {
+            let (w, h) = (panel.offset_width() as f64, panel.offset_height() as f64);
+            if w > 0.0 || h > 0.0 {
+                size.set_value((w, h));
+            }
+        }
+        let (w, h) = size.get_value();
+        format!(
+            "--biji-navigation-menu-viewport-width: {w}px; --biji-navigation-menu-viewport-height: {h}px;"
+        )
+    });
+
     view! {
         <CustomAnimatedShow
             when={is_open}
@@ -528,21 +683,179 @@ pub fn Content(
             hide_class={cn!(class, hide_class)}
             hide_delay={ctx.hide_delay}
             style_signal={style_signal}
-            node_ref={item_ctx.content_ref}
-            attr:id={item_ctx.content_id.get_value()}
+            node_ref={ctx.viewport_ref}
+            attr:data-state={move || if is_open.get() { "open" } else { "closed" }}
+        >
+            <For
+                each={move || ctx.panels.get()}
+                key={|panel| panel.item.index}
+                children={|panel| view! { <Panel panel={panel} /> }}
+            />
+        </CustomAnimatedShow>
+    }
+}
+
+/// A `Content` rendered inside the `Viewport`.
+#[component]
+fn Panel(panel: ViewportPanel) -> impl IntoView {
+    let ctx = expect_context::<NavMenuContext>();
+    let ViewportPanel {
+        item,
+        children,
+        show_class,
+        hide_class,
+    } = panel;
+
+    // The panel's children see the same contexts as they would inside `Content`.
+    provide_context(item);
+    provide_context(InsideNavContent(ctx.root_id));
+
+    let is_open = Signal::derive(move || item.value.with_value(|v| ctx.is_open(v)));
+
+    view! {
+        <CustomAnimatedShow
+            when={is_open}
+            show_class={show_class}
+            hide_class={hide_class}
+            hide_delay={ctx.hide_delay}
+            node_ref={item.content_ref}
+            attr:id={item.content_id.get_value()}
             attr:role="region"
-            attr:aria-labelledby={item_ctx.trigger_id.get_value()}
+            attr:aria-labelledby={item.trigger_id.get_value()}
             attr:tabindex="-1"
+            attr:data-state={move || if is_open.get() { "open" } else { "closed" }}
+            attr:data-motion={move || ctx.motion(item.index).map(|m| m.as_str())}
         >
             {children()}
         </CustomAnimatedShow>
     }
 }
 
+/// Highlight that follows the open item's trigger.
+///
+/// Render it inside `List`, which should be positioned (e.g. `relative`). The
+/// indicator is absolutely positioned along the active trigger with its width
+/// and horizontal offset (height and vertical offset in a vertical menu), and
+/// keeps the last position while hidden so that it can fade out in place.
+#[component]
+pub fn Indicator(
+    #[prop(optional)] children: Option<Children>,
+    #[prop(into, optional)] class: String,
+    #[prop(into, optional)] show_class: String,
+    #[prop(into, optional)] hide_class: String,
+) -> impl IntoView {
+    let ctx = expect_context::<NavMenuContext>();
+
+    let active = Memo::new(move |prev: Option<&Option<usize>>| {
+        ctx.open_value
+            .get()
+            .and_then(|v| ctx.index_of(&v))
+            .or_else(|| prev.copied().flatten())
+    });
+
+    let trigger = Signal::derive_local(move || {
+        let index = active.get()?;
+        ctx.items
+            .with(|items| items.get(&index).map(|item| item.trigger_ref))?
+            .get()
+    });
+
+    let UseElementBoundingReturn {
+        top,
+        left,
+        width,
+        height,
+        ..
+    } = use_element_bounding(trigger);
+
+    let style = move || {
+        let _ = top.read();
+        let _ = left.read();
+        let _ = width.read();
+        let _ = height.read();
+        let trigger = trigger.get()?;
+        Some(match ctx.orientation {
+            Orientation::Horizontal => format!(
+                "position: absolute; left: 0; width: {}px; transform: translateX({}px);",
+                trigger.offset_width(),
+                trigger.offset_left()
+            ),
+            Orientation::Vertical => format!(
+                "position: absolute; top: 0; height: {}px; transform: translateY({}px);",
+                trigger.offset_height(),
+                trigger.offset_top()
+            ),
+        })
+    };
+
+    let visible = move || ctx.any_open();
+    let show_class = cn!(class, show_class);
+    let hide_class = cn!(class, hide_class);
+
+    view! {
+        <li
+            role="presentation"
+            aria-hidden="true"
+            class={move || if visible() { show_class.clone() } else { hide_class.clone() }}
+            style={style}
+            data-state={move || if visible() { "visible" } else { "hidden" }}
+            data-orientation={ctx.orientation.as_str()}
+        >
+            {children.map(|children| children())}
+        </li>
+    }
+}
+
+/// A navigation menu nested inside a `Content` panel.
+///
+/// Takes its own `List`, `Item`, `Trigger` and `Content` parts and keeps its own
+/// open item and arrow-key scope. Escape closes the innermost open menu first,
+/// and a `Link` inside a sub menu closes every menu it is nested in.
+#[component]
+pub fn Sub(
+    children: Children,
+    #[prop(into, optional)] class: String,
+    #[prop(default = Orientation::Horizontal)] orientation: Orientation,
+    /// Where the sub menu's panels open. Defaults by `orientation`, as for `Root`.
+    #[prop(optional)]
+    positioning: Option<Positioning>,
+) -> impl IntoView {
+    let parent = expect_context::<NavMenuContext>();
+
+    let ctx = new_context(
+        orientation,
+        positioning,
+        parent.avoid_collisions,
+        parent.close_delay,
+        parent.hide_delay,
+        Some(parent),
+    );
+
+    parent.subs.update(|subs| subs.push(ctx));
+    on_cleanup(move || {
+        let _ = parent
+            .subs
+            .try_update(|subs| subs.retain(|sub| sub.root_id != ctx.root_id));
+    });
+
+    view! {
+        <Provider value={ctx}>
+            <div class={class} data-orientation={orientation.as_str()}>
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
 /// A plain navigation link.  Can appear directly inside `Item` (for items
 /// without a content panel) or nested inside `Content` (for links within a
 /// panel).  When `close_on_click` is `true` (the default), clicking the link
 /// closes any open content panel.
+///
+/// With the `router` feature, the link must be rendered inside a
+/// `leptos_router` `<Router>`. It navigates client-side, and while the current
+/// path matches `href` according to `active_match` it gets `data-active` and
+/// `aria-current="page"`.
 #[component]
 pub fn Link(
     children: Children,
@@ -550,10 +863,15 @@ pub fn Link(
     #[prop(into, optional)] class: String,
     #[prop(default = false)] disabled: bool,
     #[prop(default = true)] close_on_click: bool,
+    /// How `href` is compared with the current path. Requires the `router` feature.
+    #[prop(default = ActiveMatch::Exact)]
+    active_match: ActiveMatch,
 ) -> impl IntoView {
     let ctx = use_context::<NavMenuContext>();
     let item_ctx = use_context::<NavMenuItemContext>();
-    let in_content = use_context::<InsideNavContent>().is_some();
+    let in_content = use_context::<InsideNavContent>()
+        .zip(ctx)
+        .is_some_and(|(InsideNavContent(id), ctx)| id == ctx.root_id);
 
     // When this link is a top-level nav item (directly inside `Item`, not inside
     // `Content`), attach it to the item's link_ref so Arrow-key navigation can
@@ -574,14 +892,32 @@ pub fn Link(
         }
     }
 
+    let href = StoredValue::new(href);
+
+    #[cfg(feature = "router")]
+    let (is_active, navigate) = {
+        let pathname = leptos_router::hooks::use_location().pathname;
+        let is_active = Signal::derive(move || {
+            href.with_value(|href| pathname.with(|path| active_match.matches(href, path)))
+        });
+        (is_active, leptos_router::hooks::use_navigate())
+    };
+    #[cfg(not(feature = "router"))]
+    let is_active = {
+        let _ = active_match;
+        Signal::stored(false)
+    };
+
     view! {
         <a
             node_ref={link_ref}
-            href={href}
+            href={href.get_value()}
             class={class}
             tabindex={if disabled { "-1" } else { "0" }}
             aria-disabled={if disabled { Some("true") } else { None }}
+            aria-current={move || is_active.get().then_some("page")}
             data-disabled={disabled}
+            data-active={move || is_active.get()}
             on:click={move |evt| {
                 if disabled {
                     evt.prevent_default();
@@ -590,12 +926,27 @@ pub fn Link(
                 }
                 if close_on_click {
                     if let Some(ctx) = ctx {
-                        ctx.close_immediate();
+                        ctx.close_all();
                     }
                 }
+                #[cfg(feature = "router")]
+                href.with_value(|href| {
+                    if is_client_navigation(&evt, href) {
+                        evt.prevent_default();
+                        navigate(href, Default::default());
+                    }
+                });
             }}
         >
             {children()}
         </a>
     }
 }
+
+/// `false` for clicks the browser should handle itself: modified clicks
+/// (new tab or window, download) and links leaving the app.
+#[cfg(feature = "router")]
+fn is_client_navigation(evt: &web_sys::MouseEvent, href: &str) -> bool {
+    let modified = evt.ctrl_key() || evt.meta_key() || evt.shift_key() || evt.alt_key();
+    evt.button() == 0 && !modified && href.starts_with('/') && !href.starts_with("//")
+}
@@ -0,0 +1,136 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use leptos::{html::Input, prelude::*};
+
+use crate::utils::stepping::snap_to_step;
+
+use super::format::NumberFormat;
+
+static NUMBER_FIELD_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn next_number_field_id() -> String {
+    let id = NUMBER_FIELD_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("biji-number-field-{id}")
+}
+
+/// Steps moved by PageUp / PageDown.
+pub(crate) const LARGE_STEP: f64 = 10.0;
+
+/// How long Increment / Decrement must be held before the value starts
+/// repeating.
+pub(crate) const SPIN_START_DELAY: Duration = Duration::from_millis(400);
+
+/// Fastest repeat interval while Increment / Decrement is held.
+pub(crate) const SPIN_MIN_INTERVAL: Duration = Duration::from_millis(40);
+
+/// Interval before the next repeat: each one comes a little sooner than the
+/// last, down to [`SPIN_MIN_INTERVAL`].
+pub(crate) fn next_spin_interval(interval: Duration) -> Duration {
+    interval.mul_f64(0.8).max(SPIN_MIN_INTERVAL)
+}
+
+#[derive(Copy, Clone)]
+pub struct NumberFieldContext {
+    pub value: RwSignal<Option<f64>>,
+    /// Text in the input: the formatted value, or what the user is typing.
+    pub input_text: RwSignal<String>,
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+    pub disabled: bool,
+    /// Whether the mouse wheel changes the value while the input is focused.
+    pub allow_wheel_scrub: bool,
+    pub format: StoredValue<NumberFormat>,
+    pub input_ref: NodeRef<Input>,
+    pub input_id: StoredValue<String>,
+    pub(crate) on_value_change: Option<Callback<Option<f64>>>,
+}
+
+impl NumberFieldContext {
+    pub fn format_value(&self, value: Option<f64>) -> String {
+        value
+            .map(|v| self.format.with_value(|f| f.format(v)))
+            .unwrap_or_default()
+    }
+
+    /// Sets the value, snapped to the step and clamped to `min..=max`, and
+    /// shows it formatted in the input.
+    pub fn set_value(&self, value: Option<f64>) {
+        let value = value.map(|v| snap_to_step(v, self.min, self.max, self.step));
+        self.input_text.set(self.format_value(value));
+        if self.value.get_untracked() != value {
+            self.value.set(value);
+            if let Some(cb) = self.on_value_change {
+                cb.run(value);
+            }
+        }
+    }
+
+    /// Moves the value by `steps` steps from the text in the input, or from
+    /// the current value when that text cannot be read. An empty field starts
+    /// from `min` when bounded, or zero.
+    pub fn increment(&self, steps: f64) {
+        if self.disabled {
+            return;
+        }
+        let start = if self.min.is_finite() {
+            self.min
+        } else {
+            0.0_f64.min(self.max)
+        };
+        let typed = self
+            .input_text
+            .with_untracked(|text| self.format.with_value(|f| f.parse(text)));
+        let current = typed.or(self.value.get_untracked()).unwrap_or(start);
+        let step = if self.step.is_finite() && self.step > 0.0 {
+            self.step
+        } else {
+            1.0
+        };
+        self.set_value(Some(current + step * steps));
+    }
+
+    /// Parses the typed text and sets the value from it. Unreadable text is
+    /// replaced by the current value.
+    pub fn commit_input(&self) {
+        let text = self.input_text.get_untracked();
+        if text.trim().is_empty() {
+            self.set_value(None);
+            return;
+        }
+        match self.format.with_value(|f| f.parse(&text)) {
+            Some(value) => self.set_value(Some(value)),
+            None => self.set_value(self.value.get_untracked()),
+        }
+    }
+
+    pub fn can_increment(&self) -> bool {
+        !self.disabled && self.value.get().is_none_or(|v| v < self.max)
+    }
+
+    pub fn can_decrement(&self) -> bool {
+        !self.disabled && self.value.get().is_none_or(|v| v > self.min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spin_accelerates_to_minimum() {
+        let mut interval = SPIN_START_DELAY;
+        let mut steps = 0;
+        while interval > SPIN_MIN_INTERVAL {
+            let next = next_spin_interval(interval);
+            assert!(next < interval);
+            interval = next;
+            steps += 1;
+        }
+        assert_eq!(interval, SPIN_MIN_INTERVAL);
+        assert!(steps > 5);
+    }
+}
@@ -0,0 +1,237 @@
+//! Locale-aware number formatting and parsing for [`Input`](super::Input).
+//!
+//! Formatting is done in Rust rather than through `Intl.NumberFormat`, so the
+//! text rendered on the server matches what the client hydrates.
+
+/// How a value is presented.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum NumberStyle {
+    #[default]
+    Decimal,
+    /// The value is a fraction: `0.25` is shown as `25%`.
+    Percent,
+    /// The value is an amount in a currency shown with `symbol`.
+    Currency {
+        symbol: String,
+        /// Whether the symbol comes before the number (`$1.00`) or after it
+        /// (`1,00 €`).
+        symbol_first: bool,
+    },
+}
+
+/// Separators, style and precision used to show and read the value.
+///
+/// Start from [`NumberFormat::locale`] or the en-US default and adjust with the
+/// builder methods:
+///
+/// ```rust,ignore
+/// let price = NumberFormat::locale("de-DE").currency("€").fraction_digits(2, 2);
+/// assert_eq!(price.format(1234.5), "1.234,50\u{a0}€");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberFormat {
+    pub decimal_separator: char,
+    /// Separator between groups of thousands; `None` disables grouping.
+    pub group_separator: Option<char>,
+    pub style: NumberStyle,
+    pub min_fraction_digits: usize,
+    pub max_fraction_digits: usize,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            group_separator: Some(','),
+            style: NumberStyle::Decimal,
+            min_fraction_digits: 0,
+            max_fraction_digits: 3,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Separators for a BCP 47 language tag such as `"de-DE"` or `"fr"`.
+    /// Unknown locales use en-US separators.
+    pub fn locale(tag: &str) -> Self {
+        let tag = tag.to_ascii_lowercase();
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        let (decimal_separator, group_separator) = match (language, tag.as_str()) {
+            (_, "de-ch" | "it-ch" | "fr-ch") => ('.', '\u{2019}'),
+            ("de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el", _) => (',', '.'),
+            ("fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "nb" | "no" | "fi" | "uk" | "hu", _) => {
+                (',', '\u{a0}')
+            }
+            _ => ('.', ','),
+        };
+        Self {
+            decimal_separator,
+            group_separator: Some(group_separator),
+            ..Self::default()
+        }
+    }
+
+    pub fn grouping(mut self, group_separator: Option<char>) -> Self {
+        self.group_separator = group_separator;
+        self
+    }
+
+    pub fn fraction_digits(mut self, min: usize, max: usize) -> Self {
+        self.min_fraction_digits = min;
+        self.max_fraction_digits = max.max(min);
+        self
+    }
+
+    pub fn percent(mut self) -> Self {
+        self.style = NumberStyle::Percent;
+        self
+    }
+
+    /// Shows `symbol` before the number for locales with a `.` decimal
+    /// separator and after it otherwise.
+    pub fn currency(mut self, symbol: impl Into<String>) -> Self {
+        self.style = NumberStyle::Currency {
+            symbol: symbol.into(),
+            symbol_first: self.decimal_separator == '.',
+        };
+        self
+    }
+
+    /// Step used when none is given: one displayed unit, so `0.01` (one
+    /// percent) for the percent style and `1.0` otherwise.
+    pub fn default_step(&self) -> f64 {
+        match self.style {
+            NumberStyle::Percent => 0.01,
+            _ => 1.0,
+        }
+    }
+
+    /// `true` when values may have a fractional part.
+    pub fn allows_fraction(&self) -> bool {
+        self.max_fraction_digits > 0
+    }
+
+    pub fn format(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return String::new();
+        }
+        let scaled = match self.style {
+            NumberStyle::Percent => value * 100.0,
+            _ => value,
+        };
+        let fixed = format!("{:.*}", self.max_fraction_digits, scaled.abs());
+        let (int, fraction) = fixed.split_once('.').unwrap_or((&fixed, ""));
+        let keep = fraction
+            .trim_end_matches('0')
+            .len()
+            .max(self.min_fraction_digits);
+        let fraction = &fraction[..keep.min(fraction.len())];
+        let negative = scaled < 0.0 && (int.bytes().any(|b| b != b'0') || !fraction.is_empty());
+
+        let mut number = group_digits(int, self.group_separator);
+        if !fraction.is_empty() {
+            number.push(self.decimal_separator);
+            number.push_str(fraction);
+        }
+        let sign = if negative { "-" } else { "" };
+        match &self.style {
+            NumberStyle::Decimal => format!("{sign}{number}"),
+            NumberStyle::Percent => format!("{sign}{number}%"),
+            NumberStyle::Currency {
+                symbol,
+                symbol_first: true,
+            } => format!("{sign}{symbol}{number}"),
+            NumberStyle::Currency { symbol, .. } => format!("{sign}{number}\u{a0}{symbol}"),
+        }
+    }
+
+    /// Reads text typed by the user. Group separators, spaces, the currency
+    /// symbol and the percent sign are optional. `None` for empty or
+    /// unreadable text.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let mut text = text.trim().to_string();
+        if let NumberStyle::Currency { symbol, .. } = &self.style {
+            text = text.replace(symbol.as_str(), "");
+        }
+        let mut normalized = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '0'..='9' => normalized.push(c),
+                '-' | '\u{2212}' if normalized.is_empty() => normalized.push('-'),
+                '+' if normalized.is_empty() => {}
+                c if c == self.decimal_separator => normalized.push('.'),
+                c if Some(c) == self.group_separator || c.is_whitespace() || c == '%' => {}
+                _ => return None,
+            }
+        }
+        let value = normalized.parse::<f64>().ok()?;
+        Some(match self.style {
+            NumberStyle::Percent => value / 100.0,
+            _ => value,
+        })
+    }
+}
+
+fn group_digits(int: &str, separator: Option<char>) -> String {
+    let Some(separator) = separator else {
+        return int.to_string();
+    };
+    let mut grouped = String::with_capacity(int.len() + int.len() / 3);
+    for (i, digit) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_by_locale() {
+        assert_eq!(NumberFormat::default().format(1234567.891), "1,234,567.891");
+        assert_eq!(NumberFormat::default().format(-0.0001), "0");
+        assert_eq!(NumberFormat::locale("de-DE").format(-1234.5), "-1.234,5");
+        assert_eq!(NumberFormat::locale("fr").format(1234.5), "1\u{a0}234,5");
+        assert_eq!(
+            NumberFormat::default().grouping(None).format(1234.0),
+            "1234"
+        );
+    }
+
+    #[test]
+    fn formats_percent_and_currency() {
+        assert_eq!(NumberFormat::default().percent().format(0.256), "25.6%");
+        let usd = NumberFormat::default().currency("$").fraction_digits(2, 2);
+        assert_eq!(usd.format(1234.5), "$1,234.50");
+        assert_eq!(usd.format(-3.0), "-$3.00");
+        let eur = NumberFormat::locale("de-DE")
+            .currency("€")
+            .fraction_digits(2, 2);
+        assert_eq!(eur.format(1234.5), "1.234,50\u{a0}€");
+    }
+
+    #[test]
+    fn default_step_is_one_displayed_unit() {
+        assert_eq!(NumberFormat::default().default_step(), 1.0);
+        assert_eq!(NumberFormat::default().percent().default_step(), 0.01);
+        assert_eq!(NumberFormat::default().currency("$").default_step(), 1.0);
+    }
+
+    #[test]
+    fn parses_typed_text() {
+        let en = NumberFormat::default();
+        assert_eq!(en.parse(" 1,234.5 "), Some(1234.5));
+        assert_eq!(en.parse("-12"), Some(-12.0));
+        assert_eq!(en.parse(""), None);
+        assert_eq!(en.parse("12a"), None);
+        assert_eq!(en.parse("1-2"), None);
+        assert_eq!(NumberFormat::locale("de").parse("1.234,5"), Some(1234.5));
+        assert_eq!(en.clone().percent().parse("25%"), Some(0.25));
+        let eur = NumberFormat::locale("de-DE").currency("€");
+        assert_eq!(eur.parse("1.234,50 €"), Some(1234.5));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod context;
+pub mod format;
+pub mod root;
+
+pub use format::{NumberFormat, NumberStyle};
+pub use root::{Decrement, Increment, Input, Root};
@@ -0,0 +1,261 @@
+use std::time::Duration;
+
+use leptos::{
+    context::Provider,
+    ev::{blur, click, keydown, pointercancel, pointerdown, pointerleave, pointerup, wheel},
+    html::Button,
+    leptos_dom::{self, helpers::TimeoutHandle},
+    prelude::*,
+};
+use leptos_use::{UseEventListenerOptions, use_event_listener, use_event_listener_with_options};
+
+use super::{
+    context::{
+        LARGE_STEP, NumberFieldContext, SPIN_START_DELAY, next_number_field_id, next_spin_interval,
+    },
+    format::NumberFormat,
+};
+
+#[component]
+pub fn Root(
+    children: Children,
+    #[prop(into, optional)] class: String,
+    /// Initial value; the field starts empty without one.
+    #[prop(optional)]
+    value: Option<f64>,
+    #[prop(default = f64::NEG_INFINITY)] min: f64,
+    #[prop(default = f64::INFINITY)] max: f64,
+    /// Step increment; defaults to one displayed unit, `0.01` for percent
+    /// formats and `1.0` otherwise.
+    #[prop(optional)]
+    step: Option<f64>,
+    #[prop(default = false)] disabled: bool,
+    /// Lets the mouse wheel change the value while the input is focused.
+    #[prop(default = false)]
+    allow_wheel_scrub: bool,
+    /// Separators, style and precision used to show and read the value.
+    #[prop(optional)]
+    format: NumberFormat,
+    /// Called with the new value, or `None` when the field is cleared.
+    #[prop(optional)]
+    on_value_change: Option<Callback<Option<f64>>>,
+) -> impl IntoView {
+    let (min, max) = if min <= max { (min, max) } else { (max, min) };
+    let step = step.unwrap_or_else(|| format.default_step());
+    let value = value.map(|v| v.clamp(min, max));
+    let input_text = value.map(|v| format.format(v)).unwrap_or_default();
+    let ctx = NumberFieldContext {
+        value: RwSignal::new(value),
+        input_text: RwSignal::new(input_text),
+        min,
+        max,
+        step,
+        disabled,
+        allow_wheel_scrub,
+        format: StoredValue::new(format),
+        input_ref: NodeRef::new(),
+        input_id: StoredValue::new(next_number_field_id()),
+        on_value_change,
+    };
+
+    view! {
+        <Provider value={ctx}>
+            <div data-disabled={if disabled { Some("true") } else { None }} class={class}>
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// The text input, with `role="spinbutton"`. Typed text is parsed with the
+/// root's `format` on Enter and on blur.
+#[component]
+pub fn Input(#[prop(into, optional)] class: String) -> impl IntoView {
+    let ctx = expect_context::<NumberFieldContext>();
+    let input_ref = ctx.input_ref;
+
+    let _ = use_event_listener(input_ref, leptos::ev::input, move |evt| {
+        ctx.input_text.set(event_target_value(&evt));
+    });
+
+    let _ = use_event_listener(input_ref, keydown, move |evt| {
+        if ctx.disabled {
+            return;
+        }
+        match evt.key().as_str() {
+            "ArrowUp" => ctx.increment(1.0),
+            "ArrowDown" => ctx.increment(-1.0),
+            "PageUp" => ctx.increment(LARGE_STEP),
+            "PageDown" => ctx.increment(-LARGE_STEP),
+            "Home" if ctx.min.is_finite() => ctx.set_value(Some(ctx.min)),
+            "End" if ctx.max.is_finite() => ctx.set_value(Some(ctx.max)),
+            "Enter" => ctx.commit_input(),
+            _ => return,
+        }
+        evt.prevent_default();
+    });
+
+    let _ = use_event_listener(input_ref, blur, move |_| ctx.commit_input());
+
+    // Passive listeners cannot stop the page from scrolling.
+    let _ = use_event_listener_with_options(
+        input_ref,
+        wheel,
+        move |evt| {
+            let focused = input_ref.get_untracked().is_some_and(|el| {
+                let el: &web_sys::Element = el.as_ref();
+                document().active_element().as_ref() == Some(el)
+            });
+            if !ctx.allow_wheel_scrub || ctx.disabled || !focused || evt.delta_y() == 0.0 {
+                return;
+            }
+            evt.prevent_default();
+            ctx.increment(if evt.delta_y() < 0.0 { 1.0 } else { -1.0 });
+        },
+        UseEventListenerOptions::default().passive(false),
+    );
+
+    let allows_fraction = ctx.format.with_value(NumberFormat::allows_fraction);
+    // Numeric keypads on phones often lack a minus key.
+    let input_mode = match (ctx.min < 0.0, allows_fraction) {
+        (true, _) => "text",
+        (false, true) => "decimal",
+        (false, false) => "numeric",
+    };
+    let bound = |v: f64| v.is_finite().then(|| v.to_string());
+
+    view! {
+        <input
+            node_ref={input_ref}
+            id={ctx.input_id.get_value()}
+            type="text"
+            role="spinbutton"
+            inputmode={input_mode}
+            autocomplete="off"
+            spellcheck="false"
+            prop:value={move || ctx.input_text.get()}
+            aria-valuenow={move || ctx.value.get().map(|v| v.to_string())}
+            aria-valuetext={move || ctx.value.get().map(|v| ctx.format_value(Some(v)))}
+            aria-valuemin={bound(ctx.min)}
+            aria-valuemax={bound(ctx.max)}
+            disabled={ctx.disabled}
+            data-disabled={if ctx.disabled { Some("true") } else { None }}
+            class={class}
+        />
+    }
+}
+
+/// Increases the value by one step; holding it down repeats, faster the longer
+/// it is held.
+#[component]
+pub fn Increment(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
+    let ctx = expect_context::<NumberFieldContext>();
+    let button_ref = NodeRef::new();
+    use_spin_button(button_ref, 1.0);
+
+    view! {
+        <button
+            node_ref={button_ref}
+            type="button"
+            tabindex="-1"
+            aria-label="Increase"
+            aria-controls={ctx.input_id.get_value()}
+            disabled={move || !ctx.can_increment()}
+            data-disabled={move || (!ctx.can_increment()).then_some("true")}
+            class={class}
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// Decreases the value by one step; holding it down repeats, faster the longer
+/// it is held.
+#[component]
+pub fn Decrement(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
+    let ctx = expect_context::<NumberFieldContext>();
+    let button_ref = NodeRef::new();
+    use_spin_button(button_ref, -1.0);
+
+    view! {
+        <button
+            node_ref={button_ref}
+            type="button"
+            tabindex="-1"
+            aria-label="Decrease"
+            aria-controls={ctx.input_id.get_value()}
+            disabled={move || !ctx.can_decrement()}
+            data-disabled={move || (!ctx.can_decrement()).then_some("true")}
+            class={class}
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// Steps the value by `steps` on press, then repeatedly while the button is
+/// held.
+fn use_spin_button(button_ref: NodeRef<Button>, steps: f64) {
+    let ctx = expect_context::<NumberFieldContext>();
+    let timer = StoredValue::new(None::<TimeoutHandle>);
+
+    let stop = move || {
+        if let Some(h) = timer.try_update_value(|t| t.take()).flatten() {
+            h.clear();
+        }
+    };
+
+    let _ = use_event_listener(button_ref, pointerdown, move |evt| {
+        if evt.button() != 0 || ctx.disabled {
+            return;
+        }
+        // Keep focus in the input, where the keyboard keeps working.
+        evt.prevent_default();
+        if let Some(input) = ctx.input_ref.get_untracked() {
+            let _ = input.focus();
+        }
+        stop();
+        ctx.increment(steps);
+        schedule_spin(ctx, timer, steps, SPIN_START_DELAY);
+    });
+    let _ = use_event_listener(button_ref, pointerup, move |_| stop());
+    let _ = use_event_listener(button_ref, pointerleave, move |_| stop());
+    let _ = use_event_listener(button_ref, pointercancel, move |_| stop());
+
+    // Keyboard and assistive technology activation, which has no pointer press.
+    let _ = use_event_listener(button_ref, click, move |evt| {
+        if evt.detail() == 0 {
+            ctx.increment(steps);
+        }
+    });
+
+    on_cleanup(stop);
+}
+
+fn schedule_spin(
+    ctx: NumberFieldContext,
+    timer: StoredValue<Option<TimeoutHandle>>,
+    steps: f64,
+    delay: Duration,
+) {
+    let handle = leptos_dom::helpers::set_timeout_with_handle(
+        move || {
+            let can_continue = untrack(|| {
+                if steps > 0.0 {
+                    ctx.can_increment()
+                } else {
+                    ctx.can_decrement()
+                }
+            });
+            if !can_continue {
+                timer.try_set_value(None);
+                return;
+            }
+            ctx.increment(steps);
+            let next = next_spin_interval(delay.min(SPIN_START_DELAY / 2));
+            schedule_spin(ctx, timer, steps, next);
+        },
+        delay,
+    );
+    timer.set_value(handle.ok());
+}
@@ -9,6 +9,48 @@ pub(crate) fn next_pin_input_id() -> String {
     format!("biji-pin-{id}")
 }
 
+/// Which characters a cell accepts.
+#[derive(Copy, Clone, Default)]
+pub enum PinType {
+    /// ASCII digits only.
+    #[default]
+    Numeric,
+    /// ASCII letters and digits, normalized to uppercase.
+    Alphanumeric,
+    /// Characters the validator returns `true` for.
+    Custom(Callback<char, bool>),
+}
+
+impl PinType {
+    /// The character as stored in a cell, or `None` when it is rejected.
+    pub fn normalize(self, ch: char) -> Option<char> {
+        match self {
+            PinType::Numeric => ch.is_ascii_digit().then_some(ch),
+            PinType::Alphanumeric => ch.is_ascii_alphanumeric().then(|| ch.to_ascii_uppercase()),
+            PinType::Custom(validator) => validator.run(ch).then_some(ch),
+        }
+    }
+
+    /// Normalizes pasted text, skipping whitespace and `-` group separators.
+    /// `None` when any other character is rejected.
+    pub fn normalize_paste(self, text: &str) -> Option<Vec<char>> {
+        text.chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| self.normalize(c))
+            .collect()
+    }
+
+    pub(crate) fn input_mode(self) -> &'static str {
+        match self {
+            PinType::Numeric => "numeric",
+            PinType::Alphanumeric | PinType::Custom(_) => "text",
+        }
+    }
+}
+
+/// Shown in place of each entered character when `mask` is set.
+pub(crate) const MASK_CHAR: char = '•';
+
 #[derive(Copy, Clone)]
 pub struct PinInputContext {
     pub values: RwSignal<Vec<String>>,
@@ -20,6 +62,10 @@ pub struct PinInputContext {
     pub root_id: StoredValue<String>,
     pub(crate) on_complete: Option<Callback<String>>,
     pub(crate) on_change: Option<Callback<String>>,
+    pub pin_type: PinType,
+    /// Whether entered characters are displayed as [`MASK_CHAR`].
+    pub mask: bool,
+    pub(crate) on_invalid: Option<Callback<String>>,
 }
 
 impl PinInputContext {
@@ -48,6 +94,24 @@ impl PinInputContext {
         }
     }
 
+    /// What the cell at `index` displays: its character, or the mask.
+    pub fn display_value(&self, index: usize) -> String {
+        let value = self
+            .values
+            .with_untracked(|v| v.get(index).cloned().unwrap_or_default());
+        if self.mask && !value.is_empty() {
+            MASK_CHAR.to_string()
+        } else {
+            value
+        }
+    }
+
+    pub(crate) fn reject(&self, input: String) {
+        if let Some(cb) = self.on_invalid {
+            cb.run(input);
+        }
+    }
+
     pub fn focus_cell(&self, index: usize) {
         self.cell_refs.with_value(|refs| {
             if let Some(nr) = refs.get(index) {
@@ -58,3 +122,25 @@ impl PinInputContext {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_by_type() {
+        assert_eq!(PinType::Numeric.normalize('7'), Some('7'));
+        assert_eq!(PinType::Numeric.normalize('a'), None);
+        assert_eq!(PinType::Alphanumeric.normalize('a'), Some('A'));
+        assert_eq!(PinType::Alphanumeric.normalize('#'), None);
+    }
+
+    #[test]
+    fn normalize_paste_skips_separators() {
+        assert_eq!(
+            PinType::Alphanumeric.normalize_paste("ab12-cd 34"),
+            Some("AB12CD34".chars().collect())
+        );
+        assert_eq!(PinType::Numeric.normalize_paste("12a4"), None);
+    }
+}
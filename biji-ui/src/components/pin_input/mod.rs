@@ -1,4 +1,5 @@
 pub mod context;
 pub mod root;
 
-pub use root::{Cell, Root};
+pub use context::PinType;
+pub use root::{Cell, Root, Separator};
@@ -5,7 +5,7 @@ use leptos::{
 };
 use leptos_use::use_event_listener;
 
-use super::context::{PinInputContext, next_pin_input_id};
+use super::context::{MASK_CHAR, PinInputContext, PinType, next_pin_input_id};
 
 #[component]
 pub fn Root(
@@ -16,6 +16,15 @@ pub fn Root(
     #[prop(into, optional)] placeholder: Option<String>,
     #[prop(optional)] on_complete: Option<Callback<String>>,
     #[prop(optional)] on_change: Option<Callback<String>>,
+    /// Which characters the cells accept.
+    #[prop(optional)]
+    pin_type: PinType,
+    /// Displays entered characters as `•`, like a password field.
+    #[prop(default = false)]
+    mask: bool,
+    /// Called with typed or pasted input that `pin_type` rejects.
+    #[prop(optional)]
+    on_invalid: Option<Callback<String>>,
 ) -> impl IntoView {
     let cell_refs: Vec<NodeRef<leptos::html::Input>> = (0..length).map(|_| NodeRef::new()).collect();
 
@@ -28,6 +37,9 @@ pub fn Root(
         root_id: StoredValue::new(next_pin_input_id()),
         on_complete,
         on_change,
+        pin_type,
+        mask,
+        on_invalid,
     };
 
     view! {
@@ -60,9 +72,9 @@ pub fn Cell(
 
     // Sync DOM value when signal changes (handles external paste updates for non-focused cells)
     let sync_eff = RenderEffect::new(move |_| {
-        let val = ctx.values.with(|v| v.get(index).cloned().unwrap_or_default());
+        ctx.values.track();
         if let Some(el) = cell_ref.get() {
-            el.set_value(&val);
+            el.set_value(&ctx.display_value(index));
         }
     });
     on_cleanup(move || drop(sync_eff));
@@ -70,12 +82,24 @@ pub fn Cell(
     // Character input: keep last char only, advance focus
     let _ = use_event_listener(cell_ref, leptos::ev::input, move |evt| {
         let raw = event_target_value(&evt);
-        let ch = raw.chars().last().map(|c| c.to_string()).unwrap_or_default();
+        let typed = raw.chars().rfind(|c| !(ctx.mask && *c == MASK_CHAR));
+        let ch = match typed.map(|c| (c, ctx.pin_type.normalize(c))) {
+            Some((_, Some(valid))) => valid.to_string(),
+            Some((rejected, None)) => {
+                // Restore what the cell showed before the rejected keystroke
+                if let Some(el) = cell_ref.get_untracked() {
+                    el.set_value(&ctx.display_value(index));
+                }
+                ctx.reject(rejected.to_string());
+                return;
+            }
+            None => String::new(),
+        };
+        ctx.set_cell(index, ch.clone());
         // Sync DOM immediately so the display is correct before signal update
         if let Some(el) = cell_ref.get_untracked() {
-            el.set_value(&ch);
+            el.set_value(&ctx.display_value(index));
         }
-        ctx.set_cell(index, ch.clone());
         if !ch.is_empty() && index + 1 < ctx.length {
             ctx.focus_cell(index + 1);
         }
@@ -116,7 +140,10 @@ pub fn Cell(
             .clipboard_data()
             .and_then(|d| d.get_data("text/plain").ok())
             .unwrap_or_default();
-        let chars: Vec<char> = pasted.chars().collect();
+        let Some(chars) = ctx.pin_type.normalize_paste(&pasted) else {
+            ctx.reject(pasted);
+            return;
+        };
         let mut last_filled = index;
         // Update signal in one batch
         ctx.values.update(|v| {
@@ -131,13 +158,13 @@ pub fn Cell(
         });
         // Sync DOM values for cells that were filled by this paste
         ctx.cell_refs.with_value(|refs| {
-            for (offset, ch) in chars.iter().enumerate() {
+            for offset in 0..chars.len() {
                 let cell_idx = index + offset;
                 if cell_idx >= refs.len() {
                     break;
                 }
                 if let Some(el) = refs[cell_idx].get_untracked() {
-                    el.set_value(&ch.to_string());
+                    el.set_value(&ctx.display_value(cell_idx));
                 }
             }
         });
@@ -170,6 +197,10 @@ pub fn Cell(
 
     let length = ctx.length;
     let cell_id = ctx.root_id.with_value(|id| format!("{id}-{index}"));
+    let (autocomplete, label) = match ctx.pin_type {
+        PinType::Numeric => ("one-time-code", "Digit"),
+        PinType::Alphanumeric | PinType::Custom(_) => ("off", "Character"),
+    };
 
     view! {
         <input
@@ -177,12 +208,13 @@ pub fn Cell(
             id={cell_id.clone()}
             name={cell_id}
             type="text"
-            inputmode="numeric"
+            inputmode={ctx.pin_type.input_mode()}
+            autocapitalize={matches!(ctx.pin_type, PinType::Alphanumeric).then_some("characters")}
             maxlength="1"
             placeholder={placeholder_str}
             disabled={ctx.disabled}
-            autocomplete="one-time-code"
-            aria-label={format!("Digit {} of {}", index + 1, length)}
+            autocomplete={autocomplete}
+            aria-label={format!("{label} {} of {}", index + 1, length)}
             data-index={index.to_string()}
             data-filled={move || if is_filled.get() { Some("true") } else { None }}
             data-disabled={if ctx.disabled { Some("true") } else { None }}
@@ -190,3 +222,16 @@ pub fn Cell(
         />
     }.into_any()
 }
+
+/// A decorative divider between groups of cells, e.g. the dash in a license key.
+#[component]
+pub fn Separator(
+    #[prop(optional)] children: Option<Children>,
+    #[prop(into, optional)] class: String,
+) -> impl IntoView {
+    view! {
+        <div aria-hidden="true" data-separator="" class={class}>
+            {children.map(|c| c())}
+        </div>
+    }
+}
@@ -5,11 +5,17 @@ use leptos::{
     prelude::*,
 };
 
-use crate::utils::positioning::{AvoidCollisions, Positioning};
+use crate::utils::{
+    modal_stack::ModalLayer,
+    positioning::{AvoidCollisions, Positioning, anchor_or_trigger},
+};
 
 #[derive(Copy, Clone)]
 pub struct PopoverContext {
     pub trigger_ref: NodeRef<Button>,
+    /// Set by the `Anchor` part. When rendered, Content is positioned against it
+    /// instead of the trigger.
+    pub anchor_ref: NodeRef<Div>,
     pub content_ref: NodeRef<Div>,
     pub open: RwSignal<bool>,
     pub hide_delay: Duration,
@@ -18,6 +24,12 @@ pub struct PopoverContext {
     pub popover_id: StoredValue<String>,
     pub avoid_collisions: AvoidCollisions,
     pub auto_focus: bool,
+    /// Trap focus, hide the rest of the page from interaction and restore
+    /// focus to the trigger on close.
+    pub modal: bool,
+    /// Lock page scroll while a modal popover is open.
+    pub prevent_scroll: bool,
+    pub layer: ModalLayer,
     pub(crate) on_open_change: Option<Callback<bool>>,
 }
 
@@ -25,6 +37,7 @@ impl Default for PopoverContext {
     fn default() -> Self {
         Self {
             trigger_ref: NodeRef::default(),
+            anchor_ref: NodeRef::default(),
             content_ref: NodeRef::default(),
             open: RwSignal::new(false),
             hide_delay: Duration::from_millis(200),
@@ -33,12 +46,21 @@ impl Default for PopoverContext {
             popover_id: StoredValue::new(String::new()),
             avoid_collisions: AvoidCollisions::Flip,
             auto_focus: true,
+            modal: false,
+            prevent_scroll: true,
+            layer: ModalLayer::new(),
             on_open_change: None,
         }
     }
 }
 
 impl PopoverContext {
+    /// Element Content is positioned against: the `Anchor` part when rendered,
+    /// otherwise the trigger.
+    pub fn anchor_element(&self) -> Option<web_sys::Element> {
+        anchor_or_trigger(self.anchor_ref, self.trigger_ref)
+    }
+
     pub fn open(&self) {
         self.open.set(true);
         if let Some(cb) = self.on_open_change {
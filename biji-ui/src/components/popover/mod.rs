@@ -2,6 +2,7 @@ pub mod context;
 pub mod root;
 
 pub use crate::utils::positioning::{AvoidCollisions, Positioning};
+pub use root::Anchor;
 pub use root::Arrow;
 pub use root::Content;
 pub use root::Root;
@@ -16,7 +16,13 @@ use wasm_bindgen::JsCast;
 use crate::{
     cn,
     custom_animated_show::CustomAnimatedShow,
-    utils::positioning::{AvoidCollisions, Positioning},
+    utils::{
+        focus_trap::{focus_first_element, trap_tab_focus},
+        hide_others::{HiddenOthers, hide_others},
+        modal_stack::use_modal_layer,
+        positioning::{AvoidCollisions, Positioning},
+        prevent_scroll::use_prevent_scroll,
+    },
 };
 
 use super::context::PopoverContext;
@@ -40,6 +46,13 @@ pub fn Root(
     /// When true, focuses the first focusable element inside Content when the popover opens.
     #[prop(default = true)]
     auto_focus: bool,
+    /// When true, traps focus inside Content, makes the rest of the page inert
+    /// and returns focus to the trigger on close.
+    #[prop(default = false)]
+    modal: bool,
+    /// Prevent page scrolling while a modal popover is open. Ignored unless `modal`.
+    #[prop(default = true)]
+    prevent_scroll: bool,
     #[prop(optional)] on_open_change: Option<Callback<bool>>,
 ) -> impl IntoView {
     let ctx = PopoverContext {
@@ -50,6 +63,8 @@ pub fn Root(
         popover_id: StoredValue::new(next_popover_id()),
         avoid_collisions,
         auto_focus,
+        modal,
+        prevent_scroll,
         on_open_change,
         ..PopoverContext::default()
     };
@@ -67,8 +82,22 @@ pub fn Root(
 fn RootEvents(children: Children) -> impl IntoView {
     let ctx = expect_context::<PopoverContext>();
 
+    let scroll_eff = use_prevent_scroll(
+        move || ctx.modal && ctx.prevent_scroll && ctx.open.get(),
+        ctx.hide_delay,
+    );
+    let layer_eff = use_modal_layer(
+        ctx.layer,
+        move || ctx.modal && ctx.open.get(),
+        move || ctx.trigger_ref.get_untracked().map(Into::into),
+    );
+    on_cleanup(move || {
+        drop(scroll_eff);
+        drop(layer_eff);
+    });
+
     let _ = use_event_listener(use_document(), keydown, move |evt| {
-        if evt.key() == "Escape" && ctx.open.get() {
+        if evt.key() == "Escape" && ctx.open.get() && ctx.layer.is_top() {
             ctx.close();
             if let Some(trigger) = ctx.trigger_ref.get() {
                 let _ = trigger.focus();
@@ -77,7 +106,7 @@ fn RootEvents(children: Children) -> impl IntoView {
     });
 
     let _ = on_click_outside(ctx.content_ref, move |evt| {
-        if !ctx.open.get() {
+        if !ctx.open.get() || !ctx.layer.is_top() {
             return;
         }
         let is_trigger_click = evt
@@ -118,6 +147,19 @@ pub fn Trigger(children: Children, #[prop(into, optional)] class: String) -> imp
     }
 }
 
+/// Optional element that Content is positioned against instead of the Trigger,
+/// e.g. a whole input group. The Trigger still toggles the popover.
+#[component]
+pub fn Anchor(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
+    let ctx = expect_context::<PopoverContext>();
+
+    view! {
+        <div node_ref={ctx.anchor_ref} class={class}>
+            {children()}
+        </div>
+    }
+}
+
 #[component]
 pub fn Content(
     children: ChildrenFn,
@@ -140,7 +182,7 @@ pub fn Content(
         width,
         height,
         ..
-    } = use_element_bounding(ctx.trigger_ref);
+    } = use_element_bounding(Signal::derive_local(move || ctx.anchor_element()));
 
     let style_signal = Signal::derive(move || {
         // Use use_element_bounding signals only for reactive dependencies.
@@ -181,17 +223,13 @@ pub fn Content(
         if cw == 0.0 && ch == 0.0 {
             return hidden();
         }
-        // Read the trigger's bounding rect fresh from the DOM.  use_element_bounding
+        // Read the anchor's bounding rect fresh from the DOM.  use_element_bounding
         // can return a stale value from initial hydration when the page layout shifts
         // before the first scroll/resize event fires.
-        let Some(trigger) = ctx.trigger_ref.get_untracked() else {
-            return hidden();
-        };
-        let trigger_node: &web_sys::Node = trigger.as_ref();
-        let Some(trigger_el) = trigger_node.dyn_ref::<web_sys::Element>() else {
+        let Some(anchor) = ctx.anchor_element() else {
             return hidden();
         };
-        let rect = trigger_el.get_bounding_client_rect();
+        let rect = anchor.get_bounding_client_rect();
         let (t_top, t_left, t_width, t_height) =
             (rect.top(), rect.left(), rect.width(), rect.height());
         let vp_w = web_sys::window()
@@ -249,11 +287,40 @@ pub fn Content(
         }
     });
 
+    // Modal: hide everything outside the content while it is open. The
+    // trigger stays reachable so that focus can return to it on close, before
+    // this effect has run again.
+    let hidden: StoredValue<Option<HiddenOthers>, LocalStorage> = StoredValue::new_local(None);
+    Effect::new(move |_| {
+        let el = content_ref.get();
+        let previous = hidden.try_update_value(|h| h.take()).flatten();
+        if let Some(previous) = previous {
+            previous.restore();
+        }
+        if let Some(el) = el.filter(|_| ctx.modal && ctx.open.get()) {
+            let mut keep: Vec<web_sys::Element> = vec![el.into()];
+            keep.extend(ctx.trigger_ref.get_untracked().map(Into::into));
+            hidden.set_value(Some(hide_others(&keep)));
+        }
+    });
+
+    // Modal: Tab / Shift+Tab focus trap.
+    let _ = use_event_listener(content_ref, keydown, move |evt| {
+        if ctx.modal && evt.key() == "Tab" && ctx.layer.is_top() {
+            if let Some(el) = content_ref.get() {
+                trap_tab_focus(&el, evt.shift_key(), &evt);
+            }
+        }
+    });
+
     on_cleanup(move || {
         if let Some(h) = focus_handle_cleanup.lock().unwrap().take() {
             h.clear();
         }
         drop(focus_eff);
+        if let Some(previous) = hidden.try_update_value(|h| h.take()).flatten() {
+            previous.restore();
+        }
     });
 
     view! {
@@ -266,6 +333,7 @@ pub fn Content(
             node_ref={content_ref}
             attr:id={ctx.popover_id.get_value()}
             attr:role="dialog"
+            attr:aria-modal={ctx.modal.then_some("true")}
             attr:tabindex="-1"
         >
             {children()}
@@ -273,23 +341,6 @@ pub fn Content(
     }
 }
 
-fn focus_first_element(container: &web_sys::HtmlElement) {
-    let selector = r#"a[href], button:not([disabled]), textarea:not([disabled]), input:not([disabled]), select:not([disabled]), [tabindex]:not([tabindex="-1"])"#;
-    let Ok(node_list) = container.query_selector_all(selector) else {
-        return;
-    };
-    for i in 0..node_list.length() {
-        if let Some(node) = node_list.get(i) {
-            if let Ok(el) = node.dyn_into::<web_sys::HtmlElement>() {
-                let _ = el.focus();
-                return;
-            }
-        }
-    }
-    // No focusable child — focus the container itself (requires tabindex on it).
-    let _ = container.focus();
-}
-
 #[component]
 pub fn Arrow(#[prop(into, optional)] class: String) -> impl IntoView {
     let ctx = expect_context::<PopoverContext>();
@@ -12,10 +12,11 @@ use wasm_bindgen::JsCast;
 
 use crate::{
     items::{
-        FilterActiveItems, Focus, GetIndex, IsActive, ManageFocus, NavigateItems, filter_active,
-        next_item, previous_item,
+        Collection, FilterActiveItems, Focus, GetIndex, IsActive, ManageFocus, NavigateItems,
+        filter_active, next_item, previous_item,
     },
     utils::positioning::{AvoidCollisions, Positioning},
+    virtualizer::Virtualizer,
 };
 
 #[derive(Copy, Clone)]
@@ -29,11 +30,17 @@ pub struct SelectContext {
     pub selected_label: RwSignal<Option<String>>,
     pub item_focus: RwSignal<Option<usize>>,
     pub items: RwSignal<HashMap<usize, SelectItemContext>>,
+    /// Data-driven items passed to `Root`, known even while the content is closed.
+    pub collection: Option<Collection>,
     pub hide_delay: Duration,
     pub positioning: Positioning,
     pub arrow_size: i32,
     pub select_id: StoredValue<String>,
     pub avoid_collisions: AvoidCollisions,
+    /// Set while a `VirtualItems` part is mounted; navigation then moves by row index.
+    pub virtualizer: StoredValue<Option<Virtualizer>>,
+    /// Row index of a value, passed to `VirtualItems` as `index_of`.
+    pub(crate) virtual_index_of: StoredValue<Option<Callback<String, Option<usize>>>>,
     pub(crate) on_value_change: Option<Callback<String>>,
     pub(crate) next_id: StoredValue<AtomicUsize>,
 }
@@ -48,11 +55,14 @@ impl Default for SelectContext {
             selected_label: RwSignal::new(None),
             item_focus: RwSignal::new(None),
             items: RwSignal::new(HashMap::new()),
+            collection: None,
             hide_delay: Duration::from_millis(200),
             positioning: Positioning::BottomStart,
             arrow_size: 0,
             select_id: StoredValue::new(String::new()),
             avoid_collisions: AvoidCollisions::Flip,
+            virtualizer: StoredValue::new(None),
+            virtual_index_of: StoredValue::new(None),
             on_value_change: None,
             next_id: StoredValue::new(AtomicUsize::new(0)),
         }
@@ -102,6 +112,38 @@ impl SelectContext {
             self.open();
         }
     }
+
+    /// The selected item, or the first enabled one when nothing is selected.
+    /// With a virtualizer the selected row is scrolled into view and focuses
+    /// itself once it renders.
+    pub fn navigate_selected_item(&self) -> Option<SelectItemContext> {
+        let value = self.value.get_untracked();
+        if let Some(v) = self.virtualizer.get_value() {
+            let selected = value
+                .zip(self.virtual_index_of.get_value())
+                .and_then(|(value, index_of)| index_of.run(value))
+                .filter(|&i| i < v.count.get_untracked() && !v.is_disabled(i));
+            return self.resolve_virtual(v, selected.or_else(|| v.first_index()));
+        }
+        let selected = value.and_then(|value| {
+            self.items.with_untracked(|m| {
+                m.values()
+                    .find(|item| item.value.with_value(|iv| *iv == value))
+                    .copied()
+            })
+        });
+        selected.or_else(|| self.filter_active_items().into_iter().next())
+    }
+
+    /// Resolve a virtual row index to its item. When the row is not mounted yet it
+    /// is scrolled into view and marked focused so it focuses itself on render.
+    fn resolve_virtual(&self, v: Virtualizer, target: Option<usize>) -> Option<SelectItemContext> {
+        let item = self.items.with(|m| v.resolve(target, m));
+        if item.is_none() && target.is_some() {
+            self.set_focus(target);
+        }
+        item
+    }
 }
 
 impl FilterActiveItems<SelectItemContext> for SelectContext {
@@ -122,19 +164,31 @@ impl ManageFocus for SelectContext {
 
 impl NavigateItems<SelectItemContext> for SelectContext {
     fn navigate_first_item(&self) -> Option<SelectItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.first_index());
+        }
         self.filter_active_items().into_iter().next()
     }
 
     fn navigate_last_item(&self) -> Option<SelectItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.last_index());
+        }
         self.filter_active_items().into_iter().last()
     }
 
     fn navigate_next_item(&self) -> Option<SelectItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.next_index(self.item_focus.get(), true));
+        }
         let items = self.filter_active_items();
         next_item(items, self.item_focus.get(), true)
     }
 
     fn navigate_previous_item(&self) -> Option<SelectItemContext> {
+        if let Some(v) = self.virtualizer.get_value() {
+            return self.resolve_virtual(v, v.previous_index(self.item_focus.get(), true));
+        }
         let items = self.filter_active_items();
         previous_item(items, self.item_focus.get(), true)
     }
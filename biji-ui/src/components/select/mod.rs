@@ -6,6 +6,10 @@ pub use root::Content;
 pub use root::Item;
 pub use root::ItemIndicator;
 pub use root::ItemText;
+pub use root::Items;
 pub use root::Root;
 pub use root::Trigger;
 pub use root::Value;
+pub use root::VirtualItems;
+pub use crate::items::{Collection, CollectionItem};
+pub use crate::virtualizer::{ItemSize, ScrollAlign, Virtualizer};
@@ -1,4 +1,5 @@
 use std::{
+    marker::PhantomData,
     sync::{
         Arc, Mutex,
         atomic::{AtomicUsize, Ordering},
@@ -21,8 +22,9 @@ use wasm_bindgen::JsCast;
 use crate::{
     cn,
     custom_animated_show::CustomAnimatedShow,
-    items::{FilterActiveItems, Focus, ManageFocus, NavigateItems},
+    items::{Collection, CollectionItem, Focus, ManageFocus, NavigateItems, render_groups},
     utils::positioning::{AvoidCollisions, Positioning},
+    virtualizer::{VirtualList, VirtualRow, Virtualizer},
 };
 
 use super::context::{SelectContext, SelectItemContext};
@@ -43,11 +45,19 @@ pub fn Root(
     #[prop(default = Duration::from_millis(200))] hide_delay: Duration,
     #[prop(default = AvoidCollisions::Flip)] avoid_collisions: AvoidCollisions,
     #[prop(optional)] on_value_change: Option<Callback<String>>,
+    /// Data-driven items, rendered with [`Items`]. Lets the trigger show the
+    /// selected label before the content has ever opened.
+    #[prop(into, optional)]
+    items: Option<Collection>,
 ) -> impl IntoView {
+    let initial_label = value
+        .as_deref()
+        .and_then(|v| items.and_then(|c| untrack(|| c.label_of(v))));
     let ctx = SelectContext {
         open: RwSignal::new(false),
         value: RwSignal::new(value),
-        selected_label: RwSignal::new(None),
+        selected_label: RwSignal::new(initial_label),
+        collection: items,
         hide_delay,
         positioning,
         select_id: StoredValue::new(next_select_id()),
@@ -186,6 +196,10 @@ pub fn Value(#[prop(into, optional)] placeholder: String) -> impl IntoView {
             {move || {
                 ctx.selected_label
                     .get()
+                    .or_else(|| {
+                        let value = ctx.value.get()?;
+                        ctx.collection?.label_of(&value)
+                    })
                     .unwrap_or_else(|| placeholder.clone())
             }}
         </span>
@@ -299,19 +313,7 @@ pub fn Content(
             let h = leptos::leptos_dom::helpers::set_timeout_with_handle(
                 move || {
                     *fh.lock().unwrap() = None;
-                    let current_val = ctx.value.get_untracked();
-                    // Try to find and focus the currently selected item.
-                    let selected = current_val.and_then(|v| {
-                        ctx.items.with_untracked(|m| {
-                            m.values()
-                                .find(|item| item.value.with_value(|iv| *iv == v))
-                                .copied()
-                        })
-                    });
-                    if let Some(item) = selected {
-                        item.focus();
-                        ctx.item_focus.set(Some(item.index));
-                    } else if let Some(item) = ctx.filter_active_items().into_iter().next() {
+                    if let Some(item) = ctx.navigate_selected_item() {
                         item.focus();
                         ctx.item_focus.set(Some(item.index));
                     }
@@ -402,6 +404,107 @@ pub fn Content(
     }
 }
 
+/// Renders the `items` passed to [`Root`], one [`Item`] per entry.
+///
+/// `render` receives each [`CollectionItem`] and returns the item's content.
+/// Entries that share a `group` are wrapped in a `role="group"` element headed
+/// by the group name.
+///
+/// # Example
+/// ```rust,ignore
+/// let fruits = RwSignal::new(vec![
+///     CollectionItem::new("apple", "Apple").group("Fruits"),
+///     CollectionItem::new("carrot", "Carrot").group("Vegetables").disabled(true),
+/// ]);
+/// view! {
+///     <select::Root items={fruits}>
+///         <select::Trigger><select::Value placeholder="Pick one" /></select::Trigger>
+///         <select::Content>
+///             <select::Items render={|item: CollectionItem| view! { <select::ItemText>{item.label}</select::ItemText> }} />
+///         </select::Content>
+///     </select::Root>
+/// }
+/// ```
+#[component]
+pub fn Items<T, F, IV>(
+    render: F,
+    #[prop(into, optional)] item_class: String,
+    #[prop(into, optional)] group_class: String,
+    #[prop(into, optional)] group_label_class: String,
+    /// Ties `T` to the props; never set.
+    #[prop(optional)]
+    _item: PhantomData<T>,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(CollectionItem<T>) -> IV + Clone + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let ctx = expect_context::<SelectContext>();
+    let items = ctx
+        .collection
+        .expect("select::Items requires the `items` prop on select::Root")
+        .typed::<T>();
+
+    move || {
+        Some(items?.with(|items| {
+            render_groups(items, &group_class, &group_label_class, |item| {
+                let render = render.clone();
+                view! {
+                    <Item
+                        value={item.value.clone()}
+                        label={item.label.clone()}
+                        disabled={item.disabled}
+                        class={item_class.clone()}
+                    >
+                        {render(item)}
+                    </Item>
+                }
+            })
+        }))
+    }
+}
+
+/// Renders items through a [`Virtualizer`] so only the rows in view are mounted.
+///
+/// `render` is called with a row index and should return an [`Item`]. While this
+/// part is mounted, arrow keys, Home and End move over every row, scrolling rows
+/// that are not rendered into view. All items must be rendered through it.
+#[component]
+pub fn VirtualItems<F, IV>(
+    virtualizer: Virtualizer,
+    render: F,
+    /// Stable identity for the row at an index, e.g. the item's value.
+    #[prop(optional)]
+    key: Option<Callback<usize, String>>,
+    /// Row index of an item's value, used to scroll the selected item into
+    /// view when the content opens.
+    #[prop(optional)]
+    index_of: Option<Callback<String, Option<usize>>>,
+    #[prop(into, optional)] class: String,
+) -> impl IntoView
+where
+    F: Fn(usize) -> IV + Clone + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let ctx = expect_context::<SelectContext>();
+    ctx.virtualizer.set_value(Some(virtualizer));
+    ctx.virtual_index_of.set_value(index_of);
+    on_cleanup(move || {
+        ctx.virtualizer.set_value(None);
+        ctx.virtual_index_of.set_value(None);
+    });
+
+    match key {
+        Some(key) => view! {
+            <VirtualList virtualizer={virtualizer} render={render} key={key} class={class} />
+        }
+        .into_any(),
+        None => view! { <VirtualList virtualizer={virtualizer} render={render} class={class} /> }
+            .into_any(),
+    }
+}
+
 #[component]
 pub fn Item(
     children: Children,
@@ -414,8 +517,9 @@ pub fn Item(
     #[prop(default = false)] disabled: bool,
 ) -> impl IntoView {
     let ctx = expect_context::<SelectContext>();
+    let row = use_context::<VirtualRow>();
 
-    let index = ctx.next_index();
+    let index = row.map_or_else(|| ctx.next_index(), |r| r.index);
     let label_text = label.unwrap_or_else(|| value.clone());
     let item_ctx = SelectItemContext {
         index,
@@ -431,6 +535,10 @@ pub fn Item(
         ctx.remove_item(index);
     });
 
+    if let Some(row) = row {
+        row.focus_when_pending(item_ctx.item_ref);
+    }
+
     let _ = use_event_listener(item_ctx.item_ref, click, move |_| {
         if item_ctx.disabled {
             return;
@@ -464,6 +572,8 @@ pub fn Item(
                 tabindex="-1"
                 aria-selected={move || if is_selected.get() { "true" } else { "false" }}
                 aria-disabled={if item_ctx.disabled { Some("true") } else { None }}
+                aria-setsize={move || row.map(|r| r.set_size())}
+                aria-posinset={row.map(|r| r.pos_in_set())}
                 data-state={move || if is_selected.get() { "checked" } else { "unchecked" }}
                 data-disabled={item_ctx.disabled}
                 data-highlighted={move || ctx.item_in_focus(item_ctx.index)}
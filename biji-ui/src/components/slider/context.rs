@@ -1,41 +1,412 @@
 use leptos::{html::Div, prelude::*};
 
+use crate::utils::stepping::snap_to_step;
+
+#[derive(Copy, Clone, Default, PartialEq)]
+pub enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl Orientation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Orientation::Horizontal => "horizontal",
+            Orientation::Vertical => "vertical",
+        }
+    }
+}
+
+/// How values map to positions along the track.
+#[derive(Copy, Clone, Default)]
+pub enum Scale {
+    /// Equal distances for equal value differences.
+    #[default]
+    Linear,
+    /// Equal distances for equal ratios, e.g. for frequencies or prices.
+    /// Falls back to linear unless `min` is positive.
+    Logarithmic,
+    /// A custom mapping between values and the fraction of the track (`0.0`
+    /// to `1.0`) they sit at. The two callbacks should be inverses.
+    Custom {
+        to_fraction: Callback<f64, f64>,
+        from_fraction: Callback<f64, f64>,
+    },
+}
+
+impl Scale {
+    /// Fraction of the track `value` sits at, between `min` and `max`.
+    pub fn fraction(self, value: f64, min: f64, max: f64) -> f64 {
+        match self {
+            Scale::Logarithmic if min > 0.0 => (value / min).ln() / (max / min).ln(),
+            Scale::Linear | Scale::Logarithmic => (value - min) / (max - min),
+            Scale::Custom { to_fraction, .. } => to_fraction.run(value),
+        }
+    }
+
+    /// Value at `fraction` of the track, between `min` and `max`.
+    pub fn value(self, fraction: f64, min: f64, max: f64) -> f64 {
+        match self {
+            Scale::Logarithmic if min > 0.0 => min * (max / min).powf(fraction),
+            Scale::Linear | Scale::Logarithmic => min + fraction * (max - min),
+            Scale::Custom { from_fraction, .. } => from_fraction.run(fraction),
+        }
+    }
+}
+
+/// State shared by the slider parts.
 #[derive(Copy, Clone)]
 pub struct SliderContext {
-    pub value: RwSignal<f64>,
+    /// One value per thumb, in ascending order.
+    pub values: RwSignal<Vec<f64>>,
     pub min: f64,
     pub max: f64,
     pub step: f64,
     pub disabled: bool,
+    pub orientation: Orientation,
+    /// Whether `max` is at the start of the track (left, or bottom when
+    /// vertical) instead of the end.
+    pub inverted: bool,
+    pub scale: Scale,
+    /// Smallest distance between neighbouring thumbs, in steps.
+    pub min_steps_between_thumbs: usize,
+    /// Whether a thumb dragged past its neighbour swaps places with it
+    /// instead of stopping.
+    pub swap_thumbs: bool,
     pub track_ref: NodeRef<Div>,
+    pub thumb_refs: StoredValue<Vec<NodeRef<Div>>>,
+    /// Index of the thumb being dragged.
+    pub(crate) dragging: StoredValue<Option<usize>>,
     pub(crate) on_value_change: Option<Callback<f64>>,
+    pub(crate) on_values_change: Option<Callback<Vec<f64>>>,
+    pub(crate) on_value_commit: Option<Callback<Vec<f64>>>,
 }
 
 impl SliderContext {
+    /// Value of the first thumb, the only one of a single-thumb slider.
+    pub fn value(&self) -> f64 {
+        self.value_at(0)
+    }
+
+    pub fn value_at(&self, index: usize) -> f64 {
+        self.values
+            .with(|v| v.get(index).copied())
+            .unwrap_or(self.min)
+    }
+
+    /// Position of the first thumb along the track, from 0 to 100.
     pub fn percentage(&self) -> f64 {
-        let value = self.value.get();
+        self.percentage_of(self.value())
+    }
+
+    /// Position of `value` along the track, from 0 to 100.
+    pub fn percentage_of(&self, value: f64) -> f64 {
         let min = self.min;
         let max = self.max;
         if !value.is_finite() || !min.is_finite() || !max.is_finite() || max <= min {
             return 0.0;
         }
-        ((value - min) / (max - min) * 100.0).clamp(0.0, 100.0)
+        (self.scale.fraction(value, min, max) * 100.0).clamp(0.0, 100.0)
     }
 
+    /// Edge of the track positions are measured from: where `min` sits.
+    pub fn start_edge(&self) -> &'static str {
+        match (self.orientation, self.inverted) {
+            (Orientation::Horizontal, false) => "left",
+            (Orientation::Horizontal, true) => "right",
+            (Orientation::Vertical, false) => "bottom",
+            (Orientation::Vertical, true) => "top",
+        }
+    }
+
+    /// Edge of the track where `max` sits.
+    pub fn end_edge(&self) -> &'static str {
+        match (self.orientation, self.inverted) {
+            (Orientation::Horizontal, false) => "right",
+            (Orientation::Horizontal, true) => "left",
+            (Orientation::Vertical, false) => "top",
+            (Orientation::Vertical, true) => "bottom",
+        }
+    }
+
+    /// Whether `value` lies within the filled `Range`.
+    pub fn is_in_range(&self, value: f64) -> bool {
+        self.values.with(|v| match v.as_slice() {
+            [] => false,
+            [only] => value <= *only,
+            [first, .., last] => *first <= value && value <= *last,
+        })
+    }
+
+    /// Start and end percentage of the `Range`: from `min` to the only thumb,
+    /// or between the outer thumbs.
+    pub fn range_percentages(&self) -> (f64, f64) {
+        self.values.with(|v| match v.as_slice() {
+            [] => (0.0, 0.0),
+            [value] => (0.0, self.percentage_of(*value)),
+            [first, .., last] => (self.percentage_of(*first), self.percentage_of(*last)),
+        })
+    }
+
+    /// Rounds `raw` to the nearest step and clamps it to `min..=max`.
+    pub fn snap(&self, raw: f64) -> f64 {
+        snap_to_step(raw, self.min, self.max, self.step)
+    }
+
+    pub fn value_from_pct(&self, pct: f64) -> f64 {
+        self.snap(self.scale.value(pct.clamp(0.0, 1.0), self.min, self.max))
+    }
+
+    /// Moves the first thumb to `pct` of the track.
     pub fn set_value_from_pct(&self, pct: f64) {
+        self.set_thumb_from_pct(0, pct);
+    }
+
+    /// Moves the thumb at `index` as close to `pct` as its neighbours allow.
+    /// Returns the thumb's index afterwards, which differs when it swapped.
+    pub fn set_thumb_from_pct(&self, index: usize, pct: f64) -> usize {
         if !pct.is_finite() {
-            return;
+            return index;
         }
-        let raw = self.min + pct.clamp(0.0, 1.0) * (self.max - self.min);
-        let stepped = if self.step.is_finite() && self.step > 0.0 {
-            ((raw - self.min) / self.step).round() * self.step + self.min
-        } else {
-            raw
+        self.set_value(index, self.value_from_pct(pct))
+    }
+
+    /// Moves the thumb at `index` to `value`, keeping the thumbs apart and in
+    /// order. Returns the thumb's index afterwards and fires the change
+    /// callbacks when a value changed.
+    pub fn set_value(&self, index: usize, value: f64) -> usize {
+        let min_gap = self.min_gap();
+        let current = self.values.get_untracked();
+        let Some((next, moved)) = move_thumb(&current, index, value, min_gap, self.swap_thumbs)
+        else {
+            return index;
+        };
+        if next != current {
+            let moved_value = next[moved];
+            self.values.set(next.clone());
+            if let Some(cb) = self.on_value_change {
+                cb.run(moved_value);
+            }
+            if let Some(cb) = self.on_values_change {
+                cb.run(next);
+            }
+        }
+        moved
+    }
+
+    pub(crate) fn commit(&self) {
+        if let Some(cb) = self.on_value_commit {
+            cb.run(self.values.get_untracked());
+        }
+    }
+
+    /// Fraction of the track at the pointer position, from `min`'s end.
+    pub(crate) fn pct_from_pointer(&self, x: f64, y: f64) -> Option<f64> {
+        let track = self.track_ref.get_untracked()?;
+        let rect = track.get_bounding_client_rect();
+        let from_start = match self.orientation {
+            Orientation::Horizontal => (x - rect.left()) / rect.width(),
+            Orientation::Vertical => (rect.bottom() - y) / rect.height(),
         };
-        self.value.set(stepped.clamp(self.min, self.max));
+        Some(if self.inverted {
+            1.0 - from_start
+        } else {
+            from_start
+        })
+    }
+
+    /// Steps a key moves the value by: arrows and Page keys towards `max`'s
+    /// end increase it, the others decrease it.
+    pub(crate) fn key_steps(&self, key: &str) -> Option<f64> {
+        key_steps(key, self.orientation, self.inverted)
+    }
+
+    pub(crate) fn focus_thumb(&self, index: usize) {
+        let thumb = self.thumb_refs.with_value(|refs| refs.get(index).copied());
+        if let Some(el) = thumb.and_then(|t| t.get_untracked()) {
+            let _ = el.focus();
+        }
+    }
+
+    fn min_gap(&self) -> f64 {
+        if self.step.is_finite() && self.step > 0.0 {
+            self.min_steps_between_thumbs as f64 * self.step
+        } else {
+            0.0
+        }
     }
 
     pub fn data_state(&self) -> &'static str {
         if self.disabled { "disabled" } else { "enabled" }
     }
 }
+
+fn key_steps(key: &str, orientation: Orientation, inverted: bool) -> Option<f64> {
+    let (increase, decrease) = match (orientation, inverted) {
+        (Orientation::Horizontal, false) => ("ArrowRight", "ArrowLeft"),
+        (Orientation::Horizontal, true) => ("ArrowLeft", "ArrowRight"),
+        (Orientation::Vertical, false) => ("ArrowUp", "ArrowDown"),
+        (Orientation::Vertical, true) => ("ArrowDown", "ArrowUp"),
+    };
+    let (up, down) = match orientation {
+        Orientation::Horizontal => ("ArrowUp", "ArrowDown"),
+        Orientation::Vertical => ("ArrowRight", "ArrowLeft"),
+    };
+    match key {
+        "PageUp" => Some(10.0),
+        "PageDown" => Some(-10.0),
+        k if k == increase || k == up => Some(1.0),
+        k if k == decrease || k == down => Some(-1.0),
+        _ => None,
+    }
+}
+
+/// Values every `every` steps from `min` through `max`, for tick marks.
+pub(crate) fn mark_values(min: f64, max: f64, step: f64, every: usize) -> Vec<f64> {
+    let interval = step * every as f64;
+    if !interval.is_finite() || interval <= 0.0 || max < min {
+        return Vec::new();
+    }
+    let count = ((max - min) / interval + 1e-9).floor() as usize;
+    (0..=count).map(|i| min + i as f64 * interval).collect()
+}
+
+/// `values` with the thumb at `index` moved to `value`, and the thumb's index
+/// afterwards. Without `swap` the thumb stops `min_gap` short of its
+/// neighbours; with it, the thumb may pass them and the values are re-sorted.
+/// `None` when the thumb has no room to move.
+pub(crate) fn move_thumb(
+    values: &[f64],
+    index: usize,
+    value: f64,
+    min_gap: f64,
+    swap: bool,
+) -> Option<(Vec<f64>, usize)> {
+    if index >= values.len() {
+        return None;
+    }
+    if swap {
+        let mut next = values.to_vec();
+        next.remove(index);
+        let moved = next.partition_point(|v| *v < value);
+        let fits = next
+            .get(moved.wrapping_sub(1))
+            .is_none_or(|v| value - v >= min_gap)
+            && next.get(moved).is_none_or(|v| v - value >= min_gap);
+        if fits {
+            next.insert(moved, value);
+            return Some((next, moved));
+        }
+    }
+    let low = index
+        .checked_sub(1)
+        .map_or(f64::NEG_INFINITY, |i| values[i] + min_gap);
+    let high = values.get(index + 1).map_or(f64::INFINITY, |v| v - min_gap);
+    if low > high {
+        return None;
+    }
+    let mut next = values.to_vec();
+    next[index] = value.clamp(low, high);
+    Some((next, index))
+}
+
+/// Index of the thumb closest to `value`. On a tie, the thumb `value` lies
+/// above moves when it is the upper one, so stacked thumbs can be pulled apart.
+pub(crate) fn nearest_thumb(values: &[f64], value: f64) -> usize {
+    let mut nearest = 0;
+    for (i, v) in values.iter().enumerate() {
+        let distance = (v - value).abs();
+        let best = (values[nearest] - value).abs();
+        if distance < best || (distance == best && value > *v) {
+            nearest = i;
+        }
+    }
+    nearest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumbs_stop_at_neighbours() {
+        let values = [20.0, 60.0];
+        assert_eq!(
+            move_thumb(&values, 0, 80.0, 0.0, false),
+            Some((vec![60.0, 60.0], 0))
+        );
+        assert_eq!(
+            move_thumb(&values, 0, 80.0, 10.0, false),
+            Some((vec![50.0, 60.0], 0))
+        );
+        assert_eq!(
+            move_thumb(&values, 1, -5.0, 10.0, false),
+            Some((vec![20.0, 30.0], 1))
+        );
+        assert_eq!(move_thumb(&[50.0, 55.0, 60.0], 1, 52.0, 10.0, false), None);
+    }
+
+    #[test]
+    fn thumbs_swap() {
+        let values = [20.0, 60.0];
+        assert_eq!(
+            move_thumb(&values, 0, 80.0, 0.0, true),
+            Some((vec![60.0, 80.0], 1))
+        );
+        // Not enough room on the far side: stops short instead.
+        assert_eq!(
+            move_thumb(&values, 0, 65.0, 10.0, true),
+            Some((vec![50.0, 60.0], 0))
+        );
+    }
+
+    #[test]
+    fn logarithmic_scale() {
+        let scale = Scale::Logarithmic;
+        assert_eq!(scale.fraction(20.0, 20.0, 20000.0), 0.0);
+        assert!((scale.fraction(200.0, 20.0, 20000.0) - 1.0 / 3.0).abs() < 1e-9);
+        assert!((scale.value(2.0 / 3.0, 20.0, 20000.0) - 2000.0).abs() < 1e-6);
+        // Not positive: linear.
+        assert_eq!(scale.fraction(50.0, 0.0, 100.0), 0.5);
+    }
+
+    #[test]
+    fn keys_follow_orientation() {
+        let horizontal = |k| key_steps(k, Orientation::Horizontal, false);
+        assert_eq!(horizontal("ArrowRight"), Some(1.0));
+        assert_eq!(horizontal("ArrowUp"), Some(1.0));
+        assert_eq!(horizontal("PageDown"), Some(-10.0));
+        assert_eq!(
+            key_steps("ArrowRight", Orientation::Horizontal, true),
+            Some(-1.0)
+        );
+        assert_eq!(
+            key_steps("ArrowUp", Orientation::Vertical, false),
+            Some(1.0)
+        );
+        assert_eq!(
+            key_steps("ArrowUp", Orientation::Vertical, true),
+            Some(-1.0)
+        );
+        assert_eq!(horizontal("Tab"), None);
+    }
+
+    #[test]
+    fn marks_every_n_steps() {
+        assert_eq!(
+            mark_values(0.0, 100.0, 5.0, 5),
+            [0.0, 25.0, 50.0, 75.0, 100.0]
+        );
+        assert_eq!(mark_values(0.0, 10.0, 3.0, 1), [0.0, 3.0, 6.0, 9.0]);
+        assert!(mark_values(0.0, 10.0, 0.0, 1).is_empty());
+    }
+
+    #[test]
+    fn nearest_thumb_prefers_direction_on_tie() {
+        assert_eq!(nearest_thumb(&[20.0, 60.0], 30.0), 0);
+        assert_eq!(nearest_thumb(&[20.0, 60.0], 50.0), 1);
+        assert_eq!(nearest_thumb(&[40.0, 40.0], 45.0), 1);
+        assert_eq!(nearest_thumb(&[40.0, 40.0], 35.0), 0);
+    }
+}
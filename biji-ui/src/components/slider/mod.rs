@@ -1,7 +1,10 @@
 pub mod context;
 pub mod root;
 
+pub use context::{Orientation, Scale};
+pub use root::Marks;
 pub use root::Range;
 pub use root::Root;
 pub use root::Thumb;
+pub use root::Tick;
 pub use root::Track;
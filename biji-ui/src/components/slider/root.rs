@@ -1,39 +1,87 @@
 use leptos::{
     context::Provider,
     ev::{keydown, pointercancel, pointerdown, pointermove, pointerup},
-    html::Div,
     prelude::*,
 };
 use leptos_use::use_event_listener;
+use wasm_bindgen::JsCast;
 
-use super::context::SliderContext;
+use super::context::{Orientation, Scale, SliderContext, mark_values, nearest_thumb};
 
 #[component]
 pub fn Root(
     children: Children,
     #[prop(into, optional)] class: String,
     #[prop(default = 0.0)] value: f64,
+    /// Initial value of each thumb, for a range slider. Render one `Thumb` per
+    /// value. Takes precedence over `value`.
+    #[prop(optional)]
+    values: Vec<f64>,
     #[prop(default = 0.0)] min: f64,
     #[prop(default = 100.0)] max: f64,
     #[prop(default = 1.0)] step: f64,
     #[prop(default = false)] disabled: bool,
-    #[prop(optional)] on_value_change: Option<Callback<f64>>,
+    /// Vertical sliders have `min` at the bottom and `max` at the top.
+    #[prop(default = Orientation::Horizontal)]
+    orientation: Orientation,
+    /// Puts `max` at the start of the track (left, or bottom when vertical)
+    /// instead.
+    #[prop(default = false)]
+    inverted: bool,
+    /// Maps values to positions along the track.
+    #[prop(optional)]
+    scale: Scale,
+    /// Smallest distance between neighbouring thumbs, in steps.
+    #[prop(default = 0)]
+    min_steps_between_thumbs: usize,
+    /// Lets a thumb dragged past its neighbour swap places with it instead of
+    /// stopping.
+    #[prop(default = false)]
+    swap_thumbs: bool,
+    /// Called with the new value of the thumb that moved.
+    #[prop(optional)]
+    on_value_change: Option<Callback<f64>>,
+    /// Called with every thumb's value when one of them moves.
+    #[prop(optional)]
+    on_values_change: Option<Callback<Vec<f64>>>,
+    /// Called with every thumb's value when a drag ends or a key moves a thumb.
+    #[prop(optional)]
+    on_value_commit: Option<Callback<Vec<f64>>>,
 ) -> impl IntoView {
     let (min, max) = if min <= max { (min, max) } else { (max, min) };
+    let mut values = if values.is_empty() {
+        vec![value]
+    } else {
+        values
+    };
+    for v in values.iter_mut() {
+        *v = v.clamp(min, max);
+    }
+    values.sort_by(f64::total_cmp);
+    let thumb_refs = values.iter().map(|_| NodeRef::new()).collect();
     let ctx = SliderContext {
-        value: RwSignal::new(value.clamp(min, max)),
+        values: RwSignal::new(values),
         min,
         max,
         step,
         disabled,
+        orientation,
+        inverted,
+        scale,
+        min_steps_between_thumbs,
+        swap_thumbs,
         track_ref: NodeRef::new(),
+        thumb_refs: StoredValue::new(thumb_refs),
+        dragging: StoredValue::new(None),
         on_value_change,
+        on_values_change,
+        on_value_commit,
     };
 
     view! {
         <Provider value={ctx}>
             <div
-                data-orientation="horizontal"
+                data-orientation={ctx.orientation.as_str()}
                 data-disabled={ctx.disabled}
                 data-state={ctx.data_state()}
                 class={class}
@@ -44,17 +92,48 @@ pub fn Root(
     }
 }
 
+/// The slider's rail. Pressing it moves the nearest thumb to the pointer and
+/// drags it from there.
 #[component]
-pub fn Track(
-    children: Children,
-    #[prop(into, optional)] class: String,
-) -> impl IntoView {
+pub fn Track(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
     let ctx = expect_context::<SliderContext>();
 
+    let _ = use_event_listener(ctx.track_ref, pointerdown, move |evt| {
+        if ctx.disabled || evt.button() != 0 {
+            return;
+        }
+        // Presses on a thumb inside the track are the thumb's own.
+        let on_thumb = evt
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+            .is_some_and(|t| t.closest("[role=slider]").ok().flatten().is_some());
+        if on_thumb {
+            return;
+        }
+        let Some(pct) = ctx.pct_from_pointer(evt.client_x() as f64, evt.client_y() as f64) else {
+            return;
+        };
+        evt.prevent_default();
+        let nearest = ctx
+            .values
+            .with_untracked(|v| nearest_thumb(v, ctx.value_from_pct(pct)));
+        let index = ctx.set_thumb_from_pct(nearest, pct);
+        ctx.dragging.set_value(Some(index));
+        ctx.focus_thumb(index);
+        if let Some(el) = ctx.track_ref.get_untracked() {
+            let _ = el.set_pointer_capture(evt.pointer_id());
+        }
+    });
+    let _ = use_event_listener(ctx.track_ref, pointermove, move |evt| {
+        drag_to(ctx, &evt);
+    });
+    let _ = use_event_listener(ctx.track_ref, pointerup, move |_| end_drag(ctx));
+    let _ = use_event_listener(ctx.track_ref, pointercancel, move |_| end_drag(ctx));
+
     view! {
         <div
             node_ref={ctx.track_ref}
-            data-orientation="horizontal"
+            data-orientation={ctx.orientation.as_str()}
             data-disabled={ctx.disabled}
             class={class}
         >
@@ -63,25 +142,51 @@ pub fn Track(
     }
 }
 
+/// The filled part of the track: from the start to the thumb, or between the
+/// outer thumbs of a range slider.
 #[component]
 pub fn Range(#[prop(into, optional)] class: String) -> impl IntoView {
     let ctx = expect_context::<SliderContext>();
 
     view! {
         <div
-            data-orientation="horizontal"
+            data-orientation={ctx.orientation.as_str()}
             data-disabled={ctx.disabled}
             class={class}
-            style={move || format!("left: 0%; right: {}%", 100.0 - ctx.percentage())}
+            style={move || {
+                let (start, end) = ctx.range_percentages();
+                format!(
+                    "{}: {start}%; {}: {}%",
+                    ctx.start_edge(),
+                    ctx.end_edge(),
+                    100.0 - end,
+                )
+            }}
         />
     }
 }
 
 #[component]
-pub fn Thumb(#[prop(into, optional)] class: String) -> impl IntoView {
+pub fn Thumb(
+    #[prop(into, optional)] class: String,
+    /// Which value this thumb controls, for a range slider.
+    #[prop(default = 0)]
+    index: usize,
+    /// Formats the value for `aria-valuetext`, e.g. `"$40"`.
+    #[prop(optional)]
+    value_text: Option<Callback<f64, String>>,
+) -> impl IntoView {
     let ctx = expect_context::<SliderContext>();
-    let thumb_ref: NodeRef<Div> = NodeRef::new();
-    let is_dragging = RwSignal::new(false);
+    let thumb_ref = ctx
+        .thumb_refs
+        .with_value(|refs| refs.get(index).copied())
+        .unwrap_or_else(|| {
+            leptos::logging::warn!(
+                "slider::Thumb index={index} out of bounds (thumbs={})",
+                ctx.thumb_refs.with_value(Vec::len)
+            );
+            NodeRef::new()
+        });
 
     let _ = use_event_listener(thumb_ref, pointerdown, move |evt| {
         if ctx.disabled {
@@ -92,53 +197,45 @@ pub fn Thumb(#[prop(into, optional)] class: String) -> impl IntoView {
             let _ = el.set_pointer_capture(evt.pointer_id());
             let _ = el.focus();
         }
-        is_dragging.set(true);
+        ctx.dragging.set_value(Some(index));
     });
 
     let _ = use_event_listener(thumb_ref, pointermove, move |evt| {
-        if !is_dragging.get() || ctx.disabled {
-            return;
-        }
-        if let Some(track) = ctx.track_ref.get() {
-            let rect = track.get_bounding_client_rect();
-            let pct = (evt.client_x() as f64 - rect.left()) / rect.width();
-            ctx.set_value_from_pct(pct);
-            if let Some(cb) = ctx.on_value_change {
-                cb.run(ctx.value.get());
-            }
-        }
+        drag_to(ctx, &evt);
     });
 
-    let _ = use_event_listener(thumb_ref, pointerup, move |_| {
-        is_dragging.set(false);
-    });
+    let _ = use_event_listener(thumb_ref, pointerup, move |_| end_drag(ctx));
 
-    let _ = use_event_listener(thumb_ref, pointercancel, move |_| {
-        is_dragging.set(false);
-    });
+    let _ = use_event_listener(thumb_ref, pointercancel, move |_| end_drag(ctx));
 
     let _ = use_event_listener(thumb_ref, keydown, move |evt| {
         if ctx.disabled {
             return;
         }
-        let step = if ctx.step.is_finite() && ctx.step > 0.0 { ctx.step } else { 1.0 };
-        let current = ctx.value.get();
+        let step = if ctx.step.is_finite() && ctx.step > 0.0 {
+            ctx.step
+        } else {
+            1.0
+        };
+        let current = ctx.value_at(index);
         let new_value = match evt.key().as_str() {
-            "ArrowRight" | "ArrowUp" => current + step,
-            "ArrowLeft" | "ArrowDown" => current - step,
-            "PageUp" => current + step * 10.0,
-            "PageDown" => current - step * 10.0,
             "Home" => ctx.min,
             "End" => ctx.max,
-            _ => return,
+            key => match ctx.key_steps(key) {
+                Some(steps) => current + step * steps,
+                None => return,
+            },
         };
         evt.prevent_default();
-        ctx.value.set(new_value.clamp(ctx.min, ctx.max));
-        if let Some(cb) = ctx.on_value_change {
-            cb.run(ctx.value.get());
+        let moved = ctx.set_value(index, new_value.clamp(ctx.min, ctx.max));
+        if moved != index {
+            ctx.focus_thumb(moved);
         }
+        ctx.commit();
     });
 
+    let value = move || ctx.value_at(index);
+
     view! {
         <div
             node_ref={thumb_ref}
@@ -146,11 +243,108 @@ pub fn Thumb(#[prop(into, optional)] class: String) -> impl IntoView {
             tabindex={if ctx.disabled { "-1" } else { "0" }}
             aria-valuemin={ctx.min.to_string()}
             aria-valuemax={ctx.max.to_string()}
-            aria-valuenow={move || ctx.value.get().to_string()}
+            aria-valuenow={move || value().to_string()}
+            aria-valuetext={move || value_text.map(|f| f.run(value()))}
             aria-disabled={if ctx.disabled { Some("true") } else { None }}
             data-disabled={ctx.disabled}
-            style={move || format!("left: {}%", ctx.percentage())}
+            aria-orientation={ctx.orientation.as_str()}
+            data-orientation={ctx.orientation.as_str()}
+            data-index={index}
+            style={move || format!("{}: {}%", ctx.start_edge(), ctx.percentage_of(value()))}
             class={class}
         />
     }
 }
+
+/// Moves the thumb being dragged to the pointer, following it to its new
+/// index when it swaps with a neighbour.
+fn drag_to(ctx: SliderContext, evt: &web_sys::PointerEvent) {
+    let Some(index) = ctx.dragging.get_value() else {
+        return;
+    };
+    if ctx.disabled {
+        return;
+    }
+    if let Some(pct) = ctx.pct_from_pointer(evt.client_x() as f64, evt.client_y() as f64) {
+        let moved = ctx.set_thumb_from_pct(index, pct);
+        if moved != index {
+            ctx.dragging.set_value(Some(moved));
+            ctx.focus_thumb(moved);
+        }
+    }
+}
+
+fn end_drag(ctx: SliderContext) {
+    if ctx
+        .dragging
+        .try_update_value(|d| d.take())
+        .flatten()
+        .is_some()
+    {
+        ctx.commit();
+    }
+}
+
+/// Tick marks along the track: one `Tick` per value in `values`, or every
+/// `every` steps from `min` to `max`, plus any `Tick`s passed as children.
+#[component]
+pub fn Marks(
+    #[prop(optional)] children: Option<Children>,
+    #[prop(into, optional)] class: String,
+    /// Values to mark.
+    #[prop(optional)]
+    values: Vec<f64>,
+    /// Marks every this many steps when `values` is empty.
+    #[prop(optional)]
+    every: Option<usize>,
+    /// CSS class applied to each generated tick.
+    #[prop(into, optional)]
+    tick_class: String,
+    /// Label rendered inside each generated tick.
+    #[prop(optional)]
+    label: Option<Callback<f64, String>>,
+) -> impl IntoView {
+    let ctx = expect_context::<SliderContext>();
+    let values = match every {
+        Some(every) if values.is_empty() => mark_values(ctx.min, ctx.max, ctx.step, every),
+        _ => values,
+    };
+
+    view! {
+        <div aria-hidden="true" data-orientation={ctx.orientation.as_str()} class={class}>
+            {values
+                .into_iter()
+                .map(|value| {
+                    view! {
+                        <Tick value={value} class={tick_class.clone()}>
+                            {label.map(|f| f.run(value))}
+                        </Tick>
+                    }
+                })
+                .collect_view()}
+            {children.map(|c| c())}
+        </div>
+    }
+}
+
+/// A mark at `value`, positioned along the track like a thumb.
+#[component]
+pub fn Tick(
+    value: f64,
+    #[prop(optional)] children: Option<Children>,
+    #[prop(into, optional)] class: String,
+) -> impl IntoView {
+    let ctx = expect_context::<SliderContext>();
+
+    view! {
+        <span
+            data-orientation={ctx.orientation.as_str()}
+            data-value={value}
+            data-in-range={move || ctx.is_in_range(value).then_some("")}
+            style={format!("position: absolute; {}: {}%", ctx.start_edge(), ctx.percentage_of(value))}
+            class={class}
+        >
+            {children.map(|c| c())}
+        </span>
+    }
+}
@@ -0,0 +1,334 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use leptos::{
+    html::{Div, Input},
+    prelude::*,
+};
+
+use crate::items::{
+    FilterActiveItems, Focus, GetIndex, IsActive, ManageFocus, NavigateItems, filter_active,
+    next_item, previous_item,
+};
+
+/// Whether a tag equal to an existing one may be added.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Duplicates {
+    #[default]
+    Reject,
+    /// Rejects tags that differ from an existing one only in letter case.
+    RejectIgnoreCase,
+    Allow,
+}
+
+impl Duplicates {
+    fn is_duplicate(&self, tags: &[String], candidate: &str) -> bool {
+        match self {
+            Duplicates::Reject => tags.iter().any(|t| t == candidate),
+            Duplicates::RejectIgnoreCase => {
+                let candidate = candidate.to_lowercase();
+                tags.iter().any(|t| t.to_lowercase() == candidate)
+            }
+            Duplicates::Allow => false,
+        }
+    }
+}
+
+/// Splits typed or pasted text into trimmed, non-empty tags. Line breaks
+/// separate tags as well as `delimiter`.
+pub fn split_tags(text: &str, delimiter: char) -> Vec<String> {
+    text.split([delimiter, '\n', '\r'])
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `true` when `candidate` can join `tags` without exceeding `max` or breaking
+/// the duplicate policy. `skip` leaves out the tag being edited.
+pub(crate) fn admits(
+    tags: &[String],
+    candidate: &str,
+    max: Option<usize>,
+    duplicates: Duplicates,
+    skip: Option<usize>,
+) -> bool {
+    let others: Vec<String> = tags
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| Some(*i) != skip)
+        .map(|(_, t)| t.clone())
+        .collect();
+    let full = skip.is_none() && max.is_some_and(|max| tags.len() >= max);
+    !candidate.is_empty() && !full && !duplicates.is_duplicate(&others, candidate)
+}
+
+#[derive(Copy, Clone)]
+pub struct TagsInputContext {
+    pub value: RwSignal<Vec<String>>,
+    /// Position of the tag that has focus; a focused tag is the one Backspace
+    /// deletes.
+    pub item_focus: RwSignal<Option<usize>>,
+    pub items: RwSignal<HashMap<usize, TagItemContext>>,
+    /// Position of the tag being edited.
+    pub editing: RwSignal<Option<usize>>,
+    pub input_ref: NodeRef<Input>,
+    pub delimiter: char,
+    pub max: Option<usize>,
+    pub duplicates: Duplicates,
+    pub editable: bool,
+    pub disabled: bool,
+    pub(crate) next_id: StoredValue<AtomicUsize>,
+    pub(crate) validate: Option<Callback<String, bool>>,
+    pub(crate) on_value_change: Option<Callback<Vec<String>>>,
+    pub(crate) on_invalid: Option<Callback<String>>,
+}
+
+impl TagsInputContext {
+    pub fn next_id(&self) -> usize {
+        self.next_id
+            .with_value(|c| c.fetch_add(1, Ordering::Relaxed))
+    }
+
+    pub fn upsert_item(&self, index: usize, item: TagItemContext) {
+        self.items.update(|m| {
+            *m.entry(index).or_insert(item) = item;
+        });
+    }
+
+    /// Removes the tag registered at `index`, unless another tag has taken
+    /// that position since.
+    pub fn remove_item(&self, index: usize, id: usize) {
+        self.items.update(|m| {
+            if m.get(&index).is_some_and(|item| item.id == id) {
+                m.remove(&index);
+            }
+        });
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.max.is_some_and(|max| self.value.get().len() >= max)
+    }
+
+    fn accepts(&self, tags: &[String], candidate: &str, skip: Option<usize>) -> bool {
+        admits(tags, candidate, self.max, self.duplicates, skip)
+            && self.validate.is_none_or(|v| v.run(candidate.to_string()))
+    }
+
+    fn set_tags(&self, tags: Vec<String>) {
+        self.value.set(tags.clone());
+        if let Some(cb) = self.on_value_change {
+            cb.run(tags);
+        }
+    }
+
+    fn reject(&self, candidate: String) {
+        if let Some(cb) = self.on_invalid {
+            cb.run(candidate);
+        }
+    }
+
+    /// Adds each of `candidates` that passes `max`, the duplicate policy and
+    /// `validate`. Returns the rejected ones.
+    pub fn add_tags(&self, candidates: Vec<String>) -> Vec<String> {
+        if self.disabled {
+            return candidates;
+        }
+        let mut tags = self.value.get_untracked();
+        let mut rejected = Vec::new();
+        for candidate in candidates {
+            if self.accepts(&tags, &candidate, None) {
+                tags.push(candidate);
+            } else {
+                self.reject(candidate.clone());
+                rejected.push(candidate);
+            }
+        }
+        if tags.len() != self.value.with_untracked(Vec::len) {
+            self.set_tags(tags);
+        }
+        rejected
+    }
+
+    /// Replaces the tag at `index`; an empty `text` removes it. Returns `false`
+    /// when the new text is rejected.
+    pub fn replace_tag(&self, index: usize, text: &str) -> bool {
+        if self.disabled {
+            return false;
+        }
+        let text = text.trim();
+        if text.is_empty() {
+            self.remove_tag(index);
+            return true;
+        }
+        let mut tags = self.value.get_untracked();
+        if tags.get(index).is_some_and(|t| t == text) {
+            return true;
+        }
+        if index >= tags.len() || !self.accepts(&tags, text, Some(index)) {
+            self.reject(text.to_string());
+            return false;
+        }
+        tags[index] = text.to_string();
+        self.set_tags(tags);
+        true
+    }
+
+    pub fn remove_tag(&self, index: usize) {
+        if self.disabled {
+            return;
+        }
+        let mut tags = self.value.get_untracked();
+        if index < tags.len() {
+            tags.remove(index);
+            self.editing.set(None);
+            self.set_tags(tags);
+        }
+    }
+
+    pub fn focus_input(&self) {
+        self.set_focus(None);
+        if let Some(input) = self.input_ref.get_untracked() {
+            let _ = input.focus();
+        }
+    }
+
+    /// Focuses the tag at `index` once it has rendered, or the input when
+    /// there is no such tag.
+    pub(crate) fn focus_tag_later(&self, index: Option<usize>) {
+        let ctx = *self;
+        request_animation_frame(move || {
+            let item = index.and_then(|i| ctx.items.with_untracked(|m| m.get(&i).copied()));
+            match item {
+                Some(item) if item.focus() => {}
+                _ => ctx.focus_input(),
+            }
+        });
+    }
+}
+
+impl FilterActiveItems<TagItemContext> for TagsInputContext {
+    fn filter_active_items(&self) -> Vec<TagItemContext> {
+        filter_active(self.items.get())
+    }
+}
+
+impl ManageFocus for TagsInputContext {
+    fn set_focus(&self, index: Option<usize>) {
+        self.item_focus.set(index);
+    }
+
+    fn item_in_focus(&self, index: usize) -> bool {
+        self.item_focus.get() == Some(index)
+    }
+}
+
+impl NavigateItems<TagItemContext> for TagsInputContext {
+    fn navigate_first_item(&self) -> Option<TagItemContext> {
+        self.filter_active_items().into_iter().next()
+    }
+
+    fn navigate_last_item(&self) -> Option<TagItemContext> {
+        self.filter_active_items().into_iter().last()
+    }
+
+    fn navigate_next_item(&self) -> Option<TagItemContext> {
+        let items = self.filter_active_items();
+        next_item(items, self.item_focus.get(), false)
+    }
+
+    fn navigate_previous_item(&self) -> Option<TagItemContext> {
+        let items = self.filter_active_items();
+        previous_item(items, self.item_focus.get(), false)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct TagItemContext {
+    /// Position of the tag in the value.
+    pub index: usize,
+    pub(crate) id: usize,
+    pub value: StoredValue<String>,
+    pub disabled: bool,
+    pub tag_ref: NodeRef<Div>,
+}
+
+impl GetIndex<usize> for TagItemContext {
+    fn get_index(&self) -> usize {
+        self.index
+    }
+}
+
+impl IsActive for TagItemContext {
+    fn is_active(&self) -> bool {
+        !self.disabled
+    }
+}
+
+impl Focus for TagItemContext {
+    fn focus(&self) -> bool {
+        let Some(el) = self.tag_ref.get_untracked() else {
+            return false;
+        };
+        let _ = el.focus();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn splits_on_delimiter_and_lines() {
+        assert_eq!(
+            split_tags(" rust, leptos ,,wasm ", ','),
+            tags(&["rust", "leptos", "wasm"])
+        );
+        assert_eq!(split_tags("a;b\nc\r\nd", ';'), tags(&["a", "b", "c", "d"]));
+        assert!(split_tags(" , ", ',').is_empty());
+    }
+
+    #[test]
+    fn admits_by_max_and_duplicates() {
+        let current = tags(&["Rust", "Leptos"]);
+        assert!(admits(&current, "wasm", None, Duplicates::Reject, None));
+        assert!(!admits(&current, "Rust", None, Duplicates::Reject, None));
+        assert!(admits(&current, "rust", None, Duplicates::Reject, None));
+        assert!(!admits(
+            &current,
+            "rust",
+            None,
+            Duplicates::RejectIgnoreCase,
+            None
+        ));
+        assert!(admits(&current, "Rust", None, Duplicates::Allow, None));
+        assert!(!admits(&current, "wasm", Some(2), Duplicates::Reject, None));
+        assert!(!admits(&current, "", None, Duplicates::Allow, None));
+    }
+
+    #[test]
+    fn admits_edit_of_own_tag() {
+        let current = tags(&["Rust", "Leptos"]);
+        assert!(admits(
+            &current,
+            "rust",
+            Some(2),
+            Duplicates::RejectIgnoreCase,
+            Some(0)
+        ));
+        assert!(!admits(
+            &current,
+            "leptos",
+            Some(2),
+            Duplicates::RejectIgnoreCase,
+            Some(0)
+        ));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod context;
+pub mod root;
+
+pub use context::{Duplicates, split_tags};
+pub use root::{Input, Root, Tag, TagRemove};
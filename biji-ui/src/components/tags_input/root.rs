@@ -0,0 +1,374 @@
+use std::sync::atomic::AtomicUsize;
+
+use leptos::{
+    context::Provider,
+    ev::{blur, click, dblclick, focus, keydown, paste},
+    html::{self, Div},
+    prelude::*,
+};
+use leptos_use::use_event_listener;
+
+use crate::items::{Focus, ManageFocus, NavigateItems};
+
+use super::context::{Duplicates, TagItemContext, TagsInputContext, split_tags};
+
+#[component]
+pub fn Root(
+    children: Children,
+    #[prop(into, optional)] class: String,
+    /// The tags. Pass a signal to render them from and to control them.
+    #[prop(optional)]
+    value: Option<RwSignal<Vec<String>>>,
+    /// Most tags allowed; further tags are rejected.
+    #[prop(optional)]
+    max: Option<usize>,
+    /// Typing or pasting this character creates a tag.
+    #[prop(default = ',')]
+    delimiter: char,
+    #[prop(optional)] duplicates: Duplicates,
+    /// Called with each new or edited tag; returning `false` rejects it.
+    #[prop(optional)]
+    validate: Option<Callback<String, bool>>,
+    /// Lets tags be edited in place on double-click or Enter.
+    #[prop(default = false)]
+    editable: bool,
+    #[prop(default = false)] disabled: bool,
+    #[prop(optional)] on_value_change: Option<Callback<Vec<String>>>,
+    /// Called with each tag rejected by `max`, `duplicates` or `validate`.
+    #[prop(optional)]
+    on_invalid: Option<Callback<String>>,
+) -> impl IntoView {
+    let ctx = TagsInputContext {
+        value: value.unwrap_or_else(|| RwSignal::new(Vec::new())),
+        item_focus: RwSignal::new(None),
+        items: RwSignal::new(Default::default()),
+        editing: RwSignal::new(None),
+        input_ref: NodeRef::new(),
+        delimiter,
+        max,
+        duplicates,
+        editable,
+        disabled,
+        next_id: StoredValue::new(AtomicUsize::new(0)),
+        validate,
+        on_value_change,
+        on_invalid,
+    };
+    let root_ref = NodeRef::<Div>::new();
+
+    // Clicks on the empty space around the tags go to the input.
+    let _ = use_event_listener(root_ref, click, move |evt| {
+        let on_root = root_ref.get_untracked().is_some_and(|el| {
+            let el: &web_sys::EventTarget = el.as_ref();
+            evt.target().as_ref() == Some(el)
+        });
+        if on_root && !disabled {
+            ctx.focus_input();
+        }
+    });
+
+    view! {
+        <Provider value={ctx}>
+            <div
+                node_ref={root_ref}
+                data-disabled={if disabled { Some("true") } else { None }}
+                data-full={move || ctx.is_full().then_some("true")}
+                class={class}
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// One tag. `index` is its position in the root's value.
+///
+/// `index` is fixed when the tag is created, so render tags keyed by
+/// `(index, value)`. Keyed by value alone, a tag keeps its old index after an
+/// earlier tag is removed, and removing or editing it acts on the wrong tag.
+///
+/// Tags are reached with the arrow keys from the input rather than with Tab.
+///
+/// # Example
+/// ```rust,ignore
+/// <For
+///     each={move || tags.get().into_iter().enumerate()}
+///     key={|(index, tag)| (*index, tag.clone())}
+///     let((index, tag))
+/// >
+///     <tags_input::Tag index={index} value={tag.clone()}>
+///         {tag.clone()}
+///         <tags_input::TagRemove>"×"</tags_input::TagRemove>
+///     </tags_input::Tag>
+/// </For>
+/// ```
+#[component]
+pub fn Tag(
+    children: ChildrenFn,
+    index: usize,
+    #[prop(into)] value: String,
+    #[prop(into, optional)] class: String,
+    /// CSS class applied to the input shown while the tag is edited.
+    #[prop(into, optional)]
+    edit_class: String,
+    #[prop(default = false)] disabled: bool,
+) -> impl IntoView {
+    let ctx = expect_context::<TagsInputContext>();
+
+    let item = TagItemContext {
+        index,
+        id: ctx.next_id(),
+        value: StoredValue::new(value),
+        disabled: disabled || ctx.disabled,
+        tag_ref: NodeRef::new(),
+    };
+    ctx.upsert_item(index, item);
+    on_cleanup(move || ctx.remove_item(index, item.id));
+
+    let is_editing = Memo::new(move |_| ctx.editing.get() == Some(index));
+    let start_editing = move || {
+        if ctx.editable && !item.disabled {
+            ctx.editing.set(Some(index));
+        }
+    };
+
+    let _ = use_event_listener(item.tag_ref, focus, move |_| {
+        ctx.set_focus(Some(index));
+    });
+
+    let _ = use_event_listener(item.tag_ref, blur, move |_| {
+        if ctx.item_focus.get_untracked() == Some(index) {
+            ctx.set_focus(None);
+        }
+    });
+
+    let _ = use_event_listener(item.tag_ref, dblclick, move |_| start_editing());
+
+    let _ = use_event_listener(item.tag_ref, keydown, move |evt| {
+        if is_editing.get_untracked() {
+            return;
+        }
+        match evt.key().as_str() {
+            "ArrowLeft" => {
+                if let Some(prev) = ctx.navigate_previous_item() {
+                    prev.focus();
+                }
+            }
+            "ArrowRight" => match ctx.navigate_next_item() {
+                Some(next) => {
+                    next.focus();
+                }
+                None => ctx.focus_input(),
+            },
+            "Backspace" if !item.disabled => {
+                ctx.remove_tag(index);
+                ctx.focus_tag_later(index.checked_sub(1));
+            }
+            "Delete" if !item.disabled => {
+                ctx.remove_tag(index);
+                ctx.focus_tag_later(Some(index));
+            }
+            "Enter" | "F2" if ctx.editable && !item.disabled => start_editing(),
+            "Escape" => ctx.focus_input(),
+            _ => return,
+        }
+        evt.prevent_default();
+    });
+
+    view! {
+        <Provider value={item}>
+            <div
+                node_ref={item.tag_ref}
+                tabindex="-1"
+                data-selected={move || ctx.item_in_focus(index).then_some("true")}
+                data-editing={move || is_editing.get().then_some("true")}
+                data-disabled={if item.disabled { Some("true") } else { None }}
+                class={class}
+            >
+                {move || {
+                    if is_editing.get() {
+                        view! { <EditInput class={edit_class.clone()} /> }.into_any()
+                    } else {
+                        children().into_any()
+                    }
+                }}
+            </div>
+        </Provider>
+    }
+}
+
+/// Replaces a tag's content while it is edited. Enter or blur saves the text,
+/// Escape discards it.
+#[component]
+fn EditInput(class: String) -> impl IntoView {
+    let ctx = expect_context::<TagsInputContext>();
+    let item = expect_context::<TagItemContext>();
+    let input_ref = NodeRef::<html::Input>::new();
+
+    Effect::new(move |_| {
+        if let Some(el) = input_ref.get() {
+            let _ = el.focus();
+            el.select();
+        }
+    });
+
+    let finish = move |save: bool| {
+        if ctx.editing.get_untracked() != Some(item.index) {
+            return;
+        }
+        let text = input_ref
+            .get_untracked()
+            .map(|el| el.value())
+            .unwrap_or_default();
+        if save && !ctx.replace_tag(item.index, &text) {
+            return;
+        }
+        ctx.editing.set(None);
+        ctx.focus_tag_later(Some(item.index));
+    };
+
+    let _ = use_event_listener(input_ref, keydown, move |evt| {
+        // Keep the tag from treating edits as navigation.
+        evt.stop_propagation();
+        match evt.key().as_str() {
+            "Enter" => finish(true),
+            "Escape" => finish(false),
+            _ => return,
+        }
+        evt.prevent_default();
+    });
+
+    let _ = use_event_listener(input_ref, blur, move |_| {
+        if ctx.editing.get_untracked() == Some(item.index) {
+            let text = input_ref
+                .get_untracked()
+                .map(|el| el.value())
+                .unwrap_or_default();
+            // Rejected text is dropped rather than trapping focus.
+            ctx.replace_tag(item.index, &text);
+            ctx.editing.set(None);
+        }
+    });
+
+    view! {
+        <input
+            node_ref={input_ref}
+            type="text"
+            autocomplete="off"
+            aria-label="Edit tag"
+            prop:value={item.value.get_value()}
+            class={class}
+        />
+    }
+}
+
+#[component]
+pub fn TagRemove(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
+    let ctx = expect_context::<TagsInputContext>();
+    let item = expect_context::<TagItemContext>();
+    let button_ref = NodeRef::new();
+
+    let _ = use_event_listener(button_ref, click, move |evt| {
+        evt.stop_propagation();
+        ctx.remove_tag(item.index);
+        ctx.focus_input();
+    });
+
+    view! {
+        <button
+            node_ref={button_ref}
+            type="button"
+            tabindex="-1"
+            aria-label={item.value.with_value(|v| format!("Remove {v}"))}
+            disabled={item.disabled}
+            data-disabled={if item.disabled { Some("true") } else { None }}
+            class={class}
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// The text input new tags are typed into. Enter or the delimiter turns the
+/// text into tags; text that is rejected stays in the input.
+#[component]
+pub fn Input(#[prop(into, optional)] class: String) -> impl IntoView {
+    let ctx = expect_context::<TagsInputContext>();
+    let input_ref = ctx.input_ref;
+
+    // Adds tags from `text` and leaves the rejected ones, followed by `rest`,
+    // in the input.
+    let commit = move |text: &str, rest: &str| {
+        let rejected = ctx.add_tags(split_tags(text, ctx.delimiter));
+        let mut remaining = rejected.join(&ctx.delimiter.to_string());
+        if !rest.is_empty() {
+            if !remaining.is_empty() {
+                remaining.push(ctx.delimiter);
+            }
+            remaining.push_str(rest);
+        }
+        if let Some(el) = input_ref.get_untracked() {
+            el.set_value(&remaining);
+        }
+    };
+
+    let caret_at_start = move || {
+        input_ref.get_untracked().is_some_and(|el| {
+            el.selection_start().ok().flatten() == Some(0)
+                && el.selection_end().ok().flatten() == Some(0)
+        })
+    };
+
+    let _ = use_event_listener(input_ref, keydown, move |evt| {
+        match evt.key().as_str() {
+            "Enter" => {
+                let text = event_target_value(&evt);
+                if text.trim().is_empty() {
+                    return;
+                }
+                commit(&text, "");
+            }
+            // The first Backspace selects the last tag; the tag deletes itself
+            // on the next one.
+            "Backspace" | "ArrowLeft" if caret_at_start() => {
+                let Some(last) = ctx.navigate_last_item() else {
+                    return;
+                };
+                last.focus();
+            }
+            _ => return,
+        }
+        evt.prevent_default();
+    });
+
+    let _ = use_event_listener(input_ref, leptos::ev::input, move |evt| {
+        let text = event_target_value(&evt);
+        if let Some((done, rest)) = text.rsplit_once(ctx.delimiter) {
+            commit(done, rest);
+        }
+    });
+
+    let _ = use_event_listener(input_ref, paste, move |evt| {
+        let pasted = evt
+            .clipboard_data()
+            .and_then(|d| d.get_data("text/plain").ok())
+            .unwrap_or_default();
+        if !pasted.contains([ctx.delimiter, '\n', '\r']) {
+            return;
+        }
+        evt.prevent_default();
+        let typed = event_target_value(&evt);
+        commit(&format!("{typed}{}{pasted}", ctx.delimiter), "");
+    });
+
+    view! {
+        <input
+            node_ref={input_ref}
+            type="text"
+            autocomplete="off"
+            disabled={ctx.disabled}
+            data-disabled={if ctx.disabled { Some("true") } else { None }}
+            class={class}
+        />
+    }
+}
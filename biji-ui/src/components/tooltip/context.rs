@@ -2,14 +2,24 @@ use std::time::Duration;
 
 use leptos::{
     html::{Button, Div},
+    leptos_dom::helpers::TimeoutHandle,
     prelude::*,
 };
 
-use crate::{components::tooltip::singleton, utils::positioning::{AvoidCollisions, Positioning}};
+use crate::{
+    components::tooltip::singleton,
+    utils::{
+        long_press::{DEFAULT_LONG_PRESS_THRESHOLD, LongPress},
+        positioning::{AvoidCollisions, Positioning, anchor_or_trigger},
+    },
+};
 
 #[derive(Copy, Clone)]
 pub struct TooltipContext {
     pub trigger_ref: NodeRef<Button>,
+    /// Set by the `Anchor` part. When rendered, Content is positioned against it
+    /// instead of the trigger.
+    pub anchor_ref: NodeRef<Div>,
     pub content_ref: NodeRef<Div>,
     pub open: RwSignal<bool>,
     pub pointer_inside_trigger: RwSignal<bool>,
@@ -21,12 +31,61 @@ pub struct TooltipContext {
     /// Numeric ID used by the singleton registry to enforce one-at-a-time.
     pub numeric_id: usize,
     pub avoid_collisions: AvoidCollisions,
+    /// Delay between the pointer entering the trigger and the tooltip opening.
+    pub delay_duration: Duration,
+    /// Window after a tooltip closes during which the next one opens instantly.
+    pub skip_delay_duration: Duration,
+    /// Close as soon as the pointer leaves the trigger, even towards the content.
+    pub disable_hoverable_content: bool,
+    /// Touch state; a long press on the trigger opens the tooltip.
+    pub long_press: LongPress,
+    pub(crate) open_timer: StoredValue<Option<TimeoutHandle>>,
+}
+
+/// Default `delay_duration` of a `tooltip::Provider`.
+pub const DEFAULT_DELAY_DURATION: Duration = Duration::from_millis(700);
+
+/// Default `skip_delay_duration` of a `tooltip::Provider`.
+pub const DEFAULT_SKIP_DELAY_DURATION: Duration = Duration::from_millis(300);
+
+/// Defaults shared by every tooltip below a `tooltip::Provider`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TooltipProviderContext {
+    pub delay_duration: Duration,
+    pub skip_delay_duration: Duration,
+    pub disable_hoverable_content: bool,
+}
+
+impl TooltipProviderContext {
+    /// Used by tooltips outside any provider, which open as soon as they are
+    /// hovered.
+    pub const STANDALONE: Self = Self {
+        delay_duration: Duration::ZERO,
+        skip_delay_duration: Duration::ZERO,
+        disable_hoverable_content: false,
+    };
+}
+
+impl Default for TooltipProviderContext {
+    fn default() -> Self {
+        Self {
+            delay_duration: DEFAULT_DELAY_DURATION,
+            skip_delay_duration: DEFAULT_SKIP_DELAY_DURATION,
+            disable_hoverable_content: false,
+        }
+    }
 }
 
 impl Default for TooltipContext {
     fn default() -> Self {
+        let TooltipProviderContext {
+            delay_duration,
+            skip_delay_duration,
+            disable_hoverable_content,
+        } = TooltipProviderContext::STANDALONE;
         Self {
             trigger_ref: NodeRef::default(),
+            anchor_ref: NodeRef::default(),
             content_ref: NodeRef::default(),
             open: RwSignal::new(false),
             pointer_inside_trigger: RwSignal::new(false),
@@ -37,21 +96,60 @@ impl Default for TooltipContext {
             tooltip_id: StoredValue::new(String::new()),
             numeric_id: 0,
             avoid_collisions: AvoidCollisions::Flip,
+            delay_duration,
+            skip_delay_duration,
+            disable_hoverable_content,
+            long_press: LongPress::new(DEFAULT_LONG_PRESS_THRESHOLD),
+            open_timer: StoredValue::new(None),
         }
     }
 }
 
 impl TooltipContext {
+    /// Element Content is positioned against: the `Anchor` part when rendered,
+    /// otherwise the trigger.
+    pub fn anchor_element(&self) -> Option<web_sys::Element> {
+        anchor_or_trigger(self.anchor_ref, self.trigger_ref)
+    }
+
     pub fn open(&self) {
         singleton::activate(self.numeric_id);
         self.open.set(true);
     }
 
     pub fn close(&self) {
+        self.cancel_open_timer();
         singleton::deactivate(self.numeric_id);
         self.open.set(false);
     }
 
+    /// Open after `delay_duration`, or immediately when another tooltip is open
+    /// or closed within `skip_delay_duration`.
+    pub fn schedule_open(&self) {
+        self.cancel_open_timer();
+        if self.open.get_untracked() {
+            return;
+        }
+        if self.delay_duration.is_zero() || singleton::should_skip_delay(self.skip_delay_duration)
+        {
+            self.open();
+            return;
+        }
+        let ctx = *self;
+        let handle = leptos::leptos_dom::helpers::set_timeout_with_handle(
+            move || ctx.open(),
+            self.delay_duration,
+        )
+        .ok();
+        self.open_timer.set_value(handle);
+    }
+
+    pub fn cancel_open_timer(&self) {
+        if let Some(h) = self.open_timer.try_update_value(|t| t.take()).flatten() {
+            h.clear();
+        }
+    }
+
     pub fn toggle(&self) {
         if self.open.get() {
             self.close();
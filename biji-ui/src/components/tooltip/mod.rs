@@ -3,7 +3,9 @@ pub(crate) mod singleton;
 pub mod tooltip;
 
 pub use crate::utils::positioning::{AvoidCollisions, Positioning};
+pub use tooltip::Anchor;
 pub use tooltip::Arrow;
 pub use tooltip::Content;
 pub use tooltip::Root;
+pub use tooltip::TooltipProvider as Provider;
 pub use tooltip::Trigger;
@@ -3,15 +3,20 @@
 /// WASM is single-threaded, so `thread_local!` storage is safe and requires no
 /// locking. The pattern mirrors what Radix UI's `TooltipProvider` and melt-ui's
 /// `createTooltipGroup()` do at the framework level, but transparently — callers
-/// do not need to add any provider to their tree.
+/// do not need to add any provider to their tree. An optional `tooltip::Provider`
+/// only tunes the open delays.
 ///
 /// Lifecycle:
 /// 1. `Root` calls `register(id, close_fn)` on mount and `unregister(id)` on cleanup.
 /// 2. `TooltipContext::open()` calls `activate(id)` before opening, which immediately
 ///    closes the previously active tooltip (if any).
-/// 3. `TooltipContext::close()` calls `deactivate(id)` to clear the active slot.
+/// 3. `TooltipContext::close()` calls `deactivate(id)` to clear the active slot and
+///    record the close time, so a trigger hovered shortly afterwards opens instantly.
 use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::utils::clock::{now_ms, within_window};
 
 thread_local! {
     /// The numeric ID of the currently-open tooltip, or `None`.
@@ -19,6 +24,8 @@ thread_local! {
     /// Maps numeric tooltip ID → a closure that closes that tooltip immediately.
     static REGISTRY: RefCell<HashMap<usize, Box<dyn Fn()>>> =
         RefCell::new(HashMap::new());
+    /// `performance.now()` at the moment the last active tooltip closed.
+    static LAST_CLOSED: Cell<Option<f64>> = const { Cell::new(None) };
 }
 
 /// Register a close callback for tooltip `id`. Called from `Root` on mount.
@@ -57,6 +64,19 @@ pub fn deactivate(id: usize) {
     ACTIVE.with(|a| {
         if a.get() == Some(id) {
             a.set(None);
+            LAST_CLOSED.with(|c| c.set(now_ms()));
         }
     });
 }
+
+/// Returns `true` when a tooltip is open or closed less than `skip` ago, in
+/// which case the next tooltip opens without waiting for its delay.
+pub fn should_skip_delay(skip: Duration) -> bool {
+    if ACTIVE.with(|a| a.get()).is_some() {
+        return true;
+    }
+    let Some(now) = now_ms() else {
+        return false;
+    };
+    LAST_CLOSED.with(|c| within_window(c.get(), now, skip))
+}
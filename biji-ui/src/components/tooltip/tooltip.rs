@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use leptos::ev::{blur, focus, mousemove, pointerenter, pointerleave};
+use leptos::ev::{blur, focus, mousemove, pointerdown, pointerenter, pointerleave};
 use leptos::{context::Provider, prelude::*};
 use leptos_use::{
     UseElementBoundingReturn, use_document, use_element_bounding, use_event_listener,
@@ -9,9 +9,16 @@ use wasm_bindgen::JsCast;
 
 use crate::{
     cn,
-    components::tooltip::{context::TooltipContext, singleton},
+    components::tooltip::{
+        context::{
+            DEFAULT_DELAY_DURATION, DEFAULT_SKIP_DELAY_DURATION, TooltipContext,
+            TooltipProviderContext,
+        },
+        singleton,
+    },
     custom_animated_show::CustomAnimatedShow,
     utils::{
+        long_press::{DEFAULT_LONG_PRESS_THRESHOLD, LongPress, is_touch, use_long_press},
         polygon::{get_points_from_el, make_hull, point_in_polygon},
         positioning::{AvoidCollisions, Positioning},
     },
@@ -49,6 +56,19 @@ pub fn Trigger(children: Children, #[prop(into, optional)] class: String) -> imp
     }
 }
 
+/// Optional element that Content is positioned against instead of the Trigger.
+/// Hovering or focusing the Trigger still opens the tooltip.
+#[component]
+pub fn Anchor(children: Children, #[prop(into, optional)] class: String) -> impl IntoView {
+    let tooltip_ctx = expect_context::<TooltipContext>();
+
+    view! {
+        <div node_ref={tooltip_ctx.anchor_ref} class={class}>
+            {children()}
+        </div>
+    }
+}
+
 #[component]
 pub fn TriggerEvents(children: Children) -> impl IntoView {
     let tooltip_ctx = expect_context::<TooltipContext>();
@@ -58,8 +78,6 @@ pub fn TriggerEvents(children: Children) -> impl IntoView {
         left: trigger_left,
         bottom: trigger_bottom,
         right: trigger_right,
-        width: trigger_width,
-        height: trigger_height,
         ..
     } = use_element_bounding(tooltip_ctx.trigger_ref);
 
@@ -69,6 +87,14 @@ pub fn TriggerEvents(children: Children) -> impl IntoView {
         ..
     } = use_element_bounding(tooltip_ctx.content_ref);
 
+    let UseElementBoundingReturn {
+        top: anchor_top,
+        left: anchor_left,
+        width: anchor_width,
+        height: anchor_height,
+        ..
+    } = use_element_bounding(Signal::derive_local(move || tooltip_ctx.anchor_element()));
+
     let polygon_elements = move || {
         let mut trigger_points =
             get_points_from_el(&(trigger_top, trigger_right, trigger_bottom, trigger_left));
@@ -102,20 +128,20 @@ pub fn TriggerEvents(children: Children) -> impl IntoView {
         let eff = tooltip_ctx.positioning.effective_positioning(
             cw,
             ch,
-            trigger_top.get(),
-            trigger_left.get(),
-            trigger_width.get(),
-            trigger_height.get(),
+            anchor_top.get(),
+            anchor_left.get(),
+            anchor_width.get(),
+            anchor_height.get(),
             tooltip_ctx.arrow_size as f64,
             vp_w,
             vp_h,
             tooltip_ctx.avoid_collisions,
         );
         let content_pos = eff.calculate_position(
-            trigger_top.get(),
-            trigger_left.get(),
-            trigger_width.get(),
-            trigger_height.get(),
+            anchor_top.get(),
+            anchor_left.get(),
+            anchor_width.get(),
+            anchor_height.get(),
             ch,
             cw,
             tooltip_ctx.arrow_size as f64,
@@ -135,13 +161,49 @@ pub fn TriggerEvents(children: Children) -> impl IntoView {
 
     let polygon = move || make_hull(&polygon_elements());
 
-    let _ = use_event_listener(tooltip_ctx.trigger_ref, pointerenter, move |_| {
-        tooltip_ctx.pointer_inside_trigger.set(true);
+    let long_press = tooltip_ctx.long_press;
+
+    use_long_press(tooltip_ctx.trigger_ref, long_press, move || {
+        tooltip_ctx.cancel_open_timer();
         tooltip_ctx.open();
     });
 
-    let _ = use_event_listener(tooltip_ctx.trigger_ref, pointerleave, move |_| {
+    // Touch: tapping anywhere outside the trigger and content closes the tooltip.
+    let _ = use_event_listener(use_document(), pointerdown, move |evt| {
+        if !is_touch(&evt) || !tooltip_ctx.open.get_untracked() {
+            return;
+        }
+        let Some(target) = evt.target().and_then(|t| t.dyn_into::<web_sys::Node>().ok()) else {
+            return;
+        };
+        let inside =
+            |el: Option<web_sys::HtmlElement>| el.is_some_and(|el| el.contains(Some(&target)));
+        if inside(tooltip_ctx.trigger_ref.get_untracked().map(Into::into))
+            || inside(tooltip_ctx.content_ref.get_untracked().map(Into::into))
+        {
+            return;
+        }
+        tooltip_ctx.close();
+    });
+
+    let _ = use_event_listener(tooltip_ctx.trigger_ref, pointerenter, move |evt| {
+        long_press.pointer_type.set(evt.pointer_type());
+        if is_touch(&evt) {
+            return;
+        }
+        tooltip_ctx.pointer_inside_trigger.set(true);
+        tooltip_ctx.schedule_open();
+    });
+
+    let _ = use_event_listener(tooltip_ctx.trigger_ref, pointerleave, move |evt| {
+        if is_touch(&evt) {
+            return;
+        }
         tooltip_ctx.pointer_inside_trigger.set(false);
+        tooltip_ctx.cancel_open_timer();
+        if tooltip_ctx.disable_hoverable_content && tooltip_ctx.open.get_untracked() {
+            tooltip_ctx.close();
+        }
     });
 
     let _ = use_event_listener(tooltip_ctx.content_ref, pointerenter, move |_| {
@@ -153,9 +215,10 @@ pub fn TriggerEvents(children: Children) -> impl IntoView {
     });
 
     let _ = use_event_listener(use_document(), mousemove, move |e| {
-        if tooltip_ctx.pointer_inside_content.get()
-            || tooltip_ctx.pointer_inside_trigger.get()
-            || point_in_polygon((e.x() as f64, e.y() as f64), &polygon())
+        if long_press.is_recent_touch() || tooltip_ctx.pointer_inside_trigger.get()
+            || (!tooltip_ctx.disable_hoverable_content
+                && (tooltip_ctx.pointer_inside_content.get()
+                    || point_in_polygon((e.x() as f64, e.y() as f64), &polygon())))
         {
             return;
         }
@@ -165,6 +228,10 @@ pub fn TriggerEvents(children: Children) -> impl IntoView {
     });
 
     let _ = use_event_listener(tooltip_ctx.trigger_ref, focus, move |_| {
+        // A tap focuses the trigger; only a long press should open on touch.
+        if long_press.is_recent_touch() {
+            return;
+        }
         tooltip_ctx.open();
     });
 
@@ -177,6 +244,39 @@ pub fn TriggerEvents(children: Children) -> impl IntoView {
     children()
 }
 
+/// Shares open delays between every tooltip inside it. Optional: tooltips
+/// outside a provider open as soon as they are hovered.
+///
+/// # Example
+/// ```rust,ignore
+/// <tooltip::Provider delay_duration={Duration::from_millis(400)}>
+///     <Toolbar />
+/// </tooltip::Provider>
+/// ```
+#[component]
+pub fn TooltipProvider(
+    children: Children,
+    /// Delay between the pointer entering a trigger and its tooltip opening.
+    #[prop(default = DEFAULT_DELAY_DURATION)]
+    delay_duration: Duration,
+    /// After a tooltip closes, hovering another trigger within this window opens
+    /// it immediately.
+    #[prop(default = DEFAULT_SKIP_DELAY_DURATION)]
+    skip_delay_duration: Duration,
+    /// Close tooltips as soon as the pointer leaves the trigger, instead of
+    /// keeping them open while the pointer moves onto the content.
+    #[prop(default = false)]
+    disable_hoverable_content: bool,
+) -> impl IntoView {
+    let ctx = TooltipProviderContext {
+        delay_duration,
+        skip_delay_duration,
+        disable_hoverable_content,
+    };
+
+    view! { <Provider value={ctx}>{children()}</Provider> }
+}
+
 #[component]
 pub fn Root(
     #[prop(into, optional)] class: String,
@@ -186,14 +286,33 @@ pub fn Root(
     hide_delay: Duration,
     #[prop(default = Positioning::default())] positioning: Positioning,
     #[prop(default = AvoidCollisions::Flip)] avoid_collisions: AvoidCollisions,
+    /// Overrides the provider's `delay_duration` for this tooltip.
+    #[prop(optional)]
+    delay_duration: Option<Duration>,
+    /// Overrides the provider's `skip_delay_duration` for this tooltip.
+    #[prop(optional)]
+    skip_delay_duration: Option<Duration>,
+    /// Overrides the provider's `disable_hoverable_content` for this tooltip.
+    #[prop(optional)]
+    disable_hoverable_content: Option<bool>,
+    /// How long a touch must rest on the trigger to open the tooltip.
+    #[prop(default = DEFAULT_LONG_PRESS_THRESHOLD)]
+    long_press_threshold: Duration,
 ) -> impl IntoView {
     let (numeric_id, string_id) = next_tooltip_id();
     let open_signal = RwSignal::new(false);
+    let provider =
+        use_context::<TooltipProviderContext>().unwrap_or(TooltipProviderContext::STANDALONE);
 
     let ctx = TooltipContext {
         hide_delay,
         positioning,
         avoid_collisions,
+        delay_duration: delay_duration.unwrap_or(provider.delay_duration),
+        skip_delay_duration: skip_delay_duration.unwrap_or(provider.skip_delay_duration),
+        disable_hoverable_content: disable_hoverable_content
+            .unwrap_or(provider.disable_hoverable_content),
+        long_press: LongPress::new(long_press_threshold),
         numeric_id,
         tooltip_id: StoredValue::new(string_id),
         open: open_signal,
@@ -201,7 +320,10 @@ pub fn Root(
     };
 
     singleton::register(numeric_id, move || open_signal.set(false));
-    on_cleanup(move || singleton::unregister(numeric_id));
+    on_cleanup(move || {
+        ctx.cancel_open_timer();
+        singleton::unregister(numeric_id);
+    });
 
     view! {
         <Provider value={ctx}>
@@ -239,7 +361,7 @@ pub fn Content(
         width,
         height,
         ..
-    } = use_element_bounding(tooltip_ctx.trigger_ref);
+    } = use_element_bounding(Signal::derive_local(move || tooltip_ctx.anchor_element()));
 
     let style_signal = Signal::derive(move || {
         let raw_cw = *content_width.read();
@@ -270,14 +392,10 @@ pub fn Content(
         if cw == 0.0 && ch == 0.0 {
             return hidden();
         }
-        let Some(trigger) = tooltip_ctx.trigger_ref.get_untracked() else {
-            return hidden();
-        };
-        let trigger_node: &web_sys::Node = trigger.as_ref();
-        let Some(trigger_el) = trigger_node.dyn_ref::<web_sys::Element>() else {
+        let Some(anchor) = tooltip_ctx.anchor_element() else {
             return hidden();
         };
-        let rect = trigger_el.get_bounding_client_rect();
+        let rect = anchor.get_bounding_client_rect();
         let (t_top, t_left, t_width, t_height) =
             (rect.top(), rect.left(), rect.width(), rect.height());
         let vp_w = web_sys::window()
@@ -322,6 +440,7 @@ pub fn Content(
             node_ref={content_ref}
             attr:id={tooltip_ctx.tooltip_id.get_value()}
             attr:role="tooltip"
+            attr:data-pointer-type={move || tooltip_ctx.long_press.pointer_type.get()}
         >
             {children()}
         </CustomAnimatedShow>
@@ -3,7 +3,9 @@
 //! These abstractions power the keyboard navigation logic shared across accordion,
 //! menu, and menubar components.
 
-use std::collections::HashMap;
+use std::{any::Any, collections::HashMap};
+
+use leptos::prelude::*;
 
 /// Filter a collection down to its *active* (non-disabled) items.
 pub trait FilterActiveItems<T> {
@@ -130,3 +132,298 @@ where
 
     items.get(current_pos - 1).copied()
 }
+
+/// One entry of a data-driven collection, passed to a list component's `Items` part.
+///
+/// `T` carries any extra payload the render closure needs (an icon, a record id,
+/// …). Components keep a type-erased copy (`CollectionItem<()>`) in their
+/// context, so the whole collection is known even when only some of it is
+/// mounted.
+///
+/// # Example
+///
+/// ```rust
+/// use biji_ui::items::CollectionItem;
+///
+/// let item = CollectionItem::new("gb", "United Kingdom")
+///     .keywords(["uk", "britain"])
+///     .group("Europe");
+/// assert!(item.matches("brit"));
+/// assert!(!item.matches("france"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollectionItem<T = ()> {
+    pub value: String,
+    /// Display text; also used for filtering.
+    pub label: String,
+    pub disabled: bool,
+    /// Consecutive items with the same group are rendered together under its heading.
+    pub group: Option<String>,
+    /// Extra search terms matched in addition to the label.
+    pub keywords: Vec<String>,
+    pub data: T,
+}
+
+impl CollectionItem {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            disabled: false,
+            group: None,
+            keywords: Vec::new(),
+            data: (),
+        }
+    }
+}
+
+impl<T> CollectionItem<T> {
+    /// Attach a payload, replacing the current one.
+    pub fn with_data<U>(self, data: U) -> CollectionItem<U> {
+        CollectionItem {
+            value: self.value,
+            label: self.label,
+            disabled: self.disabled,
+            group: self.group,
+            keywords: self.keywords,
+            data,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    pub fn keywords<I, S>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.keywords = keywords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Case-insensitive substring match against the label and keywords. An empty
+    /// query matches everything.
+    pub fn matches(&self, query: &str) -> bool {
+        let q = query.to_lowercase();
+        q.is_empty()
+            || self.label.to_lowercase().contains(&q)
+            || self.keywords.iter().any(|k| k.to_lowercase().contains(&q))
+    }
+
+    /// Copy without the payload, as stored in component contexts.
+    pub fn erase(&self) -> CollectionItem {
+        CollectionItem {
+            value: self.value.clone(),
+            label: self.label.clone(),
+            disabled: self.disabled,
+            group: self.group.clone(),
+            keywords: self.keywords.clone(),
+            data: (),
+        }
+    }
+}
+
+/// Split a collection into runs of consecutive items sharing the same group.
+pub fn group_runs<T: Clone>(
+    items: &[CollectionItem<T>],
+) -> Vec<(Option<String>, Vec<CollectionItem<T>>)> {
+    let mut runs: Vec<(Option<String>, Vec<CollectionItem<T>>)> = Vec::new();
+    for item in items {
+        match runs.last_mut() {
+            Some((group, run)) if *group == item.group => run.push(item.clone()),
+            _ => runs.push((item.group.clone(), vec![item.clone()])),
+        }
+    }
+    runs
+}
+
+/// Entries whose label or keywords match `query`. Disabled entries are kept so
+/// they render as disabled rows.
+pub fn filter_matching<T: Clone>(
+    items: &[CollectionItem<T>],
+    query: &str,
+) -> Vec<CollectionItem<T>> {
+    items
+        .iter()
+        .filter(|item| item.matches(query))
+        .cloned()
+        .collect()
+}
+
+/// Render `items` through `row`, wrapping each run of grouped items in a
+/// `role="group"` element whose first child is the group name. Used by the
+/// `Items` parts of the list components.
+///
+/// Rows are not keyed. Items take their index from the list's registration
+/// counter, so re-creating every row is what keeps indexes in display order.
+pub fn render_groups<T, V>(
+    items: &[CollectionItem<T>],
+    group_class: &str,
+    group_label_class: &str,
+    row: impl Fn(CollectionItem<T>) -> V,
+) -> AnyView
+where
+    T: Clone,
+    V: IntoView + 'static,
+{
+    group_runs(items)
+        .into_iter()
+        .map(|(group, run)| {
+            let rows = run.into_iter().map(&row).collect_view();
+            match group {
+                Some(group) => {
+                    let heading = group.clone();
+                    view! {
+                        <div role="group" aria-label={group} class={group_class.to_string()}>
+                            <div aria-hidden="true" class={group_label_class.to_string()}>
+                                {heading}
+                            </div>
+                            {rows}
+                        </div>
+                    }
+                    .into_any()
+                }
+                None => rows.into_any(),
+            }
+        })
+        .collect_view()
+        .into_any()
+}
+
+/// A data-driven item collection held by a list component's context.
+///
+/// Pass one to a component's `items` prop; any `Signal`, `RwSignal`, `Memo` or
+/// plain `Vec` of [`CollectionItem<T>`] converts into it. The component then knows
+/// every item, including ones that are filtered out or not mounted yet, and its
+/// `Items` part renders them with a closure taking `CollectionItem<T>`.
+#[derive(Copy, Clone)]
+pub struct Collection {
+    items: Signal<Vec<CollectionItem>>,
+    source: StoredValue<Box<dyn Any + Send + Sync>>,
+}
+
+impl Collection {
+    pub fn new<T>(items: Signal<Vec<CollectionItem<T>>>) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let erased =
+            Memo::new(move |_| items.with(|v| v.iter().map(CollectionItem::erase).collect()));
+        Self {
+            items: erased.into(),
+            source: StoredValue::new(Box::new(items)),
+        }
+    }
+
+    /// Every item, without payloads.
+    pub fn items(&self) -> Signal<Vec<CollectionItem>> {
+        self.items
+    }
+
+    /// Label of the item with `value`, if any.
+    pub fn label_of(&self, value: &str) -> Option<String> {
+        self.items.with(|items| {
+            items
+                .iter()
+                .find(|item| item.value == value)
+                .map(|item| item.label.clone())
+        })
+    }
+
+    /// The original typed signal, or `None` if `T` differs from the type the
+    /// collection was created with.
+    pub fn typed<T>(&self) -> Option<Signal<Vec<CollectionItem<T>>>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.source.with_value(|source| {
+            source
+                .downcast_ref::<Signal<Vec<CollectionItem<T>>>>()
+                .copied()
+        })
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> From<Signal<Vec<CollectionItem<T>>>> for Collection {
+    fn from(items: Signal<Vec<CollectionItem<T>>>) -> Self {
+        Self::new(items)
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> From<RwSignal<Vec<CollectionItem<T>>>> for Collection {
+    fn from(items: RwSignal<Vec<CollectionItem<T>>>) -> Self {
+        Self::new(items.into())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> From<ReadSignal<Vec<CollectionItem<T>>>> for Collection {
+    fn from(items: ReadSignal<Vec<CollectionItem<T>>>) -> Self {
+        Self::new(items.into())
+    }
+}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> From<Memo<Vec<CollectionItem<T>>>>
+    for Collection
+{
+    fn from(items: Memo<Vec<CollectionItem<T>>>) -> Self {
+        Self::new(items.into())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> From<Vec<CollectionItem<T>>> for Collection {
+    fn from(items: Vec<CollectionItem<T>>) -> Self {
+        Self::new(Signal::stored(items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_label_and_keywords() {
+        let item = CollectionItem::new("ts", "TypeScript").keywords(["JS", "node"]);
+        assert!(item.matches(""));
+        assert!(item.matches("script"));
+        assert!(item.matches("js"));
+        assert!(!item.matches("rust"));
+    }
+
+    #[test]
+    fn group_runs_keeps_order() {
+        let items = vec![
+            CollectionItem::new("a", "A").group("x"),
+            CollectionItem::new("b", "B").group("x"),
+            CollectionItem::new("c", "C"),
+            CollectionItem::new("d", "D").group("x"),
+        ];
+        let runs = group_runs(&items);
+        let shape: Vec<_> = runs
+            .iter()
+            .map(|(g, run)| (g.as_deref(), run.len()))
+            .collect();
+        assert_eq!(shape, [(Some("x"), 2), (None, 1), (Some("x"), 1)]);
+    }
+
+    #[test]
+    fn filter_matching_keeps_disabled_matches() {
+        let items = vec![
+            CollectionItem::new("a", "Apple"),
+            CollectionItem::new("b", "Apricot").disabled(true),
+            CollectionItem::new("c", "Cherry"),
+        ];
+        let values: Vec<_> = filter_matching(&items, "ap")
+            .into_iter()
+            .map(|item| item.value)
+            .collect();
+        assert_eq!(values, ["a", "b"]);
+    }
+}
@@ -45,6 +45,9 @@ pub mod items;
 /// Utility modules for positioning, scroll prevention, and polygon math.
 pub mod utils;
 
+/// Windowed rendering for long lists in select, combobox and command.
+pub mod virtualizer;
+
 /// A trait for converting a value into a CSS class string.
 ///
 /// This trait is used by the [`cn!`] macro to normalize different string types
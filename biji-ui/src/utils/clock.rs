@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+/// Milliseconds since page load, from `performance.now()`.
+pub fn now_ms() -> Option<f64> {
+    Some(web_sys::window()?.performance()?.now())
+}
+
+/// Whether `last`, a timestamp from [`now_ms`], is less than `window` before
+/// `now`.
+pub fn within_window(last: Option<f64>, now: f64, window: Duration) -> bool {
+    last.is_some_and(|last| now - last < window.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_window_is_exclusive() {
+        let window = Duration::from_millis(300);
+        assert!(!within_window(None, 1000.0, window));
+        assert!(within_window(Some(800.0), 1000.0, window));
+        assert!(!within_window(Some(700.0), 1000.0, window));
+        assert!(!within_window(Some(800.0), 1000.0, Duration::ZERO));
+    }
+}
@@ -0,0 +1,61 @@
+use leptos::prelude::document;
+use wasm_bindgen::JsCast;
+
+const FOCUSABLE: &str = r#"a[href], button:not([disabled]), textarea:not([disabled]), input:not([disabled]), select:not([disabled]), [tabindex]:not([tabindex="-1"])"#;
+
+/// Query all focusable elements inside the given container.
+pub fn get_focusable_elements(container: &web_sys::HtmlElement) -> Vec<web_sys::HtmlElement> {
+    let Ok(node_list) = container.query_selector_all(FOCUSABLE) else {
+        return Vec::new();
+    };
+    let mut elements = Vec::new();
+    for i in 0..node_list.length() {
+        if let Some(node) = node_list.get(i) {
+            if let Ok(el) = node.dyn_into::<web_sys::HtmlElement>() {
+                elements.push(el);
+            }
+        }
+    }
+    elements
+}
+
+/// Focus the first focusable element inside the container, or the container
+/// itself (which then needs a `tabindex`).
+pub fn focus_first_element(container: &web_sys::HtmlElement) {
+    if let Some(first) = get_focusable_elements(container).first() {
+        let _ = first.focus();
+        return;
+    }
+    let _ = container.focus();
+}
+
+/// Keep Tab / Shift+Tab cycling inside the container. Call from a `keydown`
+/// listener when the key is `Tab`.
+pub fn trap_tab_focus(
+    container: &web_sys::HtmlElement,
+    shift_key: bool,
+    evt: &web_sys::KeyboardEvent,
+) {
+    let focusable = get_focusable_elements(container);
+    let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+        evt.prevent_default();
+        return;
+    };
+    let active = document().active_element();
+    let is_active = |el: &web_sys::HtmlElement| {
+        active
+            .as_ref()
+            .is_some_and(|a| a.is_same_node(Some(el.unchecked_ref())))
+    };
+    let focus_inside = active
+        .as_ref()
+        .is_some_and(|a| container.contains(Some(a.unchecked_ref())));
+
+    if shift_key && (is_active(first) || !focus_inside) {
+        evt.prevent_default();
+        let _ = last.focus();
+    } else if !shift_key && (is_active(last) || !focus_inside) {
+        evt.prevent_default();
+        let _ = first.focus();
+    }
+}
@@ -0,0 +1,75 @@
+use wasm_bindgen::JsCast;
+
+/// Elements hidden by [`hide_others`]. Call [`HiddenOthers::restore`] to undo.
+#[derive(Default)]
+pub struct HiddenOthers {
+    elements: Vec<web_sys::Element>,
+}
+
+/// Mark everything outside `targets` as `inert` and `aria-hidden="true"`, so
+/// that a modal surface is the only part of the page that can be reached with
+/// the mouse, the keyboard or assistive technology.
+///
+/// Pass the surface together with any element that must stay reachable, such
+/// as the trigger that focus returns to. Walks down from `<body>` and hides
+/// every element that neither is nor contains one of `targets`. Elements that
+/// are already inert or hidden, live regions (`aria-live`, e.g. toasts) and
+/// `<script>` / `<style>` tags are left alone.
+pub fn hide_others(targets: &[web_sys::Element]) -> HiddenOthers {
+    let mut hidden = HiddenOthers::default();
+    let body = targets
+        .first()
+        .and_then(|t| t.owner_document())
+        .and_then(|d| d.body());
+    if let Some(body) = body {
+        hide_outside(&body, targets, &mut hidden);
+    }
+    hidden
+}
+
+fn hide_outside(
+    parent: &web_sys::Element,
+    targets: &[web_sys::Element],
+    hidden: &mut HiddenOthers,
+) {
+    let children = parent.children();
+    for i in 0..children.length() {
+        let Some(child) = children.item(i) else {
+            continue;
+        };
+        let child_node: &web_sys::Node = child.unchecked_ref();
+        if targets.iter().any(|t| t.is_same_node(Some(child_node))) {
+            continue;
+        }
+        if targets
+            .iter()
+            .any(|t| child.contains(Some(t.unchecked_ref())))
+        {
+            hide_outside(&child, targets, hidden);
+            continue;
+        }
+        if !should_hide(&child) {
+            continue;
+        }
+        let _ = child.set_attribute("inert", "");
+        let _ = child.set_attribute("aria-hidden", "true");
+        hidden.elements.push(child);
+    }
+}
+
+impl HiddenOthers {
+    pub fn restore(self) {
+        for el in self.elements {
+            let _ = el.remove_attribute("inert");
+            let _ = el.remove_attribute("aria-hidden");
+        }
+    }
+}
+
+fn should_hide(el: &web_sys::Element) -> bool {
+    let tag = el.tag_name().to_ascii_lowercase();
+    !matches!(tag.as_str(), "script" | "style" | "template")
+        && !el.has_attribute("inert")
+        && !el.has_attribute("aria-hidden")
+        && !el.has_attribute("aria-live")
+}
@@ -0,0 +1,199 @@
+use std::time::Duration;
+
+use leptos::{
+    ev::{click, contextmenu, pointercancel, pointerdown, pointermove, pointerup},
+    leptos_dom::{self, helpers::TimeoutHandle},
+    prelude::*,
+};
+use leptos_use::{
+    UseEventListenerOptions, core::IntoElementMaybeSignal, use_event_listener,
+    use_event_listener_with_options,
+};
+
+use super::clock::{now_ms, within_window};
+
+/// Default time a finger must rest on a trigger before it counts as a long press.
+pub const DEFAULT_LONG_PRESS_THRESHOLD: Duration = Duration::from_millis(500);
+
+/// Browsers emit compatibility mouse events (and focus) shortly after a touch
+/// ends. Hover handlers ignore events inside this window.
+const SYNTHETIC_MOUSE_WINDOW: Duration = Duration::from_millis(800);
+
+/// Default distance in CSS pixels a touch may move before it is treated as a
+/// scroll rather than a long press.
+pub const DEFAULT_LONG_PRESS_TOLERANCE: f64 = 10.0;
+
+/// Touch state shared by hover-driven components such as tooltips and hover
+/// cards, which cannot rely on `pointerenter` on touch screens.
+///
+/// Create one per component with [`LongPress::new`], attach it to the trigger
+/// with [`use_long_press`], and skip hover and focus handling while
+/// [`LongPress::is_recent_touch`] is `true`.
+#[derive(Copy, Clone)]
+pub struct LongPress {
+    /// `PointerEvent.pointerType` of the last interaction with the trigger:
+    /// `"mouse"`, `"touch"` or `"pen"`.
+    pub pointer_type: RwSignal<String>,
+    pub threshold: Duration,
+    /// How far in CSS pixels the touch may move before the press is cancelled.
+    pub move_tolerance: f64,
+    /// `true` from a touch `pointerdown` on the trigger until the finger lifts,
+    /// moves away or the press is cancelled.
+    pub pressing: RwSignal<bool>,
+    timer: StoredValue<Option<TimeoutHandle>>,
+    origin: StoredValue<Option<(f64, f64)>>,
+    last_touch: StoredValue<Option<f64>>,
+    fired: StoredValue<bool>,
+}
+
+impl LongPress {
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            pointer_type: RwSignal::new(String::from("mouse")),
+            threshold,
+            move_tolerance: DEFAULT_LONG_PRESS_TOLERANCE,
+            pressing: RwSignal::new(false),
+            timer: StoredValue::new(None),
+            origin: StoredValue::new(None),
+            last_touch: StoredValue::new(None),
+            fired: StoredValue::new(false),
+        }
+    }
+
+    pub fn with_move_tolerance(mut self, move_tolerance: f64) -> Self {
+        self.move_tolerance = move_tolerance;
+        self
+    }
+
+    /// Viewport coordinates where the current touch started. Still set when
+    /// the long-press callback runs.
+    pub fn origin(&self) -> Option<(f64, f64)> {
+        self.origin.get_value()
+    }
+
+    /// Cancel a pending long press.
+    pub fn cancel(&self) {
+        if let Some(h) = self.timer.try_update_value(|t| t.take()).flatten() {
+            h.clear();
+        }
+        self.origin.set_value(None);
+        if self.pressing.try_get_untracked() == Some(true) {
+            self.pressing.set(false);
+        }
+    }
+
+    /// `true` while compatibility mouse and focus events from the last touch
+    /// may still arrive.
+    pub fn is_recent_touch(&self) -> bool {
+        let Some(now) = now_ms() else {
+            return false;
+        };
+        within_window(self.last_touch.get_value(), now, SYNTHETIC_MOUSE_WINDOW)
+    }
+
+    fn mark_touch(&self) {
+        self.last_touch.set_value(now_ms());
+    }
+}
+
+pub fn is_touch(evt: &web_sys::PointerEvent) -> bool {
+    evt.pointer_type() == "touch"
+}
+
+/// Attach long-press detection to `target`.
+///
+/// A touch that rests for `long_press.threshold` without moving runs
+/// `on_long_press`. The native context menu and the click that follow a long
+/// press are suppressed.
+pub fn use_long_press<El, M>(
+    target: El,
+    long_press: LongPress,
+    on_long_press: impl Fn() + Clone + 'static,
+) where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M> + Clone,
+{
+    let _ = use_event_listener(target.clone(), pointerdown, move |evt| {
+        long_press.pointer_type.set(evt.pointer_type());
+        if !is_touch(&evt) {
+            return;
+        }
+        long_press.cancel();
+        long_press.mark_touch();
+        long_press.fired.set_value(false);
+        long_press.pressing.set(true);
+        long_press
+            .origin
+            .set_value(Some((evt.client_x() as f64, evt.client_y() as f64)));
+        let on_long_press = on_long_press.clone();
+        let handle = leptos_dom::helpers::set_timeout_with_handle(
+            move || {
+                long_press.timer.set_value(None);
+                long_press.fired.set_value(true);
+                on_long_press();
+            },
+            long_press.threshold,
+        )
+        .ok();
+        long_press.timer.set_value(handle);
+    });
+
+    let _ = use_event_listener(target.clone(), pointermove, move |evt| {
+        if !is_touch(&evt) {
+            return;
+        }
+        let point = (evt.client_x() as f64, evt.client_y() as f64);
+        if let Some(origin) = long_press.origin.get_value() {
+            if moved_beyond(origin, point, long_press.move_tolerance) {
+                long_press.cancel();
+            }
+        }
+    });
+
+    let _ = use_event_listener(target.clone(), pointerup, move |evt| {
+        if is_touch(&evt) {
+            long_press.mark_touch();
+            long_press.cancel();
+        }
+    });
+
+    let _ = use_event_listener(target.clone(), pointercancel, move |_| {
+        long_press.cancel();
+    });
+
+    let _ = use_event_listener(target.clone(), contextmenu, move |evt| {
+        if long_press.is_recent_touch() {
+            evt.prevent_default();
+        }
+    });
+
+    let _ = use_event_listener_with_options(
+        target,
+        click,
+        move |evt| {
+            if long_press.fired.get_value() {
+                long_press.fired.set_value(false);
+                evt.prevent_default();
+                evt.stop_propagation();
+            }
+        },
+        UseEventListenerOptions::default().capture(true),
+    );
+
+    on_cleanup(move || long_press.cancel());
+}
+
+fn moved_beyond(origin: (f64, f64), point: (f64, f64), tolerance: f64) -> bool {
+    let (dx, dy) = (point.0 - origin.0, point.1 - origin.1);
+    dx * dx + dy * dy > tolerance * tolerance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_moves_stay_within_tolerance() {
+        assert!(!moved_beyond((0.0, 0.0), (6.0, 8.0), DEFAULT_LONG_PRESS_TOLERANCE));
+        assert!(moved_beyond((0.0, 0.0), (6.0, 9.0), DEFAULT_LONG_PRESS_TOLERANCE));
+    }
+}
@@ -1,3 +1,10 @@
+pub mod clock;
+pub mod focus_trap;
+pub mod hide_others;
 pub mod polygon;
 pub mod positioning;
+pub mod long_press;
+pub mod modal_stack;
 pub mod prevent_scroll;
+pub mod shortcut;
+pub mod stepping;
@@ -0,0 +1,144 @@
+//! Stack of open modal layers (dialogs, alert dialogs, drawers).
+//!
+//! Each modal owns a [`ModalLayer`] and registers it with [`use_modal_layer`]
+//! while open. The stack decides which layer is on top, so that only the top
+//! layer handles Escape, outside clicks and the Tab focus trap, and lets stacked
+//! layers style themselves through `data-nested` / `data-nested-count`. When a
+//! layer closes, focus returns to the element that was focused when it opened.
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+static LAYER_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// IDs of the open layers, bottom first.
+    static STACK: ArcRwSignal<Vec<usize>> = ArcRwSignal::new(Vec::new());
+}
+
+#[derive(Copy, Clone)]
+pub struct ModalLayer {
+    pub id: usize,
+    previous_focus: StoredValue<Option<web_sys::HtmlElement>, LocalStorage>,
+}
+
+impl Default for ModalLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ModalLayer {
+    pub fn new() -> Self {
+        Self {
+            id: LAYER_ID_COUNTER.fetch_add(1, Ordering::Relaxed),
+            previous_focus: StoredValue::new_local(None),
+        }
+    }
+
+    /// `true` when no other open layer is stacked above this one. Not reactive;
+    /// meant for event handlers.
+    pub fn is_top(&self) -> bool {
+        STACK.with(|s| s.with_untracked(|v| layers_above(v, self.id) == 0))
+    }
+
+    /// `true` while this layer is open on top of another open layer.
+    pub fn is_nested(&self) -> bool {
+        STACK.with(|s| s.with(|v| layers_below(v, self.id) > 0))
+    }
+
+    /// Number of open layers stacked above this one.
+    pub fn nested_count(&self) -> usize {
+        STACK.with(|s| s.with(|v| layers_above(v, self.id)))
+    }
+}
+
+/// Push `layer` onto the stack while `is_open` returns `true`.
+///
+/// Opening records the focused element (or `fallback_focus` when focus is on
+/// `<body>`); closing pops the layer and, if it was on top, restores that focus.
+pub fn use_modal_layer<F, G>(
+    layer: ModalLayer,
+    is_open: F,
+    fallback_focus: G,
+) -> RenderEffect<bool>
+where
+    F: Fn() -> bool + 'static,
+    G: Fn() -> Option<web_sys::HtmlElement> + 'static,
+{
+    on_cleanup(move || {
+        STACK.with(|s| s.update(|v| remove_layer(v, layer.id)));
+    });
+
+    RenderEffect::new(move |was_open: Option<bool>| {
+        let open = is_open();
+        match (was_open.unwrap_or(false), open) {
+            (false, true) => {
+                let focused = document()
+                    .active_element()
+                    .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok())
+                    .filter(|el| document().body().as_ref() != Some(el))
+                    .or_else(&fallback_focus);
+                layer.previous_focus.set_value(focused);
+                STACK.with(|s| s.update(|v| push_layer(v, layer.id)));
+            }
+            (true, false) => {
+                let was_top = layer.is_top();
+                STACK.with(|s| s.update(|v| remove_layer(v, layer.id)));
+                let previous = layer
+                    .previous_focus
+                    .try_update_value(|f| f.take())
+                    .flatten();
+                if let Some(el) = previous.filter(|el| was_top && el.is_connected()) {
+                    let _ = el.focus();
+                }
+            }
+            _ => {}
+        }
+        open
+    })
+}
+
+fn push_layer(stack: &mut Vec<usize>, id: usize) {
+    stack.retain(|&l| l != id);
+    stack.push(id);
+}
+
+fn remove_layer(stack: &mut Vec<usize>, id: usize) {
+    stack.retain(|&l| l != id);
+}
+
+fn layers_above(stack: &[usize], id: usize) -> usize {
+    stack
+        .iter()
+        .position(|&l| l == id)
+        .map_or(0, |pos| stack.len() - pos - 1)
+}
+
+fn layers_below(stack: &[usize], id: usize) -> usize {
+    stack.iter().position(|&l| l == id).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_order() {
+        let mut stack = Vec::new();
+        push_layer(&mut stack, 1);
+        push_layer(&mut stack, 2);
+        push_layer(&mut stack, 3);
+        assert_eq!(layers_above(&stack, 1), 2);
+        assert_eq!(layers_below(&stack, 1), 0);
+        assert_eq!(layers_above(&stack, 3), 0);
+        assert_eq!(layers_below(&stack, 3), 2);
+
+        remove_layer(&mut stack, 2);
+        assert_eq!(layers_above(&stack, 1), 1);
+        push_layer(&mut stack, 1);
+        assert_eq!(stack, [3, 1]);
+        assert_eq!(layers_above(&stack, 9), 0);
+    }
+}
@@ -1,3 +1,25 @@
+use leptos::{
+    html::{Div, ElementType},
+    prelude::*,
+};
+use wasm_bindgen::JsCast;
+
+/// Element an overlay's content is positioned against: the `Anchor` part when
+/// it is rendered, otherwise the trigger.
+pub fn anchor_or_trigger<E>(
+    anchor_ref: NodeRef<Div>,
+    trigger_ref: NodeRef<E>,
+) -> Option<web_sys::Element>
+where
+    E: ElementType,
+    E::Output: JsCast + Clone + 'static,
+{
+    anchor_ref
+        .get()
+        .map(Into::into)
+        .or_else(|| trigger_ref.get().map(JsCast::unchecked_into))
+}
+
 /// Controls how positioned overlays react when they would overflow the viewport.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub enum AvoidCollisions {
@@ -1,4 +1,11 @@
-use std::time::Duration;
+use std::{
+    cell::Cell,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
 
 use leptos::{
     leptos_dom::{self, helpers::TimeoutHandle},
@@ -11,6 +18,9 @@ use leptos::{
 /// prevent background scrolling. It also compensates for scrollbar width to prevent
 /// layout shift.
 ///
+/// Locks are reference counted, so nested overlays can each call this: the
+/// document stays locked until the last of them releases.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -25,47 +35,119 @@ pub fn use_prevent_scroll<F>(should_prevent: F, hide_delay: Duration) -> RenderE
 where
     F: Fn() -> bool + 'static,
 {
-    let hide_handle: StoredValue<Option<TimeoutHandle>> = StoredValue::new(None);
+    // Shared with `on_cleanup`, which may run after the owner's stored values
+    // are disposed.
+    let held = Arc::new(AtomicBool::new(false));
+    let held_cleanup = Arc::clone(&held);
+
+    on_cleanup(move || {
+        if held_cleanup.swap(false, Ordering::Relaxed) {
+            unlock(hide_delay);
+        }
+    });
 
     RenderEffect::new(move |_| {
-        if should_prevent() {
-            // Clear any pending hide timeout
-            if let Some(h) = hide_handle.get_value() {
-                h.clear();
-            }
+        let prevent = should_prevent();
+        if prevent && !held.swap(true, Ordering::Relaxed) {
+            lock();
+        } else if !prevent && held.swap(false, Ordering::Relaxed) {
+            unlock(hide_delay);
+        }
+    })
+}
 
-            // Apply prevent scroll styles
-            if let Some(doc) = document().body() {
-                let client_width = f64::from(doc.client_width());
-                let inner_width = window()
-                    .inner_width()
-                    .unwrap()
-                    .as_f64()
-                    .unwrap_or(client_width);
-                let scrollbar_width = inner_width - client_width;
+thread_local! {
+    /// Number of components currently preventing scroll. Styles are applied when
+    /// it goes from 0 to 1 and removed (after the hide delay) when it drops to 0.
+    static LOCK_COUNT: Cell<usize> = const { Cell::new(0) };
+    static STYLES_APPLIED: Cell<bool> = const { Cell::new(false) };
+    static PENDING_RELEASE: Cell<Option<TimeoutHandle>> = const { Cell::new(None) };
+}
+
+fn lock() {
+    if !acquire() {
+        return;
+    }
+    if let Some(h) = PENDING_RELEASE.with(|p| p.take()) {
+        h.clear();
+    }
+    if STYLES_APPLIED.with(|a| a.replace(true)) {
+        return;
+    }
+    if let Some(doc) = document().body() {
+        let client_width = f64::from(doc.client_width());
+        let inner_width = window()
+            .inner_width()
+            .unwrap()
+            .as_f64()
+            .unwrap_or(client_width);
+        let scrollbar_width = inner_width - client_width;
+
+        let _ = doc.style().set_property("overflow", "hidden");
+        let _ = doc
+            .style()
+            .set_property("--scrollbar-width", &format!("{}px", scrollbar_width));
+        let _ = doc
+            .style()
+            .set_property("padding-right", &format!("calc({}px)", scrollbar_width));
+    }
+}
 
-                let _ = doc.style().set_property("overflow", "hidden");
-                let _ = doc
-                    .style()
-                    .set_property("--scrollbar-width", &format!("{}px", scrollbar_width));
-                let _ = doc
-                    .style()
-                    .set_property("padding-right", &format!("calc({}px)", scrollbar_width));
+fn unlock(hide_delay: Duration) {
+    if !release() {
+        return;
+    }
+    // Wait for the closing animation before restoring the scrollbar.
+    let h = leptos_dom::helpers::set_timeout_with_handle(
+        move || {
+            PENDING_RELEASE.with(|p| p.set(None));
+            if LOCK_COUNT.with(|c| c.get()) > 0 {
+                return;
             }
-        } else {
-            // Schedule removal of prevent scroll styles
-            let h = leptos_dom::helpers::set_timeout_with_handle(
-                move || {
-                    if let Some(doc) = document().body() {
-                        let _ = doc.style().remove_property("overflow");
-                        let _ = doc.style().remove_property("--scrollbar-width");
-                        let _ = doc.style().remove_property("padding-right");
-                    }
-                },
-                hide_delay,
-            )
-            .expect("set timeout in use_prevent_scroll");
-            hide_handle.set_value(Some(h));
-        }
+            STYLES_APPLIED.with(|a| a.set(false));
+            if let Some(doc) = document().body() {
+                let _ = doc.style().remove_property("overflow");
+                let _ = doc.style().remove_property("--scrollbar-width");
+                let _ = doc.style().remove_property("padding-right");
+            }
+        },
+        hide_delay,
+    )
+    .expect("set timeout in use_prevent_scroll");
+    if let Some(prev) = PENDING_RELEASE.with(|p| p.replace(Some(h))) {
+        prev.clear();
+    }
+}
+
+/// Increment the lock count. Returns `true` for the first lock.
+fn acquire() -> bool {
+    LOCK_COUNT.with(|c| {
+        c.set(c.get() + 1);
+        c.get() == 1
     })
 }
+
+/// Decrement the lock count. Returns `true` when the last lock is released.
+fn release() -> bool {
+    LOCK_COUNT.with(|c| {
+        let n = c.get().saturating_sub(1);
+        c.set(n);
+        n == 0
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_locks_release_on_last() {
+        assert!(acquire());
+        assert!(!acquire());
+        assert!(!release());
+        assert!(release());
+        assert!(release(), "releasing an unheld lock stays at zero");
+        assert!(acquire());
+        assert!(release());
+    }
+}
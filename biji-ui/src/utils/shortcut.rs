@@ -0,0 +1,266 @@
+/// A parsed keyboard shortcut such as `"Mod+K"` or `"Ctrl+Shift+P"`.
+///
+/// Modifiers and the key are separated by `+` and matched case-insensitively.
+/// `Mod` (also `CmdOrCtrl`) resolves to ⌘ on Apple platforms and Ctrl elsewhere,
+/// so a single shortcut string works on every OS.
+///
+/// # Example
+///
+/// ```rust
+/// use biji_ui::utils::shortcut::Shortcut;
+///
+/// let shortcut = Shortcut::parse("Mod+K").unwrap();
+/// assert!(shortcut.matches_keys("k", "KeyK", false, true, false, false, true));
+/// assert!(shortcut.matches_keys("k", "KeyK", true, false, false, false, false));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Shortcut {
+    /// The lowercased `KeyboardEvent.key` value, e.g. `"k"` or `"escape"`.
+    pub key: String,
+    /// Platform-aware modifier: Meta on Apple platforms, Ctrl elsewhere.
+    pub mod_key: bool,
+    pub ctrl: bool,
+    pub meta: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl Shortcut {
+    /// Parse a `+`-separated shortcut string. Returns `None` when the string is
+    /// empty, has no key, or contains more than one non-modifier part.
+    pub fn parse(input: &str) -> Option<Self> {
+        if input.trim().is_empty() {
+            return None;
+        }
+        // A trailing "++" (e.g. "Ctrl++") means the plus key itself.
+        let (input, plus_key) = match input.trim().strip_suffix("++") {
+            Some(rest) => (rest, true),
+            None => (input, false),
+        };
+
+        let mut shortcut = Shortcut::default();
+        let mut key: Option<String> = plus_key.then(|| "+".to_string());
+
+        for part in input.split('+').map(str::trim) {
+            match part.to_lowercase().as_str() {
+                "" => return None,
+                "mod" | "cmdorctrl" => shortcut.mod_key = true,
+                "ctrl" | "control" => shortcut.ctrl = true,
+                "meta" | "cmd" | "command" | "super" => shortcut.meta = true,
+                "alt" | "option" | "opt" => shortcut.alt = true,
+                "shift" => shortcut.shift = true,
+                other => {
+                    if key.is_some() {
+                        return None;
+                    }
+                    key = Some(normalize_key(other));
+                }
+            }
+        }
+
+        shortcut.key = key?;
+        Some(shortcut)
+    }
+
+    /// Check a keyboard event against this shortcut on the current platform.
+    pub fn matches(&self, evt: &web_sys::KeyboardEvent) -> bool {
+        self.matches_keys(
+            &evt.key(),
+            &evt.code(),
+            evt.ctrl_key(),
+            evt.meta_key(),
+            evt.alt_key(),
+            evt.shift_key(),
+            is_apple_platform(),
+        )
+    }
+
+    /// Platform-independent matching used by [`Shortcut::matches`].
+    ///
+    /// Modifiers must match exactly. The key is compared against `key` first and
+    /// falls back to the physical `code` for letters and digits, because Alt/Option
+    /// combinations change `key` to a symbol on macOS.
+    #[allow(clippy::too_many_arguments)]
+    pub fn matches_keys(
+        &self,
+        key: &str,
+        code: &str,
+        ctrl: bool,
+        meta: bool,
+        alt: bool,
+        shift: bool,
+        apple: bool,
+    ) -> bool {
+        let want_ctrl = self.ctrl || (self.mod_key && !apple);
+        let want_meta = self.meta || (self.mod_key && apple);
+        if ctrl != want_ctrl || meta != want_meta || alt != self.alt || shift != self.shift {
+            return false;
+        }
+        if key.to_lowercase() == self.key {
+            return true;
+        }
+        code_matches(code, &self.key)
+    }
+
+    /// Human-readable label, e.g. `⌘K` on Apple platforms or `Ctrl+K` elsewhere.
+    pub fn label(&self, apple: bool) -> String {
+        let key = display_key(&self.key);
+        if apple {
+            let mut out = String::new();
+            if self.ctrl {
+                out.push('⌃');
+            }
+            if self.alt {
+                out.push('⌥');
+            }
+            if self.shift {
+                out.push('⇧');
+            }
+            if self.meta || self.mod_key {
+                out.push('⌘');
+            }
+            out.push_str(&key);
+            out
+        } else {
+            let mut parts: Vec<String> = Vec::new();
+            if self.ctrl || self.mod_key {
+                parts.push("Ctrl".into());
+            }
+            if self.meta {
+                parts.push("Meta".into());
+            }
+            if self.alt {
+                parts.push("Alt".into());
+            }
+            if self.shift {
+                parts.push("Shift".into());
+            }
+            parts.push(key);
+            parts.join("+")
+        }
+    }
+}
+
+/// Returns `true` on macOS, iOS and iPadOS, where `Mod` maps to the ⌘ key.
+pub fn is_apple_platform() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    let navigator = window.navigator();
+    let platform = navigator.platform().unwrap_or_default().to_lowercase();
+    if platform.is_empty() {
+        let ua = navigator.user_agent().unwrap_or_default().to_lowercase();
+        return ua.contains("mac os") || ua.contains("iphone") || ua.contains("ipad");
+    }
+    platform.starts_with("mac") || platform.starts_with("iphone") || platform.starts_with("ipad")
+}
+
+fn normalize_key(key: &str) -> String {
+    match key {
+        "space" => " ".into(),
+        "esc" => "escape".into(),
+        "up" => "arrowup".into(),
+        "down" => "arrowdown".into(),
+        "left" => "arrowleft".into(),
+        "right" => "arrowright".into(),
+        "del" => "delete".into(),
+        "return" => "enter".into(),
+        "plus" => "+".into(),
+        other => other.into(),
+    }
+}
+
+fn code_matches(code: &str, key: &str) -> bool {
+    let mut chars = key.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        return false;
+    };
+    if c.is_ascii_alphabetic() {
+        code == format!("Key{}", c.to_ascii_uppercase())
+    } else if c.is_ascii_digit() {
+        code == format!("Digit{c}")
+    } else {
+        false
+    }
+}
+
+fn display_key(key: &str) -> String {
+    match key {
+        " " => "Space".into(),
+        "escape" => "Esc".into(),
+        "arrowup" => "↑".into(),
+        "arrowdown" => "↓".into(),
+        "arrowleft" => "←".into(),
+        "arrowright" => "→".into(),
+        "enter" => "↵".into(),
+        other if other.chars().count() == 1 => other.to_uppercase(),
+        other => {
+            let mut chars = other.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mod_shortcut() {
+        let s = Shortcut::parse("Mod+K").unwrap();
+        assert!(s.mod_key);
+        assert_eq!(s.key, "k");
+        assert!(!s.ctrl && !s.meta && !s.alt && !s.shift);
+    }
+
+    #[test]
+    fn parse_rejects_invalid() {
+        assert_eq!(Shortcut::parse(""), None);
+        assert_eq!(Shortcut::parse("Ctrl+Shift"), None);
+        assert_eq!(Shortcut::parse("A+B"), None);
+    }
+
+    #[test]
+    fn parse_aliases_and_plus_key() {
+        let s = Shortcut::parse("cmd+option+Esc").unwrap();
+        assert!(s.meta && s.alt);
+        assert_eq!(s.key, "escape");
+        assert_eq!(Shortcut::parse("Ctrl++").unwrap().key, "+");
+    }
+
+    #[test]
+    fn mod_resolves_per_platform() {
+        let s = Shortcut::parse("Mod+K").unwrap();
+        // Apple: Meta required, Ctrl rejected.
+        assert!(s.matches_keys("k", "KeyK", false, true, false, false, true));
+        assert!(!s.matches_keys("k", "KeyK", true, false, false, false, true));
+        // Elsewhere: Ctrl required, Meta rejected.
+        assert!(s.matches_keys("k", "KeyK", true, false, false, false, false));
+        assert!(!s.matches_keys("k", "KeyK", false, true, false, false, false));
+    }
+
+    #[test]
+    fn modifiers_must_match_exactly() {
+        let s = Shortcut::parse("Ctrl+Shift+P").unwrap();
+        assert!(s.matches_keys("P", "KeyP", true, false, false, true, false));
+        assert!(!s.matches_keys("p", "KeyP", true, false, false, false, false));
+        assert!(!s.matches_keys("P", "KeyP", true, false, true, true, false));
+    }
+
+    #[test]
+    fn falls_back_to_physical_code() {
+        let s = Shortcut::parse("Alt+N").unwrap();
+        assert!(s.matches_keys("˜", "KeyN", false, false, true, false, true));
+        assert!(!s.matches_keys("˜", "KeyM", false, false, true, false, true));
+    }
+
+    #[test]
+    fn labels() {
+        let s = Shortcut::parse("Mod+Shift+P").unwrap();
+        assert_eq!(s.label(true), "⇧⌘P");
+        assert_eq!(s.label(false), "Ctrl+Shift+P");
+    }
+}
@@ -0,0 +1,62 @@
+//! Stepping and clamping shared by numeric inputs (slider, number field).
+
+/// Rounds `value` to the nearest multiple of `step` counted from `min` (or
+/// from zero when `min` is unbounded), then clamps it to `min..=max`.
+///
+/// The result is rounded to the step's decimal places, so `0.1 + 0.2` with a
+/// step of `0.1` gives `0.3` rather than `0.30000000000000004`.
+pub fn snap_to_step(value: f64, min: f64, max: f64, step: f64) -> f64 {
+    if !value.is_finite() {
+        return value.clamp(min, max);
+    }
+    let stepped = if step.is_finite() && step > 0.0 {
+        let base = if min.is_finite() { min } else { 0.0 };
+        let snapped = ((value - base) / step).round() * step + base;
+        round_to(snapped, decimals(step).max(decimals(base)))
+    } else {
+        value
+    };
+    stepped.clamp(min, max)
+}
+
+/// Number of decimal places in `value`'s shortest representation, up to 10.
+fn decimals(value: f64) -> i32 {
+    let text = value.to_string();
+    text.split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len().min(10) as i32)
+}
+
+fn round_to(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snaps_and_clamps() {
+        assert_eq!(snap_to_step(47.0, 0.0, 100.0, 5.0), 45.0);
+        assert_eq!(snap_to_step(48.0, 0.0, 100.0, 5.0), 50.0);
+        assert_eq!(snap_to_step(120.0, 0.0, 100.0, 5.0), 100.0);
+        assert_eq!(snap_to_step(4.0, 1.0, 10.0, 2.0), 5.0);
+    }
+
+    #[test]
+    fn avoids_float_noise() {
+        assert_eq!(snap_to_step(0.1 + 0.2, 0.0, 1.0, 0.1), 0.3);
+        assert_eq!(
+            snap_to_step(1.35, f64::NEG_INFINITY, f64::INFINITY, 0.05),
+            1.35
+        );
+    }
+
+    #[test]
+    fn unbounded_counts_from_zero() {
+        assert_eq!(
+            snap_to_step(-7.4, f64::NEG_INFINITY, f64::INFINITY, 0.5),
+            -7.5
+        );
+    }
+}
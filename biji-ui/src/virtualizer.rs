@@ -0,0 +1,527 @@
+//! Windowed rendering for long lists.
+//!
+//! A [`Virtualizer`] tracks the scroll position of a container and computes
+//! which rows are in view. [`VirtualList`] renders only those rows (plus an
+//! overscan margin) inside a spacer sized to the full list, so a list of 10k
+//! entries mounts a few dozen elements.
+//!
+//! `select`, `combobox` and `command` expose a `VirtualItems` part built on
+//! [`VirtualList`]. While it is mounted, keyboard navigation in those components
+//! moves by index over the whole collection, scrolling unmounted rows into view
+//! and focusing them once they render.
+
+use std::{collections::HashMap, ops::Range};
+
+use leptos::{context::Provider, ev, html::Div, prelude::*};
+use leptos_use::{use_element_size, use_event_listener};
+
+/// How row heights are determined.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ItemSize {
+    /// Every row is exactly this many pixels tall.
+    Fixed(f64),
+    /// Rows are measured after they render; unmeasured rows use the estimate.
+    Measured { estimate: f64 },
+}
+
+/// Where a row ends up when scrolled to with [`Virtualizer::scroll_to_index`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum ScrollAlign {
+    /// Scroll the minimum distance needed to make the row fully visible.
+    #[default]
+    Auto,
+    Start,
+    Center,
+    End,
+}
+
+/// Reactive windowing state for one scroll container. Cheap to copy.
+///
+/// Create it with [`Virtualizer::new`], pass it to a `VirtualItems` part (or
+/// [`VirtualList`]) and keep the handle around for [`scroll_to_index`](Self::scroll_to_index).
+#[derive(Copy, Clone)]
+pub struct Virtualizer {
+    pub count: Signal<usize>,
+    pub item_size: ItemSize,
+    /// Rows rendered beyond each edge of the viewport.
+    pub overscan: usize,
+    pub scroll_ref: NodeRef<Div>,
+    pub scroll_offset: RwSignal<f64>,
+    pub viewport_size: RwSignal<f64>,
+    measured: RwSignal<HashMap<usize, f64>>,
+    offsets: Memo<Vec<f64>>,
+    is_disabled: Option<Callback<usize, bool>>,
+    pending_focus: StoredValue<Option<usize>>,
+}
+
+impl Virtualizer {
+    pub fn new(count: impl Into<Signal<usize>>, item_size: ItemSize) -> Self {
+        let count = count.into();
+        let measured = RwSignal::new(HashMap::new());
+        let offsets = Memo::new(move |_| match item_size {
+            // Fixed rows are computed arithmetically; no table needed.
+            ItemSize::Fixed(_) => Vec::new(),
+            ItemSize::Measured { estimate } => {
+                measured.with(|m| prefix_offsets(count.get(), estimate, m))
+            }
+        });
+        Self {
+            count,
+            item_size,
+            overscan: 5,
+            scroll_ref: NodeRef::new(),
+            scroll_offset: RwSignal::new(0.0),
+            viewport_size: RwSignal::new(0.0),
+            measured,
+            offsets,
+            is_disabled: None,
+            pending_focus: StoredValue::new(None),
+        }
+    }
+
+    /// Number of rows rendered beyond each edge of the viewport. Defaults to 5.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Rows for which `is_disabled` returns `true` are skipped by keyboard navigation,
+    /// even when they are not mounted.
+    pub fn disabled(mut self, is_disabled: Callback<usize, bool>) -> Self {
+        self.is_disabled = Some(is_disabled);
+        self
+    }
+
+    /// Indexes of the rows that should currently be mounted.
+    pub fn range(&self) -> Range<usize> {
+        let count = self.count.get();
+        let scroll = self.scroll_offset.get();
+        let viewport = self.viewport_size.get();
+        match self.item_size {
+            ItemSize::Fixed(size) => fixed_range(count, size, scroll, viewport, self.overscan),
+            ItemSize::Measured { .. } => self
+                .offsets
+                .with(|o| offsets_range(o, scroll, viewport, self.overscan)),
+        }
+    }
+
+    /// Distance in pixels from the top of the list to the start of row `index`.
+    pub fn offset_of(&self, index: usize) -> f64 {
+        match self.item_size {
+            ItemSize::Fixed(size) => index as f64 * size,
+            ItemSize::Measured { estimate } => self
+                .offsets
+                .with(|o| o.get(index).copied().unwrap_or(index as f64 * estimate)),
+        }
+    }
+
+    /// Height of row `index`: measured if available, otherwise the estimate.
+    pub fn size_of(&self, index: usize) -> f64 {
+        match self.item_size {
+            ItemSize::Fixed(size) => size,
+            ItemSize::Measured { estimate } => self
+                .measured
+                .with(|m| m.get(&index).copied().unwrap_or(estimate)),
+        }
+    }
+
+    /// Total height of the list in pixels.
+    pub fn total_size(&self) -> f64 {
+        self.offset_of(self.count.get())
+    }
+
+    /// Record the rendered height of row `index`. No-op for fixed sizes.
+    pub fn measure(&self, index: usize, size: f64) {
+        if matches!(self.item_size, ItemSize::Fixed(_)) || size <= 0.0 {
+            return;
+        }
+        let unchanged = self
+            .measured
+            .with_untracked(|m| m.get(&index).is_some_and(|s| (s - size).abs() < 0.5));
+        if !unchanged {
+            self.measured.update(|m| {
+                m.insert(index, size);
+            });
+        }
+    }
+
+    /// Scroll the container so row `index` is visible.
+    pub fn scroll_to_index(&self, index: usize, align: ScrollAlign) {
+        let count = self.count.get_untracked();
+        if count == 0 {
+            return;
+        }
+        let index = index.min(count - 1);
+        let (start, end) = untrack(|| {
+            let start = self.offset_of(index);
+            (start, start + self.size_of(index))
+        });
+        let Some(target) = scroll_target(
+            start,
+            end,
+            self.scroll_offset.get_untracked(),
+            self.viewport_size.get_untracked(),
+            align,
+        ) else {
+            return;
+        };
+        self.scroll_offset.set(target);
+        if let Some(el) = self.scroll_ref.get_untracked() {
+            el.set_scroll_top(target.round() as i32);
+        }
+    }
+
+    pub fn is_disabled(&self, index: usize) -> bool {
+        self.is_disabled.is_some_and(|cb| cb.run(index))
+    }
+
+    pub fn first_index(&self) -> Option<usize> {
+        (0..self.count.get()).find(|&i| !self.is_disabled(i))
+    }
+
+    pub fn last_index(&self) -> Option<usize> {
+        (0..self.count.get()).rev().find(|&i| !self.is_disabled(i))
+    }
+
+    /// Next enabled index after `current`, wrapping when `allow_loop` is set.
+    pub fn next_index(&self, current: Option<usize>, allow_loop: bool) -> Option<usize> {
+        let count = self.count.get();
+        let Some(current) = current.filter(|&c| c < count) else {
+            return self.first_index();
+        };
+        let after = (current + 1..count).find(|&i| !self.is_disabled(i));
+        match after {
+            Some(i) => Some(i),
+            None if allow_loop => self.first_index(),
+            None => None,
+        }
+    }
+
+    /// Previous enabled index before `current`, wrapping when `allow_loop` is set.
+    pub fn previous_index(&self, current: Option<usize>, allow_loop: bool) -> Option<usize> {
+        let count = self.count.get();
+        let Some(current) = current.filter(|&c| c < count) else {
+            return self.last_index();
+        };
+        let before = (0..current).rev().find(|&i| !self.is_disabled(i));
+        match before {
+            Some(i) => Some(i),
+            None if allow_loop => self.last_index(),
+            None => None,
+        }
+    }
+
+    /// Scroll to `target` and return its item if it is mounted. Otherwise the row
+    /// is flagged so it focuses itself as soon as it renders.
+    ///
+    /// Used by the list components' `NavigateItems` implementations.
+    pub fn resolve<T: Copy>(&self, target: Option<usize>, items: &HashMap<usize, T>) -> Option<T> {
+        let target = target?;
+        self.scroll_to_index(target, ScrollAlign::Auto);
+        let item = items.get(&target).copied();
+        if item.is_none() {
+            self.pending_focus.set_value(Some(target));
+        }
+        item
+    }
+
+    /// Returns `true` once for the row that [`resolve`](Self::resolve) asked to focus.
+    pub fn take_pending_focus(&self, index: usize) -> bool {
+        let pending = self.pending_focus.get_value() == Some(index);
+        if pending {
+            self.pending_focus.set_value(None);
+        }
+        pending
+    }
+}
+
+/// Position of a rendered row within its [`VirtualList`], provided to the row's children.
+///
+/// List items read this to register under their row index and to set
+/// `aria-posinset` / `aria-setsize`.
+#[derive(Copy, Clone)]
+pub struct VirtualRow {
+    pub index: usize,
+    pub virtualizer: Virtualizer,
+}
+
+impl VirtualRow {
+    /// 1-based position for `aria-posinset`.
+    pub fn pos_in_set(&self) -> usize {
+        self.index + 1
+    }
+
+    /// Total row count for `aria-setsize`.
+    pub fn set_size(&self) -> usize {
+        self.virtualizer.count.get()
+    }
+
+    /// Focus `node_ref` once it mounts if navigation targeted this row before it
+    /// was rendered.
+    pub fn focus_when_pending(&self, node_ref: NodeRef<Div>) {
+        let row = *self;
+        Effect::new(move |_| {
+            if let Some(el) = node_ref.get() {
+                if row.virtualizer.take_pending_focus(row.index) {
+                    let _ = el.focus();
+                }
+            }
+        });
+    }
+}
+
+/// Renders the rows of `virtualizer` that are in view inside a scrollable container.
+///
+/// The container needs a bounded height (e.g. `max-h-64`) for windowing to work.
+/// `render` is called with the row index; when rows can change while keeping the
+/// same index (filtering), pass `key` so a changed row is re-rendered.
+#[component]
+pub fn VirtualList<F, IV>(
+    virtualizer: Virtualizer,
+    render: F,
+    /// Stable identity for the row at an index, e.g. the item's value.
+    #[prop(optional)]
+    key: Option<Callback<usize, String>>,
+    #[prop(into, optional)] class: String,
+) -> impl IntoView
+where
+    F: Fn(usize) -> IV + Clone + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let v = virtualizer;
+    let scroll_ref = v.scroll_ref;
+
+    let _ = use_event_listener(scroll_ref, ev::scroll, move |_| {
+        if let Some(el) = scroll_ref.get_untracked() {
+            v.scroll_offset.set(f64::from(el.scroll_top()));
+        }
+    });
+
+    let size = use_element_size(scroll_ref);
+    Effect::new(move |_| v.viewport_size.set(size.height.get()));
+
+    let rows = move || {
+        v.range()
+            .map(|i| (i, key.map(|k| k.run(i))))
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div
+            node_ref={scroll_ref}
+            class={class}
+            role="presentation"
+            style="overflow-y: auto; position: relative;"
+        >
+            <div
+                role="presentation"
+                style={move || format!("position: relative; width: 100%; height: {}px;", v.total_size())}
+            >
+                <For each={rows} key={|row| row.clone()} let:row>
+                    <VirtualListRow
+                        index={row.0}
+                        virtualizer={v}
+                        render={render.clone()}
+                        keyed={key.is_some()}
+                    />
+                </For>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn VirtualListRow<F, IV>(
+    index: usize,
+    virtualizer: Virtualizer,
+    render: F,
+    keyed: bool,
+) -> impl IntoView
+where
+    F: Fn(usize) -> IV + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let row_ref = NodeRef::<Div>::new();
+
+    if matches!(virtualizer.item_size, ItemSize::Measured { .. }) {
+        let size = use_element_size(row_ref);
+        Effect::new(move |_| virtualizer.measure(index, size.height.get()));
+    }
+
+    // Without a key, re-render when the count changes so rows at a reused index
+    // pick up their new data.
+    let count = virtualizer.count;
+
+    view! {
+        <div
+            node_ref={row_ref}
+            role="presentation"
+            data-index={index}
+            style={move || {
+                format!(
+                    "position: absolute; top: 0; left: 0; width: 100%; transform: translateY({}px);",
+                    virtualizer.offset_of(index),
+                )
+            }}
+        >
+            <Provider value={VirtualRow { index, virtualizer }}>
+                {move || {
+                    if !keyed {
+                        count.track();
+                    }
+                    untrack(|| render(index))
+                }}
+            </Provider>
+        </div>
+    }
+}
+
+pub(crate) fn fixed_range(
+    count: usize,
+    size: f64,
+    scroll: f64,
+    viewport: f64,
+    overscan: usize,
+) -> Range<usize> {
+    if count == 0 || size <= 0.0 {
+        return 0..0;
+    }
+    let first = ((scroll / size).floor().max(0.0) as usize).min(count - 1);
+    let last = (((scroll + viewport) / size).ceil().max(0.0) as usize).min(count);
+    first.saturating_sub(overscan)..(last.max(first + 1) + overscan).min(count)
+}
+
+/// `offsets[i]` is the start of row `i`; `offsets[count]` is the total height.
+pub(crate) fn prefix_offsets(
+    count: usize,
+    estimate: f64,
+    measured: &HashMap<usize, f64>,
+) -> Vec<f64> {
+    let mut offsets = Vec::with_capacity(count + 1);
+    let mut acc = 0.0;
+    offsets.push(acc);
+    for i in 0..count {
+        acc += measured.get(&i).copied().unwrap_or(estimate);
+        offsets.push(acc);
+    }
+    offsets
+}
+
+pub(crate) fn offsets_range(
+    offsets: &[f64],
+    scroll: f64,
+    viewport: f64,
+    overscan: usize,
+) -> Range<usize> {
+    let count = offsets.len().saturating_sub(1);
+    if count == 0 {
+        return 0..0;
+    }
+    // Last row starting at or before the scroll offset.
+    let first = offsets[..count]
+        .partition_point(|&o| o <= scroll)
+        .saturating_sub(1);
+    // First row starting at or after the viewport's bottom edge.
+    let last = offsets[..count].partition_point(|&o| o < scroll + viewport);
+    first.saturating_sub(overscan)..(last.max(first + 1) + overscan).min(count)
+}
+
+/// New scroll offset that brings `[start, end)` into view, or `None` if no scroll
+/// is needed.
+pub(crate) fn scroll_target(
+    start: f64,
+    end: f64,
+    scroll: f64,
+    viewport: f64,
+    align: ScrollAlign,
+) -> Option<f64> {
+    let target = match align {
+        ScrollAlign::Start => start,
+        ScrollAlign::End => end - viewport,
+        ScrollAlign::Center => start - (viewport - (end - start)) / 2.0,
+        ScrollAlign::Auto => {
+            if start < scroll {
+                start
+            } else if end > scroll + viewport {
+                end - viewport
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(target.max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_range_applies_overscan_and_clamps() {
+        // 100 rows of 10px, viewport 50px scrolled to 200px → rows 20..25 visible.
+        assert_eq!(fixed_range(100, 10.0, 200.0, 50.0, 0), 20..25);
+        assert_eq!(fixed_range(100, 10.0, 200.0, 50.0, 3), 17..28);
+        assert_eq!(fixed_range(100, 10.0, 0.0, 50.0, 3), 0..8);
+        assert_eq!(fixed_range(100, 10.0, 990.0, 50.0, 3), 96..100);
+        assert_eq!(fixed_range(0, 10.0, 0.0, 50.0, 3), 0..0);
+    }
+
+    #[test]
+    fn fixed_range_renders_one_row_before_viewport_is_measured() {
+        assert_eq!(fixed_range(100, 10.0, 0.0, 0.0, 0), 0..1);
+    }
+
+    #[test]
+    fn prefix_offsets_mix_measured_and_estimated() {
+        let measured = HashMap::from([(1, 30.0)]);
+        assert_eq!(prefix_offsets(3, 10.0, &measured), [0.0, 10.0, 40.0, 50.0]);
+    }
+
+    #[test]
+    fn offsets_range_matches_fixed_range_for_uniform_rows() {
+        let offsets = prefix_offsets(100, 10.0, &HashMap::new());
+        for scroll in [0.0, 5.0, 200.0, 955.0] {
+            assert_eq!(
+                offsets_range(&offsets, scroll, 50.0, 2),
+                fixed_range(100, 10.0, scroll, 50.0, 2),
+                "scroll={scroll}"
+            );
+        }
+    }
+
+    #[test]
+    fn scroll_target_auto_only_scrolls_when_needed() {
+        assert_eq!(
+            scroll_target(100.0, 110.0, 90.0, 50.0, ScrollAlign::Auto),
+            None
+        );
+        assert_eq!(
+            scroll_target(80.0, 90.0, 90.0, 50.0, ScrollAlign::Auto),
+            Some(80.0)
+        );
+        assert_eq!(
+            scroll_target(150.0, 160.0, 90.0, 50.0, ScrollAlign::Auto),
+            Some(110.0)
+        );
+    }
+
+    #[test]
+    fn scroll_target_alignments() {
+        assert_eq!(
+            scroll_target(100.0, 110.0, 0.0, 50.0, ScrollAlign::Start),
+            Some(100.0)
+        );
+        assert_eq!(
+            scroll_target(100.0, 110.0, 0.0, 50.0, ScrollAlign::End),
+            Some(60.0)
+        );
+        assert_eq!(
+            scroll_target(100.0, 110.0, 0.0, 50.0, ScrollAlign::Center),
+            Some(80.0)
+        );
+        assert_eq!(
+            scroll_target(10.0, 20.0, 0.0, 50.0, ScrollAlign::Center),
+            Some(0.0)
+        );
+    }
+}